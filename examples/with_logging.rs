@@ -10,6 +10,7 @@ async fn main() -> Result<(), Error> {
         json_format: false,
         filter_sensitive: true,
         file_path: None,
+        ..Default::default()
     };
 
     // 환경 변수에서 인증키 읽기
@@ -0,0 +1,304 @@
+//! 채권지수 그룹·지수 구분 분류와 시계열/비교 변환.
+//!
+//! `index().bond_daily()`는 한 날짜에 여러 채권지수 그룹(`BND_IDX_GRP_NM`)의
+//! 총수익지수, 순가격지수, 제로재투자지수, 콜재투자지수, 시장가격지수와
+//! 듀레이션, 컨벡시티, YTM을 한 행에 담아 반환합니다. 이 모듈은 그룹과 지수 구분을
+//! 타입으로 표현하고, 이를 long 형식 시계열과 그룹 비교용 wide 형식으로 변환합니다.
+//!
+//! # 예시
+//! ```rust,no_run
+//! # use krx_rs::Client;
+//! use krx_rs::analysis::bond_index::{BondIndexGroup, BondIndexVariant};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), krx_rs::error::Error> {
+//! # let client = Client::new("YOUR_AUTH_KEY");
+//! let series = client
+//!     .index()
+//!     .bond_series(BondIndexGroup::KtbPrime, BondIndexVariant::TotalReturn)
+//!     .start_date("20240102")
+//!     .end_date("20240131")
+//!     .fetch()
+//!     .await?;
+//!
+//! let comparison = client
+//!     .index()
+//!     .bond_daily()
+//!     .date("20240105")
+//!     .fetch_group_comparison()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{data::index::BondIndexDailyRecord, error::Result};
+use polars::prelude::*;
+use std::fmt;
+
+/// KRX 채권지수 그룹 (`BND_IDX_GRP_NM`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BondIndexGroup {
+    /// KRX 채권지수
+    Krx,
+    /// KTB 지수
+    Ktb,
+    /// 국고채프라임지수
+    KtbPrime,
+    /// 그 외 그룹 (원문 유지)
+    Other(String),
+}
+
+impl BondIndexGroup {
+    /// KRX가 제공하는 그룹 목록 (`Other` 제외)
+    pub const ALL: [BondIndexGroup; 3] = [Self::Krx, Self::Ktb, Self::KtbPrime];
+
+    /// 그룹명을 분류합니다. 공백 차이("KTB지수")는 무시합니다.
+    pub fn from_name(name: &str) -> Self {
+        let normalized: String = name.chars().filter(|c| !c.is_whitespace()).collect();
+        match normalized.as_str() {
+            "KRX채권지수" => Self::Krx,
+            "KTB지수" => Self::Ktb,
+            "국고채프라임지수" => Self::KtbPrime,
+            _ => Self::Other(name.trim().to_string()),
+        }
+    }
+
+    /// KRX 응답에 사용되는 그룹명
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Krx => "KRX 채권지수",
+            Self::Ktb => "KTB 지수",
+            Self::KtbPrime => "국고채프라임지수",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl fmt::Display for BondIndexGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 채권지수 구분
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BondIndexVariant {
+    /// 총수익지수
+    TotalReturn,
+    /// 순가격지수
+    NetPrice,
+    /// 제로재투자지수
+    ZeroReinvestment,
+    /// 콜재투자지수
+    CallReinvestment,
+    /// 시장가격지수
+    MarketPrice,
+}
+
+impl BondIndexVariant {
+    /// 전체 지수 구분
+    pub const ALL: [BondIndexVariant; 5] = [
+        Self::TotalReturn,
+        Self::NetPrice,
+        Self::ZeroReinvestment,
+        Self::CallReinvestment,
+        Self::MarketPrice,
+    ];
+
+    /// `parse_bond_index_daily` 결과의 지수 컬럼명
+    pub fn column(&self) -> &'static str {
+        match self {
+            Self::TotalReturn => "총수익지수",
+            Self::NetPrice => "순가격지수",
+            Self::ZeroReinvestment => "제로재투자지수",
+            Self::CallReinvestment => "콜재투자지수",
+            Self::MarketPrice => "시장가격지수",
+        }
+    }
+
+    /// `parse_bond_index_daily` 결과의 전일 대비 컬럼명
+    pub fn change_column(&self) -> String {
+        format!("{}_대비", self.column())
+    }
+
+    /// 레코드에서 지수 값과 전일 대비를 꺼냅니다.
+    pub fn values(&self, record: &BondIndexDailyRecord) -> (Option<f64>, Option<f64>) {
+        match self {
+            Self::TotalReturn => (
+                record.total_earning_index,
+                record.total_earning_index_change,
+            ),
+            Self::NetPrice => (record.net_price_index, record.net_price_index_change),
+            Self::ZeroReinvestment => (
+                record.zero_reinvest_index,
+                record.zero_reinvest_index_change,
+            ),
+            Self::CallReinvestment => (
+                record.call_reinvest_index,
+                record.call_reinvest_index_change,
+            ),
+            Self::MarketPrice => (record.market_price_index, record.market_price_index_change),
+        }
+    }
+}
+
+impl fmt::Display for BondIndexVariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.column())
+    }
+}
+
+impl BondIndexDailyRecord {
+    /// 지수 그룹을 [`BondIndexGroup`]으로 분류합니다.
+    pub fn group(&self) -> BondIndexGroup {
+        BondIndexGroup::from_name(&self.bond_index_group_name)
+    }
+}
+
+/// 채권지수 DataFrame에서 한 그룹·지수 구분의 long 형식 시계열을 추출합니다.
+///
+/// 입력은 [`crate::data::index::parse_bond_index_daily`] 형식이며, 여러 날짜를
+/// 합친 DataFrame도 받을 수 있습니다.
+///
+/// 결과 컬럼: `날짜`, `지수명`, `지수구분`, `지수`, `대비`, `듀레이션`, `컨벡시티`, `YTM`.
+pub fn bond_index_series(
+    df: &DataFrame,
+    group: &BondIndexGroup,
+    variant: BondIndexVariant,
+) -> Result<DataFrame> {
+    if df.is_empty() {
+        return Ok(DataFrame::empty());
+    }
+
+    let result = df
+        .clone()
+        .lazy()
+        .filter(col("지수명").eq(lit(group.as_str())))
+        .select([
+            col("날짜"),
+            col("지수명"),
+            lit(variant.column()).alias("지수구분"),
+            col(variant.column()).alias("지수"),
+            col(variant.change_column()).alias("대비"),
+            col("듀레이션"),
+            col("컨벡시티"),
+            col("YTM"),
+        ])
+        .sort(["날짜"], SortMultipleOptions::default())
+        .collect()?;
+
+    Ok(result)
+}
+
+/// 한 날짜의 그룹별 듀레이션·컨벡시티·YTM을 비교하는 wide 형식 표를 만듭니다.
+///
+/// 결과는 `항목`(듀레이션, 컨벡시티, YTM) 행과 그룹명 컬럼으로 구성됩니다.
+/// 입력에 여러 날짜가 있으면 가장 최근 날짜만 사용합니다.
+pub fn compare_groups(df: &DataFrame) -> Result<DataFrame> {
+    if df.is_empty() {
+        return Ok(DataFrame::empty());
+    }
+
+    let latest = df
+        .clone()
+        .lazy()
+        .filter(col("날짜").eq(col("날짜").max()))
+        .collect()?;
+
+    const METRICS: [&str; 3] = ["듀레이션", "컨벡시티", "YTM"];
+    let groups = latest.column("지수명")?.str()?;
+
+    let mut columns = vec![Column::new("항목".into(), METRICS)];
+    for (row, group) in groups.into_iter().enumerate() {
+        let Some(group) = group else { continue };
+        let values = METRICS
+            .iter()
+            .map(|metric| Ok(latest.column(metric)?.f64()?.get(row)))
+            .collect::<Result<Vec<_>>>()?;
+        columns.push(Column::new(group.into(), values));
+    }
+
+    Ok(DataFrame::new(columns)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> DataFrame {
+        let rows = [
+            ("2024-01-04", "KRX 채권지수", 190.0, 4.1, 3.40),
+            ("2024-01-04", "국고채프라임지수", 200.0, 6.5, 3.20),
+            ("2024-01-05", "KRX 채권지수", 190.5, 4.2, 3.35),
+            ("2024-01-05", "국고채프라임지수", 201.0, 6.6, 3.15),
+        ];
+        df! {
+            "날짜" => rows.iter().map(|r| r.0).collect::<Vec<_>>(),
+            "지수명" => rows.iter().map(|r| r.1).collect::<Vec<_>>(),
+            "총수익지수" => rows.iter().map(|r| Some(r.2)).collect::<Vec<_>>(),
+            "총수익지수_대비" => [Some(0.1), Some(0.2), Some(0.5), Some(1.0)],
+            "순가격지수" => [Some(100.0); 4],
+            "순가격지수_대비" => [Some(0.0); 4],
+            "제로재투자지수" => [Some(100.0); 4],
+            "제로재투자지수_대비" => [Some(0.0); 4],
+            "콜재투자지수" => [Some(100.0); 4],
+            "콜재투자지수_대비" => [Some(0.0); 4],
+            "시장가격지수" => [Some(100.0); 4],
+            "시장가격지수_대비" => [Some(0.0); 4],
+            "듀레이션" => rows.iter().map(|r| Some(r.3)).collect::<Vec<_>>(),
+            "컨벡시티" => [Some(30.0); 4],
+            "YTM" => rows.iter().map(|r| Some(r.4)).collect::<Vec<_>>(),
+        }
+        .unwrap()
+    }
+
+    #[test]
+    fn test_bond_index_group_from_name() {
+        assert_eq!(BondIndexGroup::from_name("KTB지수"), BondIndexGroup::Ktb);
+        assert_eq!(
+            BondIndexGroup::from_name("국고채프라임지수"),
+            BondIndexGroup::KtbPrime
+        );
+        assert_eq!(
+            BondIndexGroup::from_name("통안채지수"),
+            BondIndexGroup::Other("통안채지수".to_string())
+        );
+    }
+
+    #[test]
+    fn test_bond_index_series() {
+        let series = bond_index_series(
+            &sample(),
+            &BondIndexGroup::KtbPrime,
+            BondIndexVariant::TotalReturn,
+        )
+        .unwrap();
+
+        assert_eq!(series.height(), 2);
+        assert_eq!(
+            series.column("지수구분").unwrap().str().unwrap().get(0),
+            Some("총수익지수")
+        );
+        let values = series.column("지수").unwrap().f64().unwrap();
+        assert_eq!(values.get(0), Some(200.0));
+        assert_eq!(values.get(1), Some(201.0));
+        assert_eq!(
+            series.column("대비").unwrap().f64().unwrap().get(1),
+            Some(1.0)
+        );
+    }
+
+    #[test]
+    fn test_compare_groups_uses_latest_date() {
+        let comparison = compare_groups(&sample()).unwrap();
+
+        assert_eq!(comparison.shape(), (3, 3));
+        let prime = comparison
+            .column("국고채프라임지수")
+            .unwrap()
+            .f64()
+            .unwrap();
+        assert_eq!(prime.get(0), Some(6.6));
+        assert_eq!(prime.get(2), Some(3.15));
+    }
+}
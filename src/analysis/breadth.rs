@@ -0,0 +1,279 @@
+//! 시장 폭(Market Breadth) 및 등락 통계.
+//!
+//! `stock().stock_daily()`, `kosdaq_daily()`, `konex_daily()`가 반환한 DataFrame을
+//! 입력으로 받아 날짜·시장·소속부별 상승/하락/보합 종목수, 상승/하락 거래량,
+//! 상한가/하한가 종목수, N일 이동평균 상회 비율을 계산합니다.
+//!
+//! 여러 날짜나 여러 시장의 DataFrame을 `vstack`/`concat`으로 합쳐서 전달하면
+//! 기간 전체에 대한 통계를 한 번에 얻을 수 있습니다.
+//!
+//! # 예시
+//! ```rust,no_run
+//! # use krx_rs::Client;
+//! use krx_rs::analysis::breadth::{BreadthOptions, market_breadth};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), krx_rs::error::Error> {
+//! # let client = Client::new("YOUR_AUTH_KEY");
+//! let mut df = client.stock().stock_daily().date("20240104").fetch().await?;
+//! df.vstack_mut(&client.stock().stock_daily().date("20240105").fetch().await?)?;
+//! df.vstack_mut(&client.stock().kosdaq_daily().date("20240105").fetch().await?)?;
+//!
+//! let breadth = market_breadth(&df, &BreadthOptions::default())?;
+//! println!("{breadth}");
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Result;
+use polars::prelude::*;
+
+/// 시장 전체 집계 행의 소속부 값
+pub const ALL_SECTORS: &str = "전체";
+
+/// 시장 폭 계산 옵션
+#[derive(Debug, Clone, PartialEq)]
+pub struct BreadthOptions {
+    /// 이동평균 상회 비율 계산에 사용할 기간 (거래일 수)
+    pub average_window: usize,
+    /// 상한가/하한가로 판정할 등락률 절댓값 (%)
+    ///
+    /// KOSPI·KOSDAQ 가격제한폭은 ±30%이며, 호가단위 절사로 실제 등락률은 30%보다
+    /// 약간 작을 수 있어 기본값은 29.5입니다. `market_limit_rates`에 없는 시장에 적용됩니다.
+    pub limit_rate: f64,
+    /// `시장구분`별 상한가/하한가 판정 등락률 (%)
+    ///
+    /// 기본값은 가격제한폭이 ±15%인 KONEX에 대한 `("KONEX", 14.5)`입니다.
+    pub market_limit_rates: Vec<(String, f64)>,
+}
+
+impl Default for BreadthOptions {
+    fn default() -> Self {
+        Self {
+            average_window: 20,
+            limit_rate: 29.5,
+            market_limit_rates: vec![("KONEX".to_string(), 14.5)],
+        }
+    }
+}
+
+impl BreadthOptions {
+    /// 시장별 판정 등락률을 `_limit` 컬럼으로 만드는 식
+    fn limit_expr(&self) -> Expr {
+        let mut expr = lit(self.limit_rate);
+        for (market, rate) in self.market_limit_rates.iter().rev() {
+            expr = when(col("시장구분").eq(lit(market.as_str())))
+                .then(lit(*rate))
+                .otherwise(expr);
+        }
+        expr.alias("_limit")
+    }
+}
+
+/// 일별 시세 DataFrame으로 날짜·시장·소속부별 시장 폭 통계를 계산합니다.
+///
+/// 입력에는 `날짜`, `종목코드`, `시장구분`, `소속부`, `종가`, `대비`, `등락률`,
+/// `거래량` 컬럼이 필요합니다. 결과는 `(날짜, 시장구분, 소속부)`마다 한 행을 갖는
+/// tidy DataFrame이며, 소속부가 [`ALL_SECTORS`]인 행은 시장 전체 집계입니다.
+///
+/// `이평상회비율`은 이동평균을 계산할 수 있는 종목 중 종가가 이동평균보다 높은
+/// 종목의 비율(0~1)입니다. 기간이 `average_window`보다 짧으면 `null`이 됩니다.
+pub fn market_breadth(df: &DataFrame, options: &BreadthOptions) -> Result<DataFrame> {
+    if df.is_empty() {
+        return Ok(DataFrame::empty());
+    }
+
+    let window = options.average_window.max(1);
+    let prepared = df
+        .clone()
+        .lazy()
+        .sort(["종목코드", "날짜"], SortMultipleOptions::default())
+        .with_column(
+            col("종가")
+                .rolling_mean(RollingOptionsFixedWindow {
+                    window_size: window,
+                    min_periods: window,
+                    ..Default::default()
+                })
+                .over([col("종목코드")])
+                .alias("_sma"),
+        )
+        .with_column(
+            when(col("_sma").is_null())
+                .then(lit(NULL).cast(DataType::Float64))
+                .otherwise(col("종가").gt(col("_sma")).cast(DataType::Float64))
+                .alias("_above"),
+        )
+        .with_column(options.limit_expr());
+
+    let by_sector = prepared
+        .clone()
+        .group_by([col("날짜"), col("시장구분"), col("소속부")])
+        .agg(breadth_aggregations());
+
+    let by_market = prepared
+        .group_by([col("날짜"), col("시장구분")])
+        .agg(breadth_aggregations())
+        .with_column(lit(ALL_SECTORS).alias("소속부"));
+
+    let columns = [
+        "날짜",
+        "시장구분",
+        "소속부",
+        "상승종목수",
+        "하락종목수",
+        "보합종목수",
+        "상승거래량",
+        "하락거래량",
+        "상한가종목수",
+        "하한가종목수",
+        "이평상회비율",
+    ]
+    .map(col);
+
+    let result = concat(
+        [by_market.select(columns.clone()), by_sector.select(columns)],
+        UnionArgs::default(),
+    )?
+    .sort(
+        ["날짜", "시장구분", "소속부"],
+        SortMultipleOptions::default(),
+    )
+    .collect()?;
+
+    Ok(result)
+}
+
+fn breadth_aggregations() -> Vec<Expr> {
+    let count = |predicate: Expr| predicate.sum().cast(DataType::UInt32);
+    let volume = |predicate: Expr| col("거래량").filter(predicate).sum().cast(DataType::Int64);
+
+    vec![
+        count(col("대비").gt(lit(0.0))).alias("상승종목수"),
+        count(col("대비").lt(lit(0.0))).alias("하락종목수"),
+        count(col("대비").eq(lit(0.0))).alias("보합종목수"),
+        volume(col("대비").gt(lit(0.0))).alias("상승거래량"),
+        volume(col("대비").lt(lit(0.0))).alias("하락거래량"),
+        count(col("등락률").gt_eq(col("_limit"))).alias("상한가종목수"),
+        count(col("등락률").lt_eq(-col("_limit"))).alias("하한가종목수"),
+        col("_above").mean().alias("이평상회비율"),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> DataFrame {
+        df! {
+            "날짜" => ["2024-01-04", "2024-01-04", "2024-01-04", "2024-01-05", "2024-01-05", "2024-01-05"],
+            "종목코드" => ["A", "B", "C", "A", "B", "C"],
+            "시장구분" => ["KOSDAQ"; 6],
+            "소속부" => ["우량기업부", "우량기업부", "벤처기업부", "우량기업부", "우량기업부", "벤처기업부"],
+            "종가" => [100.0, 200.0, 300.0, 130.0, 190.0, 300.0],
+            "대비" => [1.0, -1.0, 0.0, 30.0, -10.0, 0.0],
+            "등락률" => [1.0, -0.5, 0.0, 30.0, -5.0, 0.0],
+            "거래량" => [10i64, 20, 30, 40, 50, 60],
+        }
+        .unwrap()
+    }
+
+    #[test]
+    fn test_market_breadth_counts() {
+        let options = BreadthOptions {
+            average_window: 2,
+            ..Default::default()
+        };
+        let result = market_breadth(&sample(), &options).unwrap();
+
+        // 날짜 2개 × (전체 + 소속부 2개)
+        assert_eq!(result.height(), 6);
+
+        let total = result
+            .clone()
+            .lazy()
+            .filter(
+                col("날짜")
+                    .eq(lit("2024-01-05"))
+                    .and(col("소속부").eq(lit(ALL_SECTORS))),
+            )
+            .collect()
+            .unwrap();
+
+        let get_u32 = |name: &str| total.column(name).unwrap().u32().unwrap().get(0);
+        assert_eq!(get_u32("상승종목수"), Some(1));
+        assert_eq!(get_u32("하락종목수"), Some(1));
+        assert_eq!(get_u32("보합종목수"), Some(1));
+        assert_eq!(get_u32("상한가종목수"), Some(1));
+        assert_eq!(get_u32("하한가종목수"), Some(0));
+        assert_eq!(
+            total.column("상승거래량").unwrap().i64().unwrap().get(0),
+            Some(40)
+        );
+        assert_eq!(
+            total.column("하락거래량").unwrap().i64().unwrap().get(0),
+            Some(50)
+        );
+
+        // 2일 이동평균: A=115 (130 상회), B=195 (190 하회), C=300 (동일)
+        let ratio = total
+            .column("이평상회비율")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .get(0)
+            .unwrap();
+        assert!((ratio - 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_market_breadth_konex_limit() {
+        let df = df! {
+            "날짜" => ["2024-01-05"; 4],
+            "종목코드" => ["A", "B", "C", "D"],
+            "시장구분" => ["KONEX", "KONEX", "KOSDAQ", "KOSDAQ"],
+            "소속부" => ["일반기업부"; 4],
+            "종가" => [1150.0, 850.0, 1150.0, 1300.0],
+            "대비" => [150.0, -150.0, 150.0, 300.0],
+            "등락률" => [15.0, -15.0, 15.0, 30.0],
+            "거래량" => [10i64, 20, 30, 40],
+        }
+        .unwrap();
+        let result = market_breadth(&df, &BreadthOptions::default()).unwrap();
+
+        let total = |market: &str, name: &str| {
+            result
+                .clone()
+                .lazy()
+                .filter(
+                    col("시장구분")
+                        .eq(lit(market))
+                        .and(col("소속부").eq(lit(ALL_SECTORS))),
+                )
+                .collect()
+                .unwrap()
+                .column(name)
+                .unwrap()
+                .u32()
+                .unwrap()
+                .get(0)
+        };
+        // KONEX는 ±15%, KOSDAQ은 ±30%가 가격제한폭입니다.
+        assert_eq!(total("KONEX", "상한가종목수"), Some(1));
+        assert_eq!(total("KONEX", "하한가종목수"), Some(1));
+        assert_eq!(total("KOSDAQ", "상한가종목수"), Some(1));
+        assert_eq!(total("KOSDAQ", "하한가종목수"), Some(0));
+    }
+
+    #[test]
+    fn test_market_breadth_insufficient_history() {
+        let result = market_breadth(&sample(), &BreadthOptions::default()).unwrap();
+        assert_eq!(result.column("이평상회비율").unwrap().null_count(), 6);
+    }
+
+    #[test]
+    fn test_market_breadth_empty() {
+        let result = market_breadth(&DataFrame::empty(), &BreadthOptions::default()).unwrap();
+        assert!(result.is_empty());
+    }
+}
@@ -0,0 +1,445 @@
+//! 다일자 시세 패널용 기술적 지표.
+//!
+//! 모든 지표는 polars [`Expr`]로 제공되며, 종목(또는 지수)별로 분할하고 날짜 순으로
+//! 정렬한 윈도 표현식으로 계산됩니다. 입력 DataFrame을 미리 정렬할 필요는 없습니다.
+//!
+//! 주식·ETF·ETN·선물·금·배출권처럼 `종목코드`가 있는 DataFrame은
+//! [`PriceColumns::default()`]를, 지수 DataFrame은 [`PriceColumns::index()`]를
+//! 사용하세요. [`PriceColumns::detect`]는 컬럼 구성을 보고 둘 중 하나를 고릅니다.
+//! 선물·옵션처럼 시가·고가·저가가 없는 DataFrame에서는 해당 컬럼이 `None`이 되며,
+//! [`atr`]은 종가만으로 계산합니다.
+//!
+//! # 예시
+//! ```rust,no_run
+//! # use krx_rs::Client;
+//! use krx_rs::analysis::indicators::{self, PriceColumns};
+//! use polars::prelude::*;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), krx_rs::error::Error> {
+//! # let client = Client::new("YOUR_AUTH_KEY");
+//! let mut df = client.stock().stock_daily().date("20240104").fetch().await?;
+//! df.vstack_mut(&client.stock().stock_daily().date("20240105").fetch().await?)?;
+//!
+//! let cols = PriceColumns::detect(&df);
+//! let df = df
+//!     .lazy()
+//!     .with_columns([
+//!         indicators::sma(&cols, 20),
+//!         indicators::rsi(&cols, 14),
+//!         indicators::obv(&cols),
+//!     ])
+//!     .collect()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use polars::prelude::*;
+
+/// 지표 계산에 사용할 컬럼 이름 설정
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceColumns {
+    /// 정렬 기준 날짜 컬럼
+    pub date: String,
+    /// 분할 기준 컬럼 (`None`이면 전체를 하나의 시계열로 취급)
+    pub partition: Option<String>,
+    /// 시가 컬럼 (`None`이면 시가 없음)
+    pub open: Option<String>,
+    /// 고가 컬럼 (`None`이면 고가 없음)
+    pub high: Option<String>,
+    /// 저가 컬럼 (`None`이면 저가 없음)
+    pub low: Option<String>,
+    /// 종가 컬럼
+    pub close: String,
+    /// 거래량 컬럼
+    pub volume: String,
+}
+
+impl Default for PriceColumns {
+    /// 종목코드로 분할하는 종목 시세용 설정
+    fn default() -> Self {
+        Self {
+            date: "날짜".to_string(),
+            partition: Some("종목코드".to_string()),
+            open: Some("시가".to_string()),
+            high: Some("고가".to_string()),
+            low: Some("저가".to_string()),
+            close: "종가".to_string(),
+            volume: "거래량".to_string(),
+        }
+    }
+}
+
+impl PriceColumns {
+    /// 지수명으로 분할하는 지수 시세용 설정
+    pub fn index() -> Self {
+        Self {
+            partition: Some("지수명".to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// DataFrame의 컬럼 구성을 보고 종목 또는 지수 설정을 선택합니다.
+    ///
+    /// `종목코드`가 있으면 종목 설정, `지수명`이 있으면 지수 설정,
+    /// 둘 다 없으면 분할 없이 단일 시계열로 취급합니다. 시가·고가·저가 중 없는
+    /// 컬럼은 `None`으로 둡니다.
+    pub fn detect(df: &DataFrame) -> Self {
+        let schema = df.schema();
+        let mut cols = if schema.contains("종목코드") {
+            Self::default()
+        } else if schema.contains("지수명") {
+            Self::index()
+        } else {
+            Self {
+                partition: None,
+                ..Self::default()
+            }
+        };
+        for column in [&mut cols.open, &mut cols.high, &mut cols.low] {
+            if column.as_deref().is_some_and(|name| !schema.contains(name)) {
+                *column = None;
+            }
+        }
+        cols
+    }
+
+    /// 분할 컬럼 설정을 변경합니다.
+    pub fn partition(mut self, column: Option<&str>) -> Self {
+        self.partition = column.map(str::to_string);
+        self
+    }
+
+    /// 종목(또는 지수)별로 분할하고 날짜 순으로 정렬한 윈도 표현식으로 감쌉니다.
+    pub fn windowed(&self, expr: Expr) -> Expr {
+        let order_by = ([col(self.date.as_str())], SortOptions::default());
+        let partition_by = self.partition.as_deref().map(|partition| [col(partition)]);
+
+        expr.over_with_options(partition_by, Some(order_by), WindowMapping::default())
+            .expect("order_by is always provided")
+    }
+}
+
+fn fixed_window(window: usize) -> RollingOptionsFixedWindow {
+    RollingOptionsFixedWindow {
+        window_size: window,
+        min_periods: window,
+        ..Default::default()
+    }
+}
+
+fn ewm(expr: Expr, alpha: f64, min_periods: usize) -> Expr {
+    expr.ewm_mean(EWMOptions {
+        alpha,
+        adjust: false,
+        min_periods,
+        ..Default::default()
+    })
+}
+
+fn span_alpha(span: usize) -> f64 {
+    2.0 / (span as f64 + 1.0)
+}
+
+/// 단순이동평균 (`SMA_{window}`)
+pub fn sma(cols: &PriceColumns, window: usize) -> Expr {
+    cols.windowed(col(cols.close.as_str()).rolling_mean(fixed_window(window)))
+        .alias(format!("SMA_{window}"))
+}
+
+/// 지수이동평균 (`EMA_{span}`)
+pub fn ema(cols: &PriceColumns, span: usize) -> Expr {
+    cols.windowed(ewm(col(cols.close.as_str()), span_alpha(span), span))
+        .alias(format!("EMA_{span}"))
+}
+
+/// Wilder 방식 상대강도지수 (`RSI_{period}`)
+///
+/// 평균 상승폭과 평균 하락폭이 모두 0이면 50을 반환합니다.
+pub fn rsi(cols: &PriceColumns, period: usize) -> Expr {
+    let close = col(cols.close.as_str());
+    let delta = close.clone() - close.shift(lit(1));
+    let gain = when(delta.clone().gt(lit(0.0)))
+        .then(delta.clone())
+        .otherwise(lit(0.0));
+    let loss = when(delta.clone().lt(lit(0.0)))
+        .then(-delta.clone())
+        .otherwise(lit(0.0));
+    // 첫 행의 대비는 정의되지 않으므로 null로 유지
+    let gain = when(delta.clone().is_null())
+        .then(lit(NULL))
+        .otherwise(gain);
+    let loss = when(delta.is_null()).then(lit(NULL)).otherwise(loss);
+
+    let alpha = 1.0 / period as f64;
+    let avg_gain = ewm(gain, alpha, period);
+    let avg_loss = ewm(loss, alpha, period);
+    // 기간 내 가격 변화가 전혀 없으면 0/0이 되므로 중립값 50으로 둡니다.
+    let rsi = when(
+        avg_gain
+            .clone()
+            .eq(lit(0.0))
+            .and(avg_loss.clone().eq(lit(0.0))),
+    )
+    .then(lit(50.0))
+    .otherwise(lit(100.0) - lit(100.0) / (lit(1.0) + avg_gain / avg_loss));
+
+    cols.windowed(rsi).alias(format!("RSI_{period}"))
+}
+
+/// MACD 라인, 시그널, 히스토그램 (`MACD`, `MACD_signal`, `MACD_hist`)
+pub fn macd(cols: &PriceColumns, fast: usize, slow: usize, signal: usize) -> [Expr; 3] {
+    let close = col(cols.close.as_str());
+    let line = ewm(close.clone(), span_alpha(fast), slow) - ewm(close, span_alpha(slow), slow);
+    let signal_line = ewm(line.clone(), span_alpha(signal), signal);
+    let hist = line.clone() - signal_line.clone();
+
+    [
+        cols.windowed(line).alias("MACD"),
+        cols.windowed(signal_line).alias("MACD_signal"),
+        cols.windowed(hist).alias("MACD_hist"),
+    ]
+}
+
+/// 볼린저 밴드 상단, 중단, 하단 (`BB_upper_{window}`, `BB_middle_{window}`, `BB_lower_{window}`)
+///
+/// 표준편차는 모표준편차(ddof = 0)를 사용합니다.
+pub fn bollinger_bands(cols: &PriceColumns, window: usize, num_std: f64) -> [Expr; 3] {
+    let close = col(cols.close.as_str());
+    let middle = close.clone().rolling_mean(fixed_window(window));
+    let std = close.rolling_std(RollingOptionsFixedWindow {
+        fn_params: Some(RollingFnParams::Var(RollingVarParams { ddof: 0 })),
+        ..fixed_window(window)
+    });
+
+    [
+        cols.windowed(middle.clone() + lit(num_std) * std.clone())
+            .alias(format!("BB_upper_{window}")),
+        cols.windowed(middle.clone())
+            .alias(format!("BB_middle_{window}")),
+        cols.windowed(middle - lit(num_std) * std)
+            .alias(format!("BB_lower_{window}")),
+    ]
+}
+
+/// Wilder 방식 평균진폭 (`ATR_{period}`)
+///
+/// 고가나 저가 컬럼이 `None`이면 전일 대비 종가 변동폭(`|종가 - 전일종가|`)을
+/// 진폭으로 사용하며, 이 경우 첫 행은 `null`입니다.
+pub fn atr(cols: &PriceColumns, period: usize) -> Expr {
+    let close = col(cols.close.as_str());
+    let prev_close = close.clone().shift(lit(1));
+
+    let true_range = match (cols.high.as_deref(), cols.low.as_deref()) {
+        (Some(high), Some(low)) => {
+            let high = col(high);
+            let low = col(low);
+            // 첫 행은 전일 종가가 없으므로 고가 - 저가만 사용
+            let max = |a: Expr, b: Expr| when(a.clone().gt_eq(b.clone())).then(a).otherwise(b);
+            max(
+                max(
+                    high.clone() - low.clone(),
+                    (high - prev_close.clone()).abs().fill_null(lit(0.0)),
+                ),
+                (low - prev_close).abs().fill_null(lit(0.0)),
+            )
+        }
+        _ => (close - prev_close).abs(),
+    };
+
+    cols.windowed(ewm(true_range, 1.0 / period as f64, period))
+        .alias(format!("ATR_{period}"))
+}
+
+/// 누적 거래량 지표 (`OBV`)
+pub fn obv(cols: &PriceColumns) -> Expr {
+    let close = col(cols.close.as_str());
+    let volume = col(cols.volume.as_str()).cast(DataType::Float64);
+    let prev_close = close.clone().shift(lit(1));
+
+    let signed_volume = when(close.clone().gt(prev_close.clone()))
+        .then(volume.clone())
+        .when(close.lt(prev_close))
+        .then(-volume)
+        .otherwise(lit(0.0));
+
+    cols.windowed(signed_volume.cum_sum(false)).alias("OBV")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 두 종목의 날짜가 섞인 패널
+    fn panel() -> DataFrame {
+        df! {
+            "날짜" => ["2024-01-03", "2024-01-02", "2024-01-02", "2024-01-04", "2024-01-03", "2024-01-04"],
+            "종목코드" => ["A", "A", "B", "A", "B", "B"],
+            "시가" => [11.0, 10.0, 100.0, 12.0, 99.0, 97.0],
+            "고가" => [12.0, 11.0, 101.0, 13.0, 100.0, 98.0],
+            "저가" => [10.0, 9.0, 99.0, 11.0, 97.0, 95.0],
+            "종가" => [11.0, 10.0, 100.0, 12.0, 98.0, 96.0],
+            "거래량" => [200i64, 100, 1000, 300, 2000, 3000],
+        }
+        .unwrap()
+    }
+
+    fn value_at(df: &DataFrame, column: &str, date: &str, code: &str) -> Option<f64> {
+        df.clone()
+            .lazy()
+            .filter(col("날짜").eq(lit(date)).and(col("종목코드").eq(lit(code))))
+            .collect()
+            .unwrap()
+            .column(column)
+            .unwrap()
+            .f64()
+            .unwrap()
+            .get(0)
+    }
+
+    #[test]
+    fn test_sma_partitioned_and_ordered() {
+        let cols = PriceColumns::detect(&panel());
+        let df = panel().lazy().with_column(sma(&cols, 2)).collect().unwrap();
+
+        assert_eq!(value_at(&df, "SMA_2", "2024-01-02", "A"), None);
+        assert_eq!(value_at(&df, "SMA_2", "2024-01-03", "A"), Some(10.5));
+        assert_eq!(value_at(&df, "SMA_2", "2024-01-04", "A"), Some(11.5));
+        assert_eq!(value_at(&df, "SMA_2", "2024-01-04", "B"), Some(97.0));
+    }
+
+    #[test]
+    fn test_obv() {
+        let cols = PriceColumns::default();
+        let df = panel().lazy().with_column(obv(&cols)).collect().unwrap();
+
+        assert_eq!(value_at(&df, "OBV", "2024-01-04", "A"), Some(500.0));
+        assert_eq!(value_at(&df, "OBV", "2024-01-04", "B"), Some(-5000.0));
+    }
+
+    #[test]
+    fn test_rsi_monotonic_series() {
+        let cols = PriceColumns::default();
+        let df = panel().lazy().with_column(rsi(&cols, 2)).collect().unwrap();
+
+        assert_eq!(value_at(&df, "RSI_2", "2024-01-04", "A"), Some(100.0));
+        assert_eq!(value_at(&df, "RSI_2", "2024-01-04", "B"), Some(0.0));
+    }
+
+    #[test]
+    fn test_bollinger_bands_and_atr() {
+        let cols = PriceColumns::default();
+        let [upper, middle, lower] = bollinger_bands(&cols, 2, 2.0);
+        let df = panel()
+            .lazy()
+            .with_columns([upper, middle, lower, atr(&cols, 2)])
+            .collect()
+            .unwrap();
+
+        // A: 10, 11 → 평균 10.5, 모표준편차 0.5
+        assert_eq!(value_at(&df, "BB_upper_2", "2024-01-03", "A"), Some(11.5));
+        assert_eq!(value_at(&df, "BB_lower_2", "2024-01-03", "A"), Some(9.5));
+        // TR: 2(1/2), 2(1/3), 2(1/4) → ATR 2
+        assert_eq!(value_at(&df, "ATR_2", "2024-01-04", "A"), Some(2.0));
+    }
+
+    #[test]
+    fn test_rsi_flat_series_is_neutral() {
+        let df = df! {
+            "날짜" => ["2024-01-02", "2024-01-03", "2024-01-04"],
+            "종목코드" => ["A"; 3],
+            "종가" => [10.0, 10.0, 10.0],
+        }
+        .unwrap();
+        let df = df
+            .lazy()
+            .with_column(rsi(&PriceColumns::default(), 2))
+            .collect()
+            .unwrap();
+
+        assert_eq!(value_at(&df, "RSI_2", "2024-01-04", "A"), Some(50.0));
+    }
+
+    #[test]
+    fn test_derivative_frame_without_ohlc() {
+        // 선물 일별 시세처럼 시가·고가·저가가 없는 DataFrame
+        let df = df! {
+            "날짜" => ["2024-01-02", "2024-01-03", "2024-01-04"],
+            "종목코드" => ["KR4101"; 3],
+            "종가" => [350.0, 353.0, 352.0],
+            "거래량" => [100i64, 200, 300],
+        }
+        .unwrap();
+        let cols = PriceColumns::detect(&df);
+        assert_eq!(cols.partition.as_deref(), Some("종목코드"));
+        assert_eq!(
+            (
+                cols.open.as_deref(),
+                cols.high.as_deref(),
+                cols.low.as_deref()
+            ),
+            (None, None, None)
+        );
+
+        let [upper, middle, lower] = bollinger_bands(&cols, 2, 2.0);
+        let df = df
+            .lazy()
+            .with_columns([upper, middle, lower, atr(&cols, 2)])
+            .collect()
+            .unwrap();
+
+        // 종가 변동폭: null, 3, 1 → ATR 2
+        assert_eq!(value_at(&df, "ATR_2", "2024-01-04", "KR4101"), Some(2.0));
+        assert_eq!(
+            value_at(&df, "BB_middle_2", "2024-01-04", "KR4101"),
+            Some(352.5)
+        );
+    }
+
+    #[test]
+    fn test_single_series_without_partition() {
+        let df = df! {
+            "날짜" => ["2024-01-04", "2024-01-02", "2024-01-03"],
+            "종가" => [12.0, 10.0, 11.0],
+        }
+        .unwrap();
+        let cols = PriceColumns::detect(&df);
+        assert_eq!(cols.partition, None);
+
+        let df = df
+            .lazy()
+            .with_column(sma(&cols, 2))
+            .sort(["날짜"], SortMultipleOptions::default())
+            .collect()
+            .unwrap();
+
+        let sma = df.column("SMA_2").unwrap().f64().unwrap();
+        assert_eq!(
+            sma.into_iter().collect::<Vec<_>>(),
+            [None, Some(10.5), Some(11.5)]
+        );
+    }
+
+    #[test]
+    fn test_macd_and_index_columns() {
+        let df = df! {
+            "날짜" => ["2024-01-02", "2024-01-03", "2024-01-04"],
+            "지수명" => ["코스피 200"; 3],
+            "종가" => [350.0, 351.0, 352.0],
+        }
+        .unwrap();
+        let cols = PriceColumns::detect(&df);
+        assert_eq!(cols.partition.as_deref(), Some("지수명"));
+
+        let df = df
+            .lazy()
+            .with_columns(macd(&cols, 1, 2, 1))
+            .with_column(ema(&cols, 2))
+            .collect()
+            .unwrap();
+
+        let hist = df.column("MACD_hist").unwrap().f64().unwrap();
+        assert!(hist.get(2).is_some());
+        assert_eq!(df.column("EMA_2").unwrap().f64().unwrap().get(0), None);
+    }
+}
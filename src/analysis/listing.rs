@@ -0,0 +1,612 @@
+//! 종목 기본정보 스냅샷 비교로 상장 변동을 찾습니다.
+//!
+//! 유가증권·코스닥·코넥스 종목기본정보(`*_isu_base_info`)를 표준코드(`ISU_CD`) 기준으로
+//! 두 기준일 사이에서 비교하여 신규상장, 상장폐지, 종목명·약명 변경, 시장 이전
+//! (KOSDAQ→KOSPI 등), 상장주식수 변경을 [`ListingChanges`]로 돌려줍니다.
+//! 시장 이전을 잡기 위해 세 시장의 기본정보를 하나의 스냅샷으로 합쳐 비교합니다.
+//!
+//! # 예시
+//! ```rust,no_run
+//! # use krx_rs::Client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), krx_rs::error::Error> {
+//! # let client = Client::new("YOUR_AUTH_KEY");
+//! let changes = client.stock().listing_changes("20240102", "20240105").await?;
+//! for change in changes.new_listings() {
+//!     println!("신규상장: {} ({})", change.name, change.short_code);
+//! }
+//!
+//! let history = client
+//!     .stock()
+//!     .listing_history("20240101", "20240131")
+//!     .fetch()
+//!     .await?;
+//! println!("{}", history.to_dataframe()?);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    api::endpoint::{KonexBaseInfo, KosdaqBaseInfo, StockBaseInfo},
+    calendar::TradingCalendar,
+    client::Client,
+    data::stock::StockBaseInfoRecord,
+    error::{Error, Result},
+};
+use chrono::NaiveDate;
+use futures::{StreamExt, stream};
+use polars::prelude::*;
+use std::{collections::HashMap, fmt};
+use tracing::debug;
+
+/// 기본 동시 요청 기준일 수
+pub const DEFAULT_CONCURRENCY: usize = 2;
+
+/// 상장 변동 종류
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListingChangeKind {
+    /// 신규상장
+    Listed,
+    /// 상장폐지
+    Delisted,
+    /// 한글 종목명 변경
+    Renamed { from: String, to: String },
+    /// 한글 종목약명 변경
+    AbbreviationChanged { from: String, to: String },
+    /// 시장 이전 (예: KOSDAQ → KOSPI)
+    MarketTransferred { from: String, to: String },
+    /// 상장주식수 변경
+    SharesChanged { from: Option<u64>, to: Option<u64> },
+}
+
+impl ListingChangeKind {
+    /// 변동 구분 명칭
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Listed => "신규상장",
+            Self::Delisted => "상장폐지",
+            Self::Renamed { .. } => "종목명변경",
+            Self::AbbreviationChanged { .. } => "약명변경",
+            Self::MarketTransferred { .. } => "시장이전",
+            Self::SharesChanged { .. } => "상장주식수변경",
+        }
+    }
+
+    /// 변경 전·후 값 (신규상장·상장폐지는 `None`)
+    pub fn values(&self) -> (Option<String>, Option<String>) {
+        match self {
+            Self::Listed | Self::Delisted => (None, None),
+            Self::Renamed { from, to }
+            | Self::AbbreviationChanged { from, to }
+            | Self::MarketTransferred { from, to } => (Some(from.clone()), Some(to.clone())),
+            Self::SharesChanged { from, to } => {
+                (from.map(|v| v.to_string()), to.map(|v| v.to_string()))
+            }
+        }
+    }
+}
+
+impl fmt::Display for ListingChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.values() {
+            (Some(from), Some(to)) => write!(f, "{} ({from} -> {to})", self.as_str()),
+            _ => f.write_str(self.as_str()),
+        }
+    }
+}
+
+/// 종목 하나의 상장 변동
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListingChange {
+    /// 표준코드
+    pub issue_code: String,
+    /// 단축코드
+    pub short_code: String,
+    /// 한글 종목약명 (상장폐지는 변경 전, 그 외는 변경 후 기준)
+    pub name: String,
+    /// 시장구분 (상장폐지는 변경 전, 그 외는 변경 후 기준)
+    pub market: String,
+    /// 변동 종류
+    pub kind: ListingChangeKind,
+}
+
+/// 두 스냅샷 사이의 상장 변동 목록
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListingChanges {
+    changes: Vec<ListingChange>,
+}
+
+impl ListingChanges {
+    /// 모든 변동 (표준코드 순)
+    pub fn changes(&self) -> &[ListingChange] {
+        &self.changes
+    }
+
+    /// 변동 수
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// 변동이 없는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// 신규상장
+    pub fn new_listings(&self) -> impl Iterator<Item = &ListingChange> {
+        self.filter(|kind| matches!(kind, ListingChangeKind::Listed))
+    }
+
+    /// 상장폐지
+    pub fn delistings(&self) -> impl Iterator<Item = &ListingChange> {
+        self.filter(|kind| matches!(kind, ListingChangeKind::Delisted))
+    }
+
+    /// 종목명·약명 변경
+    pub fn renames(&self) -> impl Iterator<Item = &ListingChange> {
+        self.filter(|kind| {
+            matches!(
+                kind,
+                ListingChangeKind::Renamed { .. } | ListingChangeKind::AbbreviationChanged { .. }
+            )
+        })
+    }
+
+    /// 시장 이전
+    pub fn market_transfers(&self) -> impl Iterator<Item = &ListingChange> {
+        self.filter(|kind| matches!(kind, ListingChangeKind::MarketTransferred { .. }))
+    }
+
+    /// 상장주식수 변경
+    pub fn share_changes(&self) -> impl Iterator<Item = &ListingChange> {
+        self.filter(|kind| matches!(kind, ListingChangeKind::SharesChanged { .. }))
+    }
+
+    /// 표준코드 또는 단축코드가 같은 종목의 변동
+    pub fn for_issue<'a>(&'a self, code: &'a str) -> impl Iterator<Item = &'a ListingChange> {
+        self.changes
+            .iter()
+            .filter(move |change| change.issue_code == code || change.short_code == code)
+    }
+
+    /// 변동 목록을 DataFrame으로 변환합니다.
+    ///
+    /// 컬럼: 표준코드, 단축코드, 종목약명, 시장구분, 변동구분, 변경전, 변경후
+    pub fn to_dataframe(&self) -> Result<DataFrame> {
+        changes_dataframe(self.changes.iter().map(|change| (None, change)))
+    }
+
+    fn filter(
+        &self,
+        predicate: impl Fn(&ListingChangeKind) -> bool,
+    ) -> impl Iterator<Item = &ListingChange> {
+        self.changes
+            .iter()
+            .filter(move |change| predicate(&change.kind))
+    }
+}
+
+impl IntoIterator for ListingChanges {
+    type Item = ListingChange;
+    type IntoIter = std::vec::IntoIter<ListingChange>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.into_iter()
+    }
+}
+
+/// 두 종목기본정보 스냅샷을 표준코드 기준으로 비교합니다.
+///
+/// 시장 이전을 찾으려면 각 스냅샷에 유가증권·코스닥·코넥스 기본정보를 모두 넣어야
+/// 합니다. 한 종목에 여러 변동이 있으면 각각 별도 항목으로 반환합니다.
+pub fn diff_listings(
+    before: &[StockBaseInfoRecord],
+    after: &[StockBaseInfoRecord],
+) -> ListingChanges {
+    let before_by_code: HashMap<&str, &StockBaseInfoRecord> = before
+        .iter()
+        .map(|record| (record.issue_code.as_str(), record))
+        .collect();
+    let after_by_code: HashMap<&str, &StockBaseInfoRecord> = after
+        .iter()
+        .map(|record| (record.issue_code.as_str(), record))
+        .collect();
+
+    let mut changes = Vec::new();
+    for (code, new) in &after_by_code {
+        let Some(old) = before_by_code.get(code) else {
+            changes.push(change(new, ListingChangeKind::Listed));
+            continue;
+        };
+        if old.issue_name != new.issue_name {
+            changes.push(change(
+                new,
+                ListingChangeKind::Renamed {
+                    from: old.issue_name.clone(),
+                    to: new.issue_name.clone(),
+                },
+            ));
+        }
+        if old.issue_abbreviation != new.issue_abbreviation {
+            changes.push(change(
+                new,
+                ListingChangeKind::AbbreviationChanged {
+                    from: old.issue_abbreviation.clone(),
+                    to: new.issue_abbreviation.clone(),
+                },
+            ));
+        }
+        if old.market_type != new.market_type {
+            changes.push(change(
+                new,
+                ListingChangeKind::MarketTransferred {
+                    from: old.market_type.clone(),
+                    to: new.market_type.clone(),
+                },
+            ));
+        }
+        if old.listed_shares != new.listed_shares {
+            changes.push(change(
+                new,
+                ListingChangeKind::SharesChanged {
+                    from: old.listed_shares,
+                    to: new.listed_shares,
+                },
+            ));
+        }
+    }
+    for (code, old) in &before_by_code {
+        if !after_by_code.contains_key(code) {
+            changes.push(change(old, ListingChangeKind::Delisted));
+        }
+    }
+
+    // 같은 종목의 변동은 ListingChangeKind 선언 순서를 따릅니다.
+    changes.sort_by(|a, b| {
+        a.issue_code
+            .cmp(&b.issue_code)
+            .then(kind_order(&a.kind).cmp(&kind_order(&b.kind)))
+    });
+    ListingChanges { changes }
+}
+
+fn change(record: &StockBaseInfoRecord, kind: ListingChangeKind) -> ListingChange {
+    ListingChange {
+        issue_code: record.issue_code.clone(),
+        short_code: record.issue_short_code.clone(),
+        name: record.issue_abbreviation.clone(),
+        market: record.market_type.clone(),
+        kind,
+    }
+}
+
+fn kind_order(kind: &ListingChangeKind) -> u8 {
+    match kind {
+        ListingChangeKind::Listed => 0,
+        ListingChangeKind::Delisted => 1,
+        ListingChangeKind::Renamed { .. } => 2,
+        ListingChangeKind::AbbreviationChanged { .. } => 3,
+        ListingChangeKind::MarketTransferred { .. } => 4,
+        ListingChangeKind::SharesChanged { .. } => 5,
+    }
+}
+
+fn changes_dataframe<'a>(
+    rows: impl Iterator<Item = (Option<NaiveDate>, &'a ListingChange)>,
+) -> Result<DataFrame> {
+    let mut dates = Vec::new();
+    let mut issue_codes = Vec::new();
+    let mut short_codes = Vec::new();
+    let mut names = Vec::new();
+    let mut markets = Vec::new();
+    let mut kinds = Vec::new();
+    let mut befores = Vec::new();
+    let mut afters = Vec::new();
+
+    for (date, change) in rows {
+        let (before, after) = change.kind.values();
+        dates.push(date.map(|date| date.format("%Y-%m-%d").to_string()));
+        issue_codes.push(change.issue_code.clone());
+        short_codes.push(change.short_code.clone());
+        names.push(change.name.clone());
+        markets.push(change.market.clone());
+        kinds.push(change.kind.as_str());
+        befores.push(before);
+        afters.push(after);
+    }
+
+    let with_dates = dates.iter().any(Option::is_some);
+    let mut df = df! {
+        "표준코드" => issue_codes,
+        "단축코드" => short_codes,
+        "종목약명" => names,
+        "시장구분" => markets,
+        "변동구분" => kinds,
+        "변경전" => befores,
+        "변경후" => afters,
+    }?;
+    if with_dates {
+        df.insert_column(0, Column::new("날짜".into(), dates))?;
+    }
+    Ok(df)
+}
+
+/// 기준일자(YYYYMMDD)의 유가증권·코스닥·코넥스 종목기본정보를 합친 스냅샷을 조회합니다.
+pub async fn fetch_listing_snapshot(
+    client: &Client,
+    date: &str,
+) -> Result<Vec<StockBaseInfoRecord>> {
+    Ok(fetch_market_snapshots(client, date)
+        .await?
+        .into_iter()
+        .flatten()
+        .collect())
+}
+
+/// [`fetch_market_snapshots`]가 돌려주는 시장 순서
+const MARKET_NAMES: [&str; 3] = ["KOSPI", "KOSDAQ", "KONEX"];
+
+/// 두 기준일자의 스냅샷을 조회하여 비교합니다.
+///
+/// 한 시장이라도 스냅샷이 비어 있으면 그 시장 종목이 모두 상장·상장폐지된 것처럼
+/// 보이므로 `Error::InvalidInput`을 반환합니다.
+pub(crate) async fn fetch_listing_changes(
+    client: &Client,
+    from: &str,
+    to: &str,
+) -> Result<ListingChanges> {
+    let (before, after) = futures::try_join!(
+        fetch_complete_snapshot(client, from),
+        fetch_complete_snapshot(client, to),
+    )?;
+    Ok(diff_listings(&before, &after))
+}
+
+/// 세 시장 스냅샷이 모두 있을 때만 합친 스냅샷을 반환합니다.
+async fn fetch_complete_snapshot(client: &Client, date: &str) -> Result<Vec<StockBaseInfoRecord>> {
+    let markets = fetch_market_snapshots(client, date).await?;
+    if let Some(index) = markets.iter().position(Vec::is_empty) {
+        return Err(Error::InvalidInput(format!(
+            "{} listing snapshot for {date} is empty (holiday or not yet published)",
+            MARKET_NAMES[index]
+        )));
+    }
+    Ok(markets.into_iter().flatten().collect())
+}
+
+/// 유가증권·코스닥·코넥스 종목기본정보를 시장별로 조회합니다.
+async fn fetch_market_snapshots(
+    client: &Client,
+    date: &str,
+) -> Result<[Vec<StockBaseInfoRecord>; 3]> {
+    let (kospi, kosdaq, konex) = futures::try_join!(
+        client.fetch_records::<StockBaseInfo>(date),
+        client.fetch_records::<KosdaqBaseInfo>(date),
+        client.fetch_records::<KonexBaseInfo>(date),
+    )?;
+    Ok([kospi, kosdaq, konex])
+}
+
+/// 날짜별 상장 변동 이력
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListingHistory {
+    days: Vec<(NaiveDate, ListingChanges)>,
+}
+
+impl ListingHistory {
+    /// 변동이 있었던 날짜와 그날의 변동 (날짜 순)
+    pub fn days(&self) -> &[(NaiveDate, ListingChanges)] {
+        &self.days
+    }
+
+    /// 모든 변동을 날짜와 함께 순회합니다.
+    pub fn changes(&self) -> impl Iterator<Item = (NaiveDate, &ListingChange)> {
+        self.days
+            .iter()
+            .flat_map(|(date, changes)| changes.changes().iter().map(move |change| (*date, change)))
+    }
+
+    /// 표준코드 또는 단축코드가 같은 종목의 변동 이력
+    pub fn for_issue<'a>(
+        &'a self,
+        code: &'a str,
+    ) -> impl Iterator<Item = (NaiveDate, &'a ListingChange)> {
+        self.changes()
+            .filter(move |(_, change)| change.issue_code == code || change.short_code == code)
+    }
+
+    /// 변동이 없는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.days.is_empty()
+    }
+
+    /// 이력을 DataFrame으로 변환합니다.
+    ///
+    /// 컬럼: 날짜, 표준코드, 단축코드, 종목약명, 시장구분, 변동구분, 변경전, 변경후
+    pub fn to_dataframe(&self) -> Result<DataFrame> {
+        if self.days.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+        changes_dataframe(self.changes().map(|(date, change)| (Some(date), change)))
+    }
+}
+
+/// 기간 상장 변동 이력 빌더
+///
+/// [`StockApi::listing_history`](crate::api::stock::StockApi::listing_history)로 만듭니다.
+#[must_use = "Builder does nothing unless you call .fetch()"]
+pub struct ListingHistoryBuilder {
+    client: Client,
+    start_date: String,
+    end_date: String,
+    calendar: TradingCalendar,
+    concurrency: usize,
+}
+
+impl ListingHistoryBuilder {
+    pub(crate) fn new(client: Client, start_date: String, end_date: String) -> Self {
+        Self {
+            client,
+            start_date,
+            end_date,
+            calendar: TradingCalendar::new(),
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// 조회할 거래일을 정할 달력 (기본값: [`TradingCalendar::new`], 내장 KRX 휴장일표 포함)
+    pub fn calendar(mut self, calendar: TradingCalendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// 동시에 조회할 기준일 수 (기준일마다 세 시장을 요청합니다, 최소 1)
+    pub fn concurrency(mut self, limit: usize) -> Self {
+        self.concurrency = limit.max(1);
+        self
+    }
+
+    /// 기간 안의 거래일마다 스냅샷을 조회하여 바로 앞 스냅샷과 비교합니다.
+    ///
+    /// 첫 거래일은 비교 기준으로만 쓰입니다. 세 시장 중 하나라도 스냅샷이 비어 있는 날
+    /// (달력에 없는 휴장일, 일부 시장만 공개된 날 등)은 그 시장 종목이 모두 상장폐지된
+    /// 것처럼 보이지 않도록 건너뜁니다.
+    ///
+    /// # Errors
+    /// - 시작일·종료일 형식이 잘못되었거나 시작일이 종료일보다 늦으면 `Error::InvalidInput` 반환.
+    /// - 조회 중 하나라도 실패하면 해당 오류 반환.
+    pub async fn fetch(self) -> Result<ListingHistory> {
+        let dates = self
+            .calendar
+            .trading_days(&self.start_date, &self.end_date)?
+            .into_iter()
+            .map(|date| {
+                NaiveDate::parse_from_str(&date, "%Y%m%d")
+                    .map(|parsed| (date, parsed))
+                    .map_err(|_| Error::InvalidInput("date must be in YYYYMMDD format".to_string()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        debug!(
+            start_date = %self.start_date,
+            end_date = %self.end_date,
+            trading_days = dates.len(),
+            "Building listing history"
+        );
+
+        let client = self.client;
+        let mut snapshots = stream::iter(dates)
+            .map(|(date, parsed)| {
+                let client = client.clone();
+                async move {
+                    fetch_market_snapshots(&client, &date)
+                        .await
+                        .map(|markets| (parsed, markets))
+                }
+            })
+            .buffered(self.concurrency);
+
+        let mut history = ListingHistory::default();
+        let mut previous: Option<Vec<StockBaseInfoRecord>> = None;
+        while let Some(snapshot) = snapshots.next().await {
+            let (date, markets) = snapshot?;
+            if markets.iter().any(Vec::is_empty) {
+                debug!(
+                    date = %date,
+                    kospi = markets[0].len(),
+                    kosdaq = markets[1].len(),
+                    konex = markets[2].len(),
+                    "Skipping incomplete listing snapshot"
+                );
+                continue;
+            }
+            let snapshot: Vec<_> = markets.into_iter().flatten().collect();
+            if let Some(previous) = &previous {
+                let changes = diff_listings(previous, &snapshot);
+                if !changes.is_empty() {
+                    history.days.push((date, changes));
+                }
+            }
+            previous = Some(snapshot);
+        }
+        Ok(history)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(code: &str, name: &str, market: &str, shares: u64) -> StockBaseInfoRecord {
+        StockBaseInfoRecord {
+            issue_code: format!("KR7{code}000"),
+            issue_short_code: code.to_string(),
+            issue_name: format!("{name}보통주"),
+            issue_abbreviation: name.to_string(),
+            issue_english_name: String::new(),
+            listing_date: "20200101".to_string(),
+            market_type: market.to_string(),
+            security_group: "주권".to_string(),
+            sector_type: "-".to_string(),
+            stock_type: "보통주".to_string(),
+            par_value: Some(500.0),
+            listed_shares: Some(shares),
+        }
+    }
+
+    #[test]
+    fn test_diff_listings() {
+        let before = [
+            record("000010", "가나", "KOSDAQ", 100),
+            record("000020", "다라", "KOSPI", 100),
+            record("000030", "마바", "KOSPI", 100),
+        ];
+        let after = [
+            record("000010", "가나", "KOSPI", 150),
+            record("000030", "사아", "KOSPI", 100),
+            record("000040", "자차", "KONEX", 100),
+        ];
+        let changes = diff_listings(&before, &after);
+
+        let kinds: Vec<(&str, &str)> = changes
+            .changes()
+            .iter()
+            .map(|c| (c.short_code.as_str(), c.kind.as_str()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("000010", "시장이전"),
+                ("000010", "상장주식수변경"),
+                ("000020", "상장폐지"),
+                ("000030", "종목명변경"),
+                ("000030", "약명변경"),
+                ("000040", "신규상장"),
+            ]
+        );
+        assert_eq!(
+            changes.market_transfers().next().unwrap().kind,
+            ListingChangeKind::MarketTransferred {
+                from: "KOSDAQ".to_string(),
+                to: "KOSPI".to_string()
+            }
+        );
+        assert_eq!(changes.delistings().next().unwrap().market, "KOSPI");
+        assert_eq!(changes.renames().count(), 2);
+        assert_eq!(changes.for_issue("000010").count(), 2);
+        assert!(diff_listings(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn test_changes_to_dataframe() {
+        let before = [record("000010", "가나", "KOSPI", 100)];
+        let after = [record("000010", "가나", "KOSPI", 200)];
+        let df = diff_listings(&before, &after).to_dataframe().unwrap();
+
+        assert_eq!(df.height(), 1);
+        assert_eq!(df.get_column_names()[0].as_str(), "표준코드");
+        assert_eq!(
+            df.column("변경후").unwrap().str().unwrap().get(0),
+            Some("200")
+        );
+    }
+}
@@ -0,0 +1,21 @@
+//! KRX 조회 결과를 가공하는 분석 유틸리티 모듈입니다.
+//!
+//! 각 서브 모듈은 `data::*` 레코드나 빌더가 반환한 DataFrame을 입력으로 받아
+//! 파생 지표를 계산합니다.
+
+/// 채권지수 그룹·지수 구분 분류와 시계열/비교 변환
+pub mod bond_index;
+/// 시장 폭(등락 종목수, 상승/하락 거래량 등) 통계
+pub mod breadth;
+/// 기술적 지표(SMA, EMA, RSI, MACD 등) 표현식
+pub mod indicators;
+/// 종목기본정보 비교로 찾는 신규상장·상장폐지·명칭 변경·시장 이전
+pub mod listing;
+/// 날짜 × 종목 와이드 패널 변환과 거래일 정렬
+pub mod panel;
+/// 수익률, 변동성, 낙폭, 베타, 상관·공분산 분석
+pub mod risk;
+/// 사회책임투자(SRI) 채권 분류 및 잔액 집계
+pub mod sri;
+/// 신주인수권증권/증서 가치평가
+pub mod valuation;
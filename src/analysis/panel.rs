@@ -0,0 +1,378 @@
+//! 단면(cross-sectional) 분석용 와이드 패널 변환.
+//!
+//! 일별 빌더가 반환한 long 형식 DataFrame(날짜 × 종목 한 행)을 `날짜` 컬럼과
+//! 종목별 컬럼으로 이루어진 와이드 패널로 바꾸고, 다시 long 형식으로 되돌립니다.
+//! 패널의 행은 [`TradingCalendar`]의 거래일에 맞춰 정렬되며, 비어 있는 칸은
+//! [`FillPolicy`]에 따라 `null`로 두거나 직전 값으로 채웁니다.
+//!
+//! `ndarray` 기능을 켜면 `to_ndarray`로 패널을 `Array2<f64>`로 내보낼 수 있습니다.
+//!
+//! # 예시
+//! ```rust,no_run
+//! # use krx_rs::Client;
+//! use krx_rs::analysis::panel::{self, FillPolicy, PanelOptions};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), krx_rs::error::Error> {
+//! # let client = Client::new("YOUR_AUTH_KEY");
+//! let mut df = client.stock().stock_daily().date("20240104").fetch().await?;
+//! df.vstack_mut(&client.stock().stock_daily().date("20240105").fetch().await?)?;
+//!
+//! let options = PanelOptions::detect(&df).fill(FillPolicy::ForwardFill);
+//! let close = panel::to_wide(&df, "종가", &options)?;
+//! let long = panel::to_long(&close, "종가", &options)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    calendar::TradingCalendar,
+    error::{Error, Result},
+};
+use chrono::NaiveDate;
+use polars::prelude::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// 패널 날짜 컬럼의 문자열 형식
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// 패널의 빈 칸 처리 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FillPolicy {
+    /// 값이 없는 칸을 `null`로 둡니다.
+    #[default]
+    Null,
+    /// 같은 종목의 직전 값으로 채웁니다. 첫 값 이전은 `null`로 남습니다.
+    ForwardFill,
+}
+
+/// 패널 변환 옵션
+#[derive(Debug, Clone, PartialEq)]
+pub struct PanelOptions {
+    /// 날짜 컬럼 (`%Y-%m-%d` 또는 `YYYYMMDD` 문자열, 또는 `Date`)
+    pub date: String,
+    /// 패널 컬럼이 될 식별자 컬럼
+    pub key: String,
+    /// 빈 칸 처리 방식
+    pub fill: FillPolicy,
+    /// 행 정렬에 사용할 거래일 달력 (`None`이면 입력에 있는 날짜만 사용)
+    pub calendar: Option<TradingCalendar>,
+}
+
+impl Default for PanelOptions {
+    /// 종목코드를 컬럼으로 하는 종목 시세용 설정
+    fn default() -> Self {
+        Self {
+            date: "날짜".to_string(),
+            key: "종목코드".to_string(),
+            fill: FillPolicy::default(),
+            calendar: Some(TradingCalendar::new()),
+        }
+    }
+}
+
+impl PanelOptions {
+    /// 지수명을 컬럼으로 하는 지수 시세용 설정
+    pub fn index() -> Self {
+        Self {
+            key: "지수명".to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// DataFrame의 컬럼 구성을 보고 종목 또는 지수 설정을 선택합니다.
+    pub fn detect(df: &DataFrame) -> Self {
+        if !df.schema().contains("종목코드") && df.schema().contains("지수명") {
+            Self::index()
+        } else {
+            Self::default()
+        }
+    }
+
+    /// 식별자 컬럼을 변경합니다.
+    pub fn key(mut self, column: &str) -> Self {
+        self.key = column.to_string();
+        self
+    }
+
+    /// 빈 칸 처리 방식을 설정합니다.
+    pub fn fill(mut self, fill: FillPolicy) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// 행 정렬에 사용할 거래일 달력을 설정합니다.
+    pub fn calendar(mut self, calendar: Option<TradingCalendar>) -> Self {
+        self.calendar = calendar;
+        self
+    }
+}
+
+/// long 형식 DataFrame의 `value` 컬럼을 날짜 × 식별자 와이드 패널로 변환합니다.
+///
+/// 결과의 첫 컬럼은 `날짜`(`%Y-%m-%d` 문자열)이고, 나머지는 식별자 오름차순의
+/// `Float64` 컬럼입니다. 달력이 있으면 입력의 첫 날짜부터 마지막 날짜까지의
+/// 모든 거래일이 행이 되며, 달력에 없지만 입력에 있는 날짜도 포함됩니다.
+///
+/// # Errors
+/// - 컬럼이 없거나 날짜를 해석할 수 없으면 오류 반환.
+/// - 같은 날짜·식별자 조합이 두 번 이상 나오면 `Error::InvalidInput` 반환.
+pub fn to_wide(df: &DataFrame, value: &str, options: &PanelOptions) -> Result<DataFrame> {
+    let long = df
+        .clone()
+        .lazy()
+        .select([
+            col(options.date.as_str()).cast(DataType::String),
+            col(options.key.as_str()).cast(DataType::String),
+            col(value).cast(DataType::Float64),
+        ])
+        .collect()?;
+
+    let dates = long.column(options.date.as_str())?.str()?;
+    let keys = long.column(options.key.as_str())?.str()?;
+    let values = long.column(value)?.f64()?;
+
+    let mut cells: BTreeMap<&str, BTreeMap<NaiveDate, Option<f64>>> = BTreeMap::new();
+    let mut rows = BTreeSet::new();
+    for ((date, key), value) in dates.into_iter().zip(keys).zip(values) {
+        let (Some(date), Some(key)) = (date, key) else {
+            continue;
+        };
+        let date = parse_panel_date(date)?;
+        rows.insert(date);
+        if cells.entry(key).or_default().insert(date, value).is_some() {
+            return Err(Error::InvalidInput(format!(
+                "duplicate {} {key} on {date}",
+                options.key
+            )));
+        }
+    }
+
+    if let (Some(calendar), Some(first), Some(last)) =
+        (&options.calendar, rows.first(), rows.last())
+    {
+        let trading_days = calendar.trading_days(
+            &first.format("%Y%m%d").to_string(),
+            &last.format("%Y%m%d").to_string(),
+        )?;
+        for day in trading_days {
+            rows.insert(crate::calendar::parse_date(&day)?);
+        }
+    }
+
+    let mut columns = vec![Column::new(
+        options.date.as_str().into(),
+        rows.iter()
+            .map(|date| date.format(DATE_FORMAT).to_string())
+            .collect::<Vec<_>>(),
+    )];
+    for (key, series) in &cells {
+        let mut last = None;
+        let column: Vec<Option<f64>> = rows
+            .iter()
+            .map(|date| {
+                let cell = series.get(date).copied().flatten();
+                match options.fill {
+                    FillPolicy::Null => cell,
+                    FillPolicy::ForwardFill => {
+                        last = cell.or(last);
+                        last
+                    }
+                }
+            })
+            .collect();
+        columns.push(Column::new((*key).into(), column));
+    }
+
+    Ok(DataFrame::new(columns)?)
+}
+
+/// 와이드 패널을 `날짜`, 식별자, `value` 세 컬럼의 long 형식으로 되돌립니다.
+///
+/// `null` 칸은 행으로 만들지 않으며, 결과는 날짜와 식별자 순으로 정렬됩니다.
+///
+/// # Errors
+/// - 날짜 컬럼이 없거나 값 컬럼을 `Float64`로 변환할 수 없으면 오류 반환.
+pub fn to_long(panel: &DataFrame, value: &str, options: &PanelOptions) -> Result<DataFrame> {
+    let dates = panel
+        .column(options.date.as_str())?
+        .cast(&DataType::String)?;
+    let dates = dates.str()?;
+
+    let (mut out_dates, mut out_keys, mut out_values) = (Vec::new(), Vec::new(), Vec::new());
+    let columns = value_columns(panel, options)?;
+    for (row, date) in dates.into_iter().enumerate() {
+        let Some(date) = date else {
+            continue;
+        };
+        for (key, values) in &columns {
+            if let Some(value) = values.get(row) {
+                out_dates.push(date);
+                out_keys.push(key.as_str());
+                out_values.push(value);
+            }
+        }
+    }
+
+    Ok(DataFrame::new(vec![
+        Column::new(options.date.as_str().into(), out_dates),
+        Column::new(options.key.as_str().into(), out_keys),
+        Column::new(value.into(), out_values),
+    ])?)
+}
+
+/// 와이드 패널의 값 컬럼을 `Array2<f64>`(날짜 × 식별자)로 내보냅니다.
+///
+/// 행과 열 순서는 패널과 같고 `null`은 `NaN`이 됩니다. 날짜 컬럼은 제외됩니다.
+///
+/// # Errors
+/// - 날짜 컬럼이 없거나 값 컬럼을 `Float64`로 변환할 수 없으면 오류 반환.
+#[cfg(feature = "ndarray")]
+pub fn to_ndarray(panel: &DataFrame, options: &PanelOptions) -> Result<ndarray::Array2<f64>> {
+    let columns = value_columns(panel, options)?;
+    let mut array = ndarray::Array2::from_elem((panel.height(), columns.len()), f64::NAN);
+    for (j, (_, values)) in columns.iter().enumerate() {
+        for (i, value) in values.into_iter().enumerate() {
+            if let Some(value) = value {
+                array[[i, j]] = value;
+            }
+        }
+    }
+    Ok(array)
+}
+
+fn value_columns(
+    panel: &DataFrame,
+    options: &PanelOptions,
+) -> Result<Vec<(String, Float64Chunked)>> {
+    panel.column(options.date.as_str())?;
+    panel
+        .get_columns()
+        .iter()
+        .filter(|column| column.name().as_str() != options.date)
+        .map(|column| {
+            let values = column.cast(&DataType::Float64)?.f64()?.clone();
+            Ok((column.name().to_string(), values))
+        })
+        .collect()
+}
+
+fn parse_panel_date(date: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(date, DATE_FORMAT)
+        .or_else(|_| NaiveDate::parse_from_str(date, "%Y%m%d"))
+        .map_err(|_| Error::InvalidInput(format!("invalid panel date: {date}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stocks() -> DataFrame {
+        // 2024-01-05(금)와 2024-01-08(월) 사이 주말, 2024-01-09는 B 종목만 거래
+        df! {
+            "날짜" => ["2024-01-04", "2024-01-04", "2024-01-05", "2024-01-09", "2024-01-09"],
+            "종목코드" => ["B", "A", "A", "A", "B"],
+            "종가" => [20.0, 10.0, 11.0, 12.0, 21.0],
+        }
+        .unwrap()
+    }
+
+    fn column(df: &DataFrame, name: &str) -> Vec<Option<f64>> {
+        df.column(name)
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_to_wide_aligns_to_calendar() {
+        let wide = to_wide(&stocks(), "종가", &PanelOptions::default()).unwrap();
+
+        let names: Vec<&str> = wide.get_column_names().iter().map(|n| n.as_str()).collect();
+        assert_eq!(names, vec!["날짜", "A", "B"]);
+        let dates: Vec<Option<&str>> = wide
+            .column("날짜")
+            .unwrap()
+            .str()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(
+            dates,
+            vec![
+                Some("2024-01-04"),
+                Some("2024-01-05"),
+                Some("2024-01-08"),
+                Some("2024-01-09")
+            ]
+        );
+        assert_eq!(
+            column(&wide, "A"),
+            vec![Some(10.0), Some(11.0), None, Some(12.0)]
+        );
+        assert_eq!(column(&wide, "B"), vec![Some(20.0), None, None, Some(21.0)]);
+    }
+
+    #[test]
+    fn test_to_wide_without_calendar_uses_input_dates() {
+        let options = PanelOptions::default().calendar(None);
+        let wide = to_wide(&stocks(), "종가", &options).unwrap();
+        assert_eq!(wide.height(), 3);
+    }
+
+    #[test]
+    fn test_to_wide_forward_fill() {
+        let options = PanelOptions::default().fill(FillPolicy::ForwardFill);
+        let wide = to_wide(&stocks(), "종가", &options).unwrap();
+        assert_eq!(
+            column(&wide, "B"),
+            vec![Some(20.0), Some(20.0), Some(20.0), Some(21.0)]
+        );
+    }
+
+    #[test]
+    fn test_to_wide_rejects_duplicates() {
+        let mut df = stocks();
+        df.vstack_mut(&stocks().head(Some(1))).unwrap();
+        assert!(to_wide(&df, "종가", &PanelOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let options = PanelOptions::default();
+        let wide = to_wide(&stocks(), "종가", &options).unwrap();
+        let long = to_long(&wide, "종가", &options).unwrap();
+
+        assert_eq!(long.height(), 5);
+        let keys: Vec<Option<&str>> = long
+            .column("종목코드")
+            .unwrap()
+            .str()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(
+            keys,
+            vec![Some("A"), Some("B"), Some("A"), Some("A"), Some("B")]
+        );
+        assert_eq!(
+            column(&long, "종가"),
+            vec![Some(10.0), Some(20.0), Some(11.0), Some(12.0), Some(21.0)]
+        );
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_to_ndarray() {
+        let options = PanelOptions::default();
+        let wide = to_wide(&stocks(), "종가", &options).unwrap();
+        let array = to_ndarray(&wide, &options).unwrap();
+
+        assert_eq!(array.dim(), (4, 2));
+        assert_eq!(array[[0, 0]], 10.0);
+        assert_eq!(array[[3, 1]], 21.0);
+        assert!(array[[1, 1]].is_nan());
+    }
+}
@@ -0,0 +1,466 @@
+//! 수익률, 변동성, 낙폭, 베타, 상관·공분산 분석.
+//!
+//! 주식/지수 빌더가 반환한 다일자 DataFrame을 입력으로 받습니다. 컬럼 이름과
+//! 분할 기준은 [`PriceColumns`]로 지정하며, 모든 시계열 계산은 종목(또는 지수)별로
+//! 날짜 순으로 정렬한 윈도 표현식으로 수행됩니다.
+//!
+//! 거래정지 등으로 일부 날짜의 행이 없거나 종가가 `null`인 경우:
+//! - 수익률은 직전 유효 종가 대비로 계산되며, 종가가 `null`인 날은 `null`입니다.
+//! - 베타는 종목 수익률과 같은 구간(직전 유효 종가 날짜부터)의 지수 수익률과 짝짓습니다.
+//! - 상관·공분산은 두 시계열이 모두 존재하는 날짜만 사용합니다.
+//!
+//! # 예시
+//! ```rust,no_run
+//! # use krx_rs::Client;
+//! use krx_rs::analysis::{indicators::PriceColumns, risk};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), krx_rs::error::Error> {
+//! # let client = Client::new("YOUR_AUTH_KEY");
+//! let mut stocks = client.stock().stock_daily().date("20240104").fetch().await?;
+//! stocks.vstack_mut(&client.stock().stock_daily().date("20240105").fetch().await?)?;
+//! let mut index = client.index().stock_daily().date("20240104").fetch().await?;
+//! index.vstack_mut(&client.index().stock_daily().date("20240105").fetch().await?)?;
+//!
+//! let summary = risk::risk_summary(&stocks, &PriceColumns::default())?;
+//! let beta = risk::rolling_beta(
+//!     &stocks,
+//!     &PriceColumns::default(),
+//!     &index,
+//!     &PriceColumns::index(),
+//!     "코스피 200",
+//!     60,
+//! )?;
+//! let corr = risk::correlation_matrix(&stocks, &PriceColumns::default(), &["005930", "000660"])?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    analysis::indicators::PriceColumns,
+    error::{Error, Result},
+};
+use polars::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+
+/// 연환산에 사용하는 연간 거래일 수
+pub const TRADING_DAYS_PER_YEAR: f64 = 252.0;
+
+/// 직전 유효 종가 (`null`인 종가는 이전 값으로 채움)
+fn previous_close(cols: &PriceColumns) -> Expr {
+    col(cols.close.as_str())
+        .fill_null_with_strategy(FillNullStrategy::Forward(None))
+        .shift(lit(1))
+}
+
+/// 단순 수익률 (`수익률`)
+pub fn simple_returns(cols: &PriceColumns) -> Expr {
+    let close = col(cols.close.as_str());
+    cols.windowed(close / previous_close(cols) - lit(1.0))
+        .alias("수익률")
+}
+
+/// 로그 수익률 (`로그수익률`)
+pub fn log_returns(cols: &PriceColumns) -> Expr {
+    let close = col(cols.close.as_str());
+    cols.windowed((close / previous_close(cols)).log(std::f64::consts::E))
+        .alias("로그수익률")
+}
+
+/// 누적 고점 대비 낙폭 (`낙폭`, 0 이하의 비율)
+pub fn drawdown(cols: &PriceColumns) -> Expr {
+    let close = col(cols.close.as_str());
+    let peak = close
+        .clone()
+        .fill_null_with_strategy(FillNullStrategy::Forward(None))
+        .cum_max(false);
+    cols.windowed(close / peak - lit(1.0)).alias("낙폭")
+}
+
+/// 로그 수익률 기준 연환산 이동 변동성 (`변동성_{window}`)
+pub fn rolling_volatility(cols: &PriceColumns, window: usize) -> Expr {
+    let close = col(cols.close.as_str());
+    let log_return = (close / previous_close(cols)).log(std::f64::consts::E);
+    let std = log_return.rolling_std(RollingOptionsFixedWindow {
+        window_size: window,
+        min_periods: window,
+        ..Default::default()
+    });
+    cols.windowed(std * lit(TRADING_DAYS_PER_YEAR.sqrt()))
+        .alias(format!("변동성_{window}"))
+}
+
+/// 종목(또는 지수)별 위험 요약 통계를 계산합니다.
+///
+/// 결과 컬럼: 분할 컬럼, `관측일수`, `누적수익률`, `연환산변동성`, `최대낙폭`.
+pub fn risk_summary(df: &DataFrame, cols: &PriceColumns) -> Result<DataFrame> {
+    if df.is_empty() {
+        return Ok(DataFrame::empty());
+    }
+
+    let mut sort_by = vec![cols.date.as_str()];
+    if let Some(partition) = &cols.partition {
+        sort_by.insert(0, partition.as_str());
+    }
+
+    let prepared = df
+        .clone()
+        .lazy()
+        .sort(sort_by, SortMultipleOptions::default())
+        .with_columns([log_returns(cols), drawdown(cols)]);
+
+    let close = col(cols.close.as_str()).drop_nulls();
+    let aggregations = [
+        col("로그수익률")
+            .count()
+            .cast(DataType::UInt32)
+            .alias("관측일수"),
+        (close.clone().last() / close.first() - lit(1.0)).alias("누적수익률"),
+        (col("로그수익률").std(1) * lit(TRADING_DAYS_PER_YEAR.sqrt())).alias("연환산변동성"),
+        col("낙폭").min().alias("최대낙폭"),
+    ];
+
+    let summary = match &cols.partition {
+        Some(partition) => prepared
+            .group_by_stable([col(partition.as_str())])
+            .agg(aggregations),
+        None => prepared.select(aggregations),
+    }
+    .collect()?;
+
+    Ok(summary)
+}
+
+/// 기준 지수 대비 종목별 이동 베타를 계산합니다.
+///
+/// `index_df`에서 `index_cols.partition` 값이 `index_name`인 지수를 기준으로 삼습니다.
+/// 결과 컬럼: 날짜, 분할 컬럼, `수익률`, `지수수익률`, `베타_{window}`.
+///
+/// `지수수익률`은 종목 수익률과 같은 구간, 즉 종목의 직전 유효 종가 날짜부터 해당
+/// 날짜까지의 지수 수익률입니다. 거래정지 후 첫 수익률이 여러 날에 걸쳐 있으면 지수
+/// 수익률도 같은 기간으로 복리 계산됩니다. 어느 한쪽 수익률이 없는 날은 제외하며,
+/// 윈도 안에 유효한 관측치가 절반 이상일 때만 베타를 계산합니다.
+pub fn rolling_beta(
+    df: &DataFrame,
+    cols: &PriceColumns,
+    index_df: &DataFrame,
+    index_cols: &PriceColumns,
+    index_name: &str,
+    window: usize,
+) -> Result<DataFrame> {
+    let market = match &index_cols.partition {
+        Some(partition) => index_df
+            .clone()
+            .lazy()
+            .filter(col(partition.as_str()).eq(lit(index_name))),
+        None => index_df.clone().lazy(),
+    }
+    .select([
+        col(index_cols.date.as_str()).alias("_지수날짜"),
+        col(index_cols.close.as_str()).alias("_지수종가"),
+    ]);
+    let previous_market = market.clone().select([
+        col("_지수날짜").alias("_직전날짜"),
+        col("_지수종가").alias("_지수직전종가"),
+    ]);
+
+    let mut keys = vec![col(cols.date.as_str())];
+    if let Some(partition) = &cols.partition {
+        keys.push(col(partition.as_str()));
+    }
+    let mut selection = keys.clone();
+    selection.push(simple_returns(cols));
+    // 종목의 직전 유효 종가 날짜: 지수 수익률을 같은 구간으로 맞추는 데 사용
+    let valid_date = when(col(cols.close.as_str()).is_not_null())
+        .then(col(cols.date.as_str()))
+        .otherwise(lit(NULL));
+    selection.push(
+        cols.windowed(
+            valid_date
+                .fill_null_with_strategy(FillNullStrategy::Forward(None))
+                .shift(lit(1)),
+        )
+        .alias("_직전날짜"),
+    );
+
+    let both = col("수익률")
+        .is_not_null()
+        .and(col("지수수익률").is_not_null());
+    let x = when(both.clone()).then(col("수익률")).otherwise(lit(NULL));
+    let y = when(both).then(col("지수수익률")).otherwise(lit(NULL));
+    let options = RollingOptionsFixedWindow {
+        window_size: window,
+        min_periods: window.div_ceil(2).max(2),
+        ..Default::default()
+    };
+    let mean = |e: Expr| e.rolling_mean(options.clone());
+    let covariance = mean(x.clone() * y.clone()) - mean(x) * mean(y.clone());
+    let variance = mean(y.clone() * y.clone()) - mean(y.clone()) * mean(y);
+    let beta = cols
+        .windowed(covariance / variance)
+        .alias(format!("베타_{window}"));
+
+    let result = df
+        .clone()
+        .lazy()
+        .select(selection)
+        .join(
+            market,
+            [col(cols.date.as_str())],
+            [col("_지수날짜")],
+            JoinArgs::new(JoinType::Left),
+        )
+        .join(
+            previous_market,
+            [col("_직전날짜")],
+            [col("_직전날짜")],
+            JoinArgs::new(JoinType::Left),
+        )
+        .with_column((col("_지수종가") / col("_지수직전종가") - lit(1.0)).alias("지수수익률"))
+        .with_column(beta)
+        .drop(["_직전날짜", "_지수종가", "_지수직전종가"])
+        .sort_by_exprs(keys, SortMultipleOptions::default())
+        .collect()?;
+
+    Ok(result)
+}
+
+/// 종목 바스켓의 일별 단순 수익률 상관계수 행렬을 계산합니다.
+///
+/// 결과는 첫 컬럼이 분할 컬럼(종목코드)이고 나머지 컬럼이 `codes` 순서인 정방 행렬입니다.
+/// 각 쌍은 두 종목의 수익률이 모두 존재하는 날짜만 사용합니다.
+pub fn correlation_matrix(
+    df: &DataFrame,
+    cols: &PriceColumns,
+    codes: &[&str],
+) -> Result<DataFrame> {
+    pairwise_matrix(df, cols, codes, |x, y| {
+        let cov = covariance(x, y)?;
+        let sx = covariance(x, x)?.sqrt();
+        let sy = covariance(y, y)?.sqrt();
+        (sx > 0.0 && sy > 0.0).then(|| cov / (sx * sy))
+    })
+}
+
+/// 종목 바스켓의 일별 단순 수익률 공분산 행렬(표본, ddof = 1)을 계산합니다.
+pub fn covariance_matrix(df: &DataFrame, cols: &PriceColumns, codes: &[&str]) -> Result<DataFrame> {
+    pairwise_matrix(df, cols, codes, covariance)
+}
+
+fn covariance(x: &[f64], y: &[f64]) -> Option<f64> {
+    let n = x.len();
+    if n < 2 {
+        return None;
+    }
+    let mean_x = x.iter().sum::<f64>() / n as f64;
+    let mean_y = y.iter().sum::<f64>() / n as f64;
+    let sum: f64 = x
+        .iter()
+        .zip(y)
+        .map(|(a, b)| (a - mean_x) * (b - mean_y))
+        .sum();
+    Some(sum / (n - 1) as f64)
+}
+
+fn pairwise_matrix(
+    df: &DataFrame,
+    cols: &PriceColumns,
+    codes: &[&str],
+    stat: impl Fn(&[f64], &[f64]) -> Option<f64>,
+) -> Result<DataFrame> {
+    let partition = cols.partition.as_deref().ok_or_else(|| {
+        Error::InvalidInput("a partition column is required for basket statistics".to_string())
+    })?;
+
+    let returns = df
+        .clone()
+        .lazy()
+        .with_column(simple_returns(cols))
+        .filter(col("수익률").is_not_null())
+        .select([
+            col(partition).cast(DataType::String),
+            col(cols.date.as_str()).cast(DataType::String),
+            col("수익률"),
+        ])
+        .collect()?;
+
+    let mut series: HashMap<&str, BTreeMap<&str, f64>> = HashMap::new();
+    let keys = returns.column(partition)?.str()?;
+    let dates = returns.column(cols.date.as_str())?.str()?;
+    let values = returns.column("수익률")?.f64()?;
+    for ((key, date), value) in keys.into_iter().zip(dates).zip(values) {
+        if let (Some(key), Some(date), Some(value)) = (key, date, value) {
+            series.entry(key).or_default().insert(date, value);
+        }
+    }
+
+    let empty = BTreeMap::new();
+    let mut columns = vec![Column::new(
+        partition.into(),
+        codes.iter().map(|c| c.to_string()).collect::<Vec<_>>(),
+    )];
+    for column_code in codes {
+        let y_series = series.get(column_code).unwrap_or(&empty);
+        let values: Vec<Option<f64>> = codes
+            .iter()
+            .map(|row_code| {
+                let x_series = series.get(row_code).unwrap_or(&empty);
+                let (x, y): (Vec<f64>, Vec<f64>) = x_series
+                    .iter()
+                    .filter_map(|(date, x)| y_series.get(date).map(|y| (*x, *y)))
+                    .unzip();
+                stat(&x, &y)
+            })
+            .collect();
+        columns.push(Column::new((*column_code).into(), values));
+    }
+
+    Ok(DataFrame::new(columns)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stocks() -> DataFrame {
+        df! {
+            "날짜" => ["2024-01-02", "2024-01-03", "2024-01-04", "2024-01-05",
+                       "2024-01-02", "2024-01-03", "2024-01-05"],
+            "종목코드" => ["A", "A", "A", "A", "B", "B", "B"],
+            "종가" => [Some(100.0), Some(110.0), None, Some(99.0),
+                      Some(50.0), Some(55.0), Some(49.5)],
+        }
+        .unwrap()
+    }
+
+    fn index() -> DataFrame {
+        df! {
+            "날짜" => ["2024-01-02", "2024-01-03", "2024-01-04", "2024-01-05"],
+            "지수명" => ["코스피 200"; 4],
+            "종가" => [300.0, 315.0, 330.0, 313.5],
+        }
+        .unwrap()
+    }
+
+    fn value_at(df: &DataFrame, column: &str, date: &str, code: &str) -> Option<f64> {
+        df.clone()
+            .lazy()
+            .filter(col("날짜").eq(lit(date)).and(col("종목코드").eq(lit(code))))
+            .collect()
+            .unwrap()
+            .column(column)
+            .unwrap()
+            .f64()
+            .unwrap()
+            .get(0)
+    }
+
+    #[test]
+    fn test_simple_returns_skip_halted_days() {
+        let cols = PriceColumns::default();
+        let df = stocks()
+            .lazy()
+            .with_columns([simple_returns(&cols), drawdown(&cols)])
+            .collect()
+            .unwrap();
+
+        assert_eq!(value_at(&df, "수익률", "2024-01-02", "A"), None);
+        assert!((value_at(&df, "수익률", "2024-01-03", "A").unwrap() - 0.1).abs() < 1e-12);
+        // 종가가 없는 날은 null, 다음 날은 직전 유효 종가 대비
+        assert_eq!(value_at(&df, "수익률", "2024-01-04", "A"), None);
+        assert!((value_at(&df, "수익률", "2024-01-05", "A").unwrap() + 0.1).abs() < 1e-12);
+        // 행이 없는 날(B, 01-04)은 건너뜀
+        assert!((value_at(&df, "수익률", "2024-01-05", "B").unwrap() + 0.1).abs() < 1e-12);
+        assert!((value_at(&df, "낙폭", "2024-01-05", "A").unwrap() + 0.1).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_risk_summary() {
+        let summary = risk_summary(&stocks(), &PriceColumns::default()).unwrap();
+
+        assert_eq!(summary.height(), 2);
+        let cumulative = summary.column("누적수익률").unwrap().f64().unwrap();
+        assert!((cumulative.get(0).unwrap() + 0.01).abs() < 1e-12);
+        let max_drawdown = summary.column("최대낙폭").unwrap().f64().unwrap();
+        assert!((max_drawdown.get(1).unwrap() + 0.1).abs() < 1e-12);
+        assert_eq!(
+            summary.column("관측일수").unwrap().u32().unwrap().get(0),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_rolling_beta() {
+        let result = rolling_beta(
+            &stocks(),
+            &PriceColumns::default(),
+            &index(),
+            &PriceColumns::index(),
+            "코스피 200",
+            3,
+        )
+        .unwrap();
+
+        assert_eq!(
+            result.get_column_names(),
+            ["날짜", "종목코드", "수익률", "지수수익률", "베타_3"]
+        );
+        // A의 01-05 수익률은 01-03 종가 대비이므로 지수도 01-03 대비 수익률을 사용
+        let market = value_at(&result, "지수수익률", "2024-01-05", "A").unwrap();
+        assert!((market - (313.5 / 315.0 - 1.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rolling_beta_matches_gap_returns() {
+        let index = df! {
+            "날짜" => ["2024-01-02", "2024-01-03", "2024-01-04", "2024-01-05"],
+            "지수명" => ["코스피 200"; 4],
+            "종가" => [100.0, 110.0, 121.0, 108.9],
+        }
+        .unwrap();
+        // A는 매일 지수의 1배, B(01-04 행 없음)와 C(01-04 종가 없음)는 같은 구간 지수
+        // 수익률의 2배. 지수의 하루 수익률(01-05: -10%)과 짝지으면 베타가 2가 아닙니다.
+        let stocks = df! {
+            "날짜" => ["2024-01-02", "2024-01-03", "2024-01-04", "2024-01-05",
+                       "2024-01-02", "2024-01-03", "2024-01-05",
+                       "2024-01-02", "2024-01-03", "2024-01-04", "2024-01-05"],
+            "종목코드" => ["A", "A", "A", "A", "B", "B", "B", "C", "C", "C", "C"],
+            "종가" => [Some(200.0), Some(220.0), Some(242.0), Some(217.8),
+                      Some(50.0), Some(60.0), Some(58.8),
+                      Some(50.0), Some(60.0), None, Some(58.8)],
+        }
+        .unwrap();
+
+        let result = rolling_beta(
+            &stocks,
+            &PriceColumns::default(),
+            &index,
+            &PriceColumns::index(),
+            "코스피 200",
+            3,
+        )
+        .unwrap();
+
+        let beta = |code| value_at(&result, "베타_3", "2024-01-05", code).unwrap();
+        assert!((beta("A") - 1.0).abs() < 1e-9, "beta = {}", beta("A"));
+        assert!((beta("B") - 2.0).abs() < 1e-9, "beta = {}", beta("B"));
+        assert!((beta("C") - 2.0).abs() < 1e-9, "beta = {}", beta("C"));
+        let market = value_at(&result, "지수수익률", "2024-01-05", "B").unwrap();
+        assert!((market + 0.01).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_correlation_and_covariance_matrix() {
+        let cols = PriceColumns::default();
+        let corr = correlation_matrix(&stocks(), &cols, &["A", "B"]).unwrap();
+        assert_eq!(corr.shape(), (2, 3));
+        let a = corr.column("A").unwrap().f64().unwrap();
+        assert!((a.get(0).unwrap() - 1.0).abs() < 1e-12);
+        // 공통 날짜(01-03, 01-05)에서 두 종목의 수익률이 같음
+        assert!((a.get(1).unwrap() - 1.0).abs() < 1e-12);
+
+        let cov = covariance_matrix(&stocks(), &cols, &["A", "B", "C"]).unwrap();
+        assert_eq!(cov.column("C").unwrap().null_count(), 3);
+        assert!((cov.column("A").unwrap().f64().unwrap().get(0).unwrap() - 0.02).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,366 @@
+//! 사회책임투자(SRI) 채권 분석.
+//!
+//! `esg().sri_bond_info()`의 종목정보를 `bond().bond_daily()`의 일별 시세와
+//! 표준코드(`ISU_CD`)로 연결하고, 채권종류와 잔존만기 구간을 분류하여
+//! 발행기관·종류·만기 구간별 잔액을 집계합니다.
+//!
+//! # 예시
+//! ```rust,no_run
+//! # use krx_rs::Client;
+//! use krx_rs::analysis::sri::outstanding_summary;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), krx_rs::error::Error> {
+//! # let client = Client::new("YOUR_AUTH_KEY");
+//! let view = client.esg().sri_bond_info().date("20240105").fetch_with_prices().await?;
+//! let summary = outstanding_summary(&view)?;
+//! println!("{summary}");
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{data::esg::SriBondInfoRecord, error::Result};
+use chrono::NaiveDate;
+use polars::prelude::*;
+use std::fmt;
+
+/// 사회책임투자채권 종류 (`SRI_BND_TP_NM`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SriBondType {
+    /// 녹색채권
+    Green,
+    /// 사회적채권
+    Social,
+    /// 지속가능채권
+    Sustainability,
+    /// 지속가능연계채권
+    SustainabilityLinked,
+    /// 그 외 분류 (원문 유지)
+    Other(String),
+}
+
+impl SriBondType {
+    /// KRX 채권종류 명칭을 분류합니다. 공백 차이("사회적 채권")는 무시합니다.
+    pub fn from_name(name: &str) -> Self {
+        let normalized: String = name.chars().filter(|c| !c.is_whitespace()).collect();
+        match normalized.as_str() {
+            "녹색채권" => Self::Green,
+            "사회적채권" => Self::Social,
+            "지속가능채권" => Self::Sustainability,
+            "지속가능연계채권" => Self::SustainabilityLinked,
+            _ => Self::Other(name.trim().to_string()),
+        }
+    }
+
+    /// 표준 한글 명칭
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Green => "녹색채권",
+            Self::Social => "사회적채권",
+            Self::Sustainability => "지속가능채권",
+            Self::SustainabilityLinked => "지속가능연계채권",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl fmt::Display for SriBondType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 기준일 대비 잔존만기 구간
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MaturityBucket {
+    /// 상환일이 기준일 이전 (상환 완료)
+    Matured,
+    /// 1년 이하
+    UpTo1Y,
+    /// 1년 초과 3년 이하
+    UpTo3Y,
+    /// 3년 초과 5년 이하
+    UpTo5Y,
+    /// 5년 초과 10년 이하
+    UpTo10Y,
+    /// 10년 초과
+    Over10Y,
+    /// 상환일 정보 없음
+    Unknown,
+}
+
+impl MaturityBucket {
+    /// 기준일과 상환일로 잔존만기 구간을 구합니다.
+    pub fn classify(base_date: NaiveDate, redemption_date: Option<NaiveDate>) -> Self {
+        let Some(redemption_date) = redemption_date else {
+            return Self::Unknown;
+        };
+        let years = (redemption_date - base_date).num_days() as f64 / 365.0;
+        match years {
+            y if y < 0.0 => Self::Matured,
+            y if y <= 1.0 => Self::UpTo1Y,
+            y if y <= 3.0 => Self::UpTo3Y,
+            y if y <= 5.0 => Self::UpTo5Y,
+            y if y <= 10.0 => Self::UpTo10Y,
+            _ => Self::Over10Y,
+        }
+    }
+
+    /// 구간 라벨
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Matured => "상환",
+            Self::UpTo1Y => "1년 이하",
+            Self::UpTo3Y => "1~3년",
+            Self::UpTo5Y => "3~5년",
+            Self::UpTo10Y => "5~10년",
+            Self::Over10Y => "10년 초과",
+            Self::Unknown => "미상",
+        }
+    }
+}
+
+impl fmt::Display for MaturityBucket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl SriBondInfoRecord {
+    /// 채권종류를 [`SriBondType`]으로 분류합니다.
+    pub fn sri_type(&self) -> SriBondType {
+        SriBondType::from_name(&self.sri_bond_type)
+    }
+
+    /// 기준일자 대비 잔존만기 구간
+    pub fn maturity_bucket(&self) -> MaturityBucket {
+        MaturityBucket::classify(self.base_date, self.redemption_date)
+    }
+}
+
+/// 시세 DataFrame에서 결합하는 컬럼과 결과 컬럼명
+const PRICE_COLUMNS: [(&str, &str); 5] = [
+    ("날짜", "매매일자"),
+    ("종가", "종가"),
+    ("종가수익률", "종가수익률"),
+    ("거래량", "거래량"),
+    ("거래대금", "거래대금"),
+];
+
+/// SRI 채권 종목정보에 분류와 잔존만기 구간, 시세를 결합합니다.
+///
+/// `info`는 [`crate::data::esg::parse_sri_bond_info`] 형식, `prices`는
+/// [`crate::data::bond::parse_bond_daily`] 형식이어야 합니다. 시세는
+/// `표준코드 = 종목코드`로 왼쪽 조인하며, 여러 날짜가 있으면 종목별 최근 시세를 사용합니다.
+/// 거래되지 않은 종목의 시세 컬럼은 `null`입니다.
+///
+/// 추가 컬럼: `분류`, `잔존만기구간`, `매매일자`, `종가`, `종가수익률`, `거래량`, `거래대금`.
+pub fn sri_bond_view(info: &DataFrame, prices: &DataFrame) -> Result<DataFrame> {
+    if info.is_empty() {
+        return Ok(DataFrame::empty());
+    }
+
+    let base_dates = info.column("기준일자")?.str()?;
+    let redemption_dates = info.column("상환일")?.str()?;
+    let parse = |s: Option<&str>| s.and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok());
+
+    let buckets: Vec<Option<&str>> = base_dates
+        .into_iter()
+        .zip(redemption_dates)
+        .map(|(base, redemption)| {
+            parse(base).map(|base| MaturityBucket::classify(base, parse(redemption)).as_str())
+        })
+        .collect();
+    let types: Vec<Option<String>> = info
+        .column("채권종류")?
+        .str()?
+        .into_iter()
+        .map(|name| name.map(|name| SriBondType::from_name(name).to_string()))
+        .collect();
+
+    let mut view = info.clone();
+    view.with_column(Column::new("분류".into(), types))?;
+    view.with_column(Column::new("잔존만기구간".into(), buckets))?;
+
+    let price_columns = if prices.is_empty() {
+        let mut columns = vec![Column::new_empty("종목코드".into(), &DataType::String)];
+        columns.extend(PRICE_COLUMNS.iter().map(|(_, name)| {
+            let dtype = match *name {
+                "매매일자" => DataType::String,
+                "거래량" | "거래대금" => DataType::Int64,
+                _ => DataType::Float64,
+            };
+            Column::new_empty((*name).into(), &dtype)
+        }));
+        DataFrame::new(columns)?.lazy()
+    } else {
+        let mut selection = vec![col("종목코드")];
+        selection.extend(PRICE_COLUMNS.iter().map(|(from, to)| col(*from).alias(*to)));
+        prices
+            .clone()
+            .lazy()
+            .sort(["종목코드", "날짜"], SortMultipleOptions::default())
+            .select(selection)
+            .group_by_stable([col("종목코드")])
+            .agg([all().exclude(["종목코드"]).last()])
+    };
+
+    let result = view
+        .lazy()
+        .join(
+            price_columns,
+            [col("표준코드")],
+            [col("종목코드")],
+            JoinArgs::new(JoinType::Left),
+        )
+        .collect()?;
+
+    Ok(result)
+}
+
+/// 발행기관·분류·잔존만기구간별 잔액을 집계합니다.
+///
+/// 입력은 [`sri_bond_view`]의 결과이며, 상환이 끝난 종목은 제외합니다.
+/// 잔액은 상장금액 기준이며, 상장금액이 없으면 발행금액을 사용합니다.
+///
+/// 결과 컬럼: `발행기관`, `분류`, `잔존만기구간`, `종목수`, `잔액`.
+pub fn outstanding_summary(view: &DataFrame) -> Result<DataFrame> {
+    if view.is_empty() {
+        return Ok(DataFrame::empty());
+    }
+
+    let result = view
+        .clone()
+        .lazy()
+        .filter(col("잔존만기구간").neq(lit(MaturityBucket::Matured.as_str())))
+        .group_by([col("발행기관"), col("분류"), col("잔존만기구간")])
+        .agg([
+            len().cast(DataType::UInt32).alias("종목수"),
+            col("상장금액")
+                .fill_null(col("발행금액"))
+                .sum()
+                .cast(DataType::Int64)
+                .alias("잔액"),
+        ])
+        .sort(
+            ["발행기관", "분류", "잔존만기구간"],
+            SortMultipleOptions::default(),
+        )
+        .collect()?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info() -> DataFrame {
+        df! {
+            "기준일자" => ["2024-01-05"; 3],
+            "발행기관" => ["한국전력공사", "한국전력공사", "한국주택금융공사"],
+            "표준코드" => ["KR1", "KR2", "KR3"],
+            "채권종류" => ["녹색채권", "녹색채권", "사회적 채권"],
+            "종목명" => ["A", "B", "C"],
+            "상장일" => [Some("2022-01-05"), Some("2023-01-05"), None],
+            "발행일" => [Some("2022-01-05"), Some("2023-01-05"), None],
+            "상환일" => [Some("2025-01-04"), Some("2025-06-30"), Some("2023-12-31")],
+            "표면이자율" => [Some(3.5), Some(4.0), None],
+            "발행금액" => [Some(100i64), Some(200), Some(300)],
+            "상장금액" => [None, Some(150i64), Some(300)],
+            "채권유형" => ["특수채"; 3],
+        }
+        .unwrap()
+    }
+
+    fn prices() -> DataFrame {
+        df! {
+            "날짜" => ["2024-01-04", "2024-01-05"],
+            "종목코드" => ["KR1", "KR1"],
+            "종목명" => ["A", "A"],
+            "시장구분" => ["일반채권"; 2],
+            "종가" => [Some(9_900.0), Some(9_950.0)],
+            "종가수익률" => [Some(3.6), Some(3.55)],
+            "대비" => ["", "50"],
+            "거래량" => [Some(10i64), Some(20)],
+            "거래대금" => [Some(99_000i64), Some(199_000)],
+        }
+        .unwrap()
+    }
+
+    #[test]
+    fn test_sri_bond_type_from_name() {
+        assert_eq!(SriBondType::from_name("녹색채권"), SriBondType::Green);
+        assert_eq!(SriBondType::from_name("사회적 채권"), SriBondType::Social);
+        assert_eq!(
+            SriBondType::from_name("지속가능연계채권"),
+            SriBondType::SustainabilityLinked
+        );
+        assert_eq!(
+            SriBondType::from_name("전환채권"),
+            SriBondType::Other("전환채권".to_string())
+        );
+    }
+
+    #[test]
+    fn test_maturity_bucket() {
+        let base = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+        let at = |y, m, d| Some(NaiveDate::from_ymd_opt(y, m, d).unwrap());
+        assert_eq!(
+            MaturityBucket::classify(base, at(2024, 1, 4)),
+            MaturityBucket::Matured
+        );
+        assert_eq!(
+            MaturityBucket::classify(base, at(2025, 1, 4)),
+            MaturityBucket::UpTo1Y
+        );
+        assert_eq!(
+            MaturityBucket::classify(base, at(2030, 1, 5)),
+            MaturityBucket::UpTo10Y
+        );
+        assert_eq!(
+            MaturityBucket::classify(base, at(2044, 1, 5)),
+            MaturityBucket::Over10Y
+        );
+        assert_eq!(
+            MaturityBucket::classify(base, None),
+            MaturityBucket::Unknown
+        );
+    }
+
+    #[test]
+    fn test_sri_bond_view_joins_latest_price() {
+        let view = sri_bond_view(&info(), &prices()).unwrap();
+        assert_eq!(view.height(), 3);
+        assert_eq!(view.width(), 12 + 2 + PRICE_COLUMNS.len());
+
+        let close = view.column("종가").unwrap().f64().unwrap();
+        assert_eq!(close.get(0), Some(9_950.0));
+        assert_eq!(close.get(1), None);
+        assert_eq!(
+            view.column("분류").unwrap().str().unwrap().get(2),
+            Some("사회적채권")
+        );
+
+        let without_prices = sri_bond_view(&info(), &DataFrame::empty()).unwrap();
+        assert_eq!(without_prices.column("종가").unwrap().null_count(), 3);
+    }
+
+    #[test]
+    fn test_outstanding_summary() {
+        let view = sri_bond_view(&info(), &prices()).unwrap();
+        let summary = outstanding_summary(&view).unwrap();
+
+        // 상환된 KR3 제외, KR1(1년 이하)/KR2(1~3년)는 구간이 달라 별도 행
+        assert_eq!(summary.height(), 2);
+        let buckets = summary.column("잔존만기구간").unwrap().str().unwrap();
+        let amounts = summary.column("잔액").unwrap().i64().unwrap();
+        for (bucket, amount) in buckets.into_iter().zip(amounts) {
+            match bucket.unwrap() {
+                "1년 이하" => assert_eq!(amount, Some(100)),
+                "1~3년" => assert_eq!(amount, Some(150)),
+                other => panic!("unexpected bucket {other}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,403 @@
+//! 신주인수권증권(워런트)과 신주인수권증서의 가치평가 헬퍼.
+//!
+//! 목적주권 종가와 행사가격(증서는 신주발행가)을 이용해 내재가치, 패리티,
+//! 프리미엄, 잔존일수, Black-Scholes 이론가를 계산합니다.
+
+use crate::{
+    data::{
+        ApiResponse,
+        stock::{StockRightDailyRecord, StockWarrantDailyRecord},
+    },
+    error::Result,
+};
+use chrono::NaiveDate;
+use polars::prelude::*;
+
+/// 가치평가에 사용하는 시장 가정값
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValuationParams {
+    /// 목적주권의 연환산 변동성 (예: 0.3 = 30%)
+    pub volatility: f64,
+    /// 연환산 무위험이자율 (예: 0.035 = 3.5%)
+    pub risk_free_rate: f64,
+    /// 잔존일수가 이 값 이하이면 만기임박으로 표시
+    pub expiring_within_days: i64,
+}
+
+impl Default for ValuationParams {
+    fn default() -> Self {
+        Self {
+            volatility: 0.3,
+            risk_free_rate: 0.035,
+            expiring_within_days: 30,
+        }
+    }
+}
+
+/// 종목별 가치평가 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct Valuation {
+    /// 내재가치: max(목적주권가격 - 행사가격, 0)
+    pub intrinsic_value: Option<f64>,
+    /// 패리티 (%): 목적주권가격 / 행사가격 × 100
+    pub parity: Option<f64>,
+    /// 프리미엄 (%): (종가 + 행사가격 - 목적주권가격) / 목적주권가격 × 100
+    pub premium: Option<f64>,
+    /// 기준일부터 만기일(존속기간 종료일 또는 상장폐지일)까지의 일수
+    pub days_to_expiry: Option<i64>,
+    /// Black-Scholes 콜옵션 이론가
+    pub theoretical_price: Option<f64>,
+    /// 만기임박 여부
+    pub is_expiring: bool,
+}
+
+/// 내재가치를 계산합니다.
+pub fn intrinsic_value(underlying: f64, strike: f64) -> f64 {
+    (underlying - strike).max(0.0)
+}
+
+/// 패리티(%)를 계산합니다. 행사가격이 0 이하이면 `None`을 반환합니다.
+pub fn parity(underlying: f64, strike: f64) -> Option<f64> {
+    (strike > 0.0).then(|| underlying / strike * 100.0)
+}
+
+/// 패리티 대비 프리미엄(%)을 계산합니다. 목적주권가격이 0 이하이면 `None`을 반환합니다.
+pub fn premium_over_parity(price: f64, underlying: f64, strike: f64) -> Option<f64> {
+    (underlying > 0.0).then(|| (price + strike - underlying) / underlying * 100.0)
+}
+
+/// Black-Scholes 유럽형 콜옵션 가격을 계산합니다.
+///
+/// 잔존기간이나 변동성이 0 이하이면 내재가치를 반환합니다.
+pub fn black_scholes_call(
+    underlying: f64,
+    strike: f64,
+    years: f64,
+    volatility: f64,
+    risk_free_rate: f64,
+) -> f64 {
+    if years <= 0.0 || volatility <= 0.0 || underlying <= 0.0 || strike <= 0.0 {
+        return intrinsic_value(underlying, strike);
+    }
+
+    let sqrt_t = years.sqrt();
+    let d1 = ((underlying / strike).ln() + (risk_free_rate + 0.5 * volatility.powi(2)) * years)
+        / (volatility * sqrt_t);
+    let d2 = d1 - volatility * sqrt_t;
+
+    underlying * normal_cdf(d1) - strike * (-risk_free_rate * years).exp() * normal_cdf(d2)
+}
+
+/// 표준정규분포 누적분포함수 (Abramowitz-Stegun 7.1.26 근사)
+fn normal_cdf(x: f64) -> f64 {
+    let z = x.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.327_591_1 * z);
+    let poly = t
+        * (0.254_829_592
+            + t * (-0.284_496_736
+                + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    let erf = 1.0 - poly * (-z * z).exp();
+
+    if x >= 0.0 {
+        0.5 * (1.0 + erf)
+    } else {
+        0.5 * (1.0 - erf)
+    }
+}
+
+fn valuate(
+    base_date: NaiveDate,
+    price: Option<f64>,
+    underlying: Option<f64>,
+    strike: Option<f64>,
+    expiry: Option<NaiveDate>,
+    params: &ValuationParams,
+) -> Valuation {
+    let days_to_expiry = expiry.map(|d| (d - base_date).num_days());
+    let is_expiring = days_to_expiry.is_some_and(|d| d <= params.expiring_within_days);
+
+    let (Some(underlying), Some(strike)) = (underlying, strike) else {
+        return Valuation {
+            intrinsic_value: None,
+            parity: None,
+            premium: None,
+            days_to_expiry,
+            theoretical_price: None,
+            is_expiring,
+        };
+    };
+
+    Valuation {
+        intrinsic_value: Some(intrinsic_value(underlying, strike)),
+        parity: parity(underlying, strike),
+        premium: price.and_then(|p| premium_over_parity(p, underlying, strike)),
+        days_to_expiry,
+        theoretical_price: days_to_expiry.map(|d| {
+            black_scholes_call(
+                underlying,
+                strike,
+                d as f64 / 365.0,
+                params.volatility,
+                params.risk_free_rate,
+            )
+        }),
+        is_expiring,
+    }
+}
+
+impl StockWarrantDailyRecord {
+    /// 행사가격과 존속기간 종료일을 기준으로 가치평가를 수행합니다.
+    pub fn valuation(&self, params: &ValuationParams) -> Valuation {
+        valuate(
+            self.base_date,
+            self.close_price,
+            self.target_stock_price,
+            self.exercise_price,
+            self.existence_end_date,
+            params,
+        )
+    }
+}
+
+impl StockRightDailyRecord {
+    /// 신주발행가와 상장폐지일을 기준으로 가치평가를 수행합니다.
+    pub fn valuation(&self, params: &ValuationParams) -> Valuation {
+        valuate(
+            self.base_date,
+            self.close_price,
+            self.target_stock_price,
+            self.issue_price,
+            self.delisting_date,
+            params,
+        )
+    }
+}
+
+#[derive(Default)]
+struct ValuationColumns {
+    dates: Vec<String>,
+    issue_codes: Vec<String>,
+    issue_names: Vec<String>,
+    target_codes: Vec<String>,
+    target_names: Vec<String>,
+    close_prices: Vec<Option<f64>>,
+    target_prices: Vec<Option<f64>>,
+    strikes: Vec<Option<f64>>,
+    expiries: Vec<Option<String>>,
+    intrinsic_values: Vec<Option<f64>>,
+    parities: Vec<Option<f64>>,
+    premiums: Vec<Option<f64>>,
+    days_to_expiry: Vec<Option<i64>>,
+    theoretical_prices: Vec<Option<f64>>,
+    expiring: Vec<bool>,
+}
+
+impl ValuationColumns {
+    #[allow(clippy::too_many_arguments)]
+    fn push(
+        &mut self,
+        base_date: NaiveDate,
+        issue_code: String,
+        issue_name: String,
+        target_code: String,
+        target_name: String,
+        close_price: Option<f64>,
+        target_price: Option<f64>,
+        strike: Option<f64>,
+        expiry: Option<NaiveDate>,
+        valuation: Valuation,
+    ) {
+        self.dates.push(base_date.format("%Y-%m-%d").to_string());
+        self.issue_codes.push(issue_code);
+        self.issue_names.push(issue_name);
+        self.target_codes.push(target_code);
+        self.target_names.push(target_name);
+        self.close_prices.push(close_price);
+        self.target_prices.push(target_price);
+        self.strikes.push(strike);
+        self.expiries
+            .push(expiry.map(|d| d.format("%Y-%m-%d").to_string()));
+        self.intrinsic_values.push(valuation.intrinsic_value);
+        self.parities.push(valuation.parity);
+        self.premiums.push(valuation.premium);
+        self.days_to_expiry.push(valuation.days_to_expiry);
+        self.theoretical_prices.push(valuation.theoretical_price);
+        self.expiring.push(valuation.is_expiring);
+    }
+
+    fn into_frame(self, strike_column: &str) -> Result<DataFrame> {
+        let df = df! {
+            "날짜" => self.dates,
+            "종목코드" => self.issue_codes,
+            "종목명" => self.issue_names,
+            "목적주권코드" => self.target_codes,
+            "목적주권명" => self.target_names,
+            "종가" => self.close_prices,
+            "목적주권가격" => self.target_prices,
+            strike_column => self.strikes,
+            "만기일" => self.expiries,
+            "내재가치" => self.intrinsic_values,
+            "패리티" => self.parities,
+            "프리미엄" => self.premiums,
+            "잔존일수" => self.days_to_expiry,
+            "이론가" => self.theoretical_prices,
+            "만기임박" => self.expiring,
+        }?;
+
+        Ok(df)
+    }
+}
+
+/// 신주인수권증권 일별매매정보에 가치평가 컬럼을 더한 DataFrame을 생성합니다.
+pub fn parse_stock_warrant_valuation(
+    response: ApiResponse<StockWarrantDailyRecord>,
+    params: &ValuationParams,
+) -> Result<DataFrame> {
+    if response.data.is_empty() {
+        return Ok(DataFrame::empty());
+    }
+
+    let mut columns = ValuationColumns::default();
+    for record in response.data {
+        let valuation = record.valuation(params);
+        columns.push(
+            record.base_date,
+            record.issue_code,
+            record.issue_name,
+            record.target_stock_code,
+            record.target_stock_name,
+            record.close_price,
+            record.target_stock_price,
+            record.exercise_price,
+            record.existence_end_date,
+            valuation,
+        );
+    }
+
+    columns.into_frame("행사가격")
+}
+
+/// 신주인수권증서 일별매매정보에 가치평가 컬럼을 더한 DataFrame을 생성합니다.
+pub fn parse_stock_right_valuation(
+    response: ApiResponse<StockRightDailyRecord>,
+    params: &ValuationParams,
+) -> Result<DataFrame> {
+    if response.data.is_empty() {
+        return Ok(DataFrame::empty());
+    }
+
+    let mut columns = ValuationColumns::default();
+    for record in response.data {
+        let valuation = record.valuation(params);
+        columns.push(
+            record.base_date,
+            record.issue_code,
+            record.issue_name,
+            record.target_stock_code,
+            record.target_stock_name,
+            record.close_price,
+            record.target_stock_price,
+            record.issue_price,
+            record.delisting_date,
+            valuation,
+        );
+    }
+
+    columns.into_frame("신주발행가")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn warrant(base: NaiveDate, end: Option<NaiveDate>) -> StockWarrantDailyRecord {
+        StockWarrantDailyRecord {
+            base_date: base,
+            market_name: "KOSPI".to_string(),
+            issue_code: "0036221D".to_string(),
+            issue_name: "KG모빌리티 122WR".to_string(),
+            close_price: Some(1299.0),
+            price_change: Some(1.0),
+            fluctuation_rate: Some(0.08),
+            open_price: Some(1330.0),
+            high_price: Some(1330.0),
+            low_price: Some(1271.0),
+            trading_volume: Some(45938),
+            trading_value: Some(59712974),
+            market_cap: Some(23243084940),
+            listed_shares: Some(17893060),
+            exercise_price: Some(8411.0),
+            existence_start_date: Some(base),
+            existence_end_date: end,
+            target_stock_code: "003620".to_string(),
+            target_stock_name: "KG모빌리티".to_string(),
+            target_stock_price: Some(8150.0),
+        }
+    }
+
+    #[test]
+    fn test_intrinsic_value_and_parity() {
+        assert_eq!(intrinsic_value(12000.0, 10000.0), 2000.0);
+        assert_eq!(intrinsic_value(8000.0, 10000.0), 0.0);
+        assert_eq!(parity(12000.0, 10000.0), Some(120.0));
+        assert_eq!(parity(12000.0, 0.0), None);
+        assert_eq!(premium_over_parity(500.0, 10000.0, 10000.0), Some(5.0));
+    }
+
+    #[test]
+    fn test_black_scholes_call() {
+        // S=100, K=100, T=1, σ=0.2, r=0.05 → 약 10.45
+        let price = black_scholes_call(100.0, 100.0, 1.0, 0.2, 0.05);
+        assert!((price - 10.4506).abs() < 1e-3, "price = {price}");
+
+        // 만기 도래 시 내재가치
+        assert_eq!(black_scholes_call(120.0, 100.0, 0.0, 0.2, 0.05), 20.0);
+    }
+
+    #[test]
+    fn test_warrant_valuation() {
+        let base = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+        let record = warrant(base, NaiveDate::from_ymd_opt(2028, 11, 5));
+
+        let valuation = record.valuation(&ValuationParams::default());
+
+        assert_eq!(valuation.intrinsic_value, Some(0.0));
+        assert_eq!(valuation.days_to_expiry, Some(1766));
+        assert!(!valuation.is_expiring);
+        let premium = valuation.premium.unwrap();
+        assert!((premium - (1299.0 + 8411.0 - 8150.0) / 8150.0 * 100.0).abs() < 1e-9);
+        assert!(valuation.theoretical_price.unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_warrant_expiring_flag() {
+        let base = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+        let record = warrant(base, NaiveDate::from_ymd_opt(2024, 1, 20));
+        assert!(record.valuation(&ValuationParams::default()).is_expiring);
+
+        let record = warrant(base, None);
+        let valuation = record.valuation(&ValuationParams::default());
+        assert!(!valuation.is_expiring);
+        assert_eq!(valuation.theoretical_price, None);
+    }
+
+    #[test]
+    fn test_parse_stock_warrant_valuation() {
+        let base = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+        let response = ApiResponse {
+            data: vec![warrant(base, NaiveDate::from_ymd_opt(2028, 11, 5))],
+        };
+
+        let df = parse_stock_warrant_valuation(response, &ValuationParams::default()).unwrap();
+
+        assert_eq!(df.shape(), (1, 15));
+        assert_eq!(
+            df.column("만기일").unwrap().str().unwrap().get(0),
+            Some("2028-11-05")
+        );
+        assert_eq!(
+            df.column("잔존일수").unwrap().i64().unwrap().get(0),
+            Some(1766)
+        );
+    }
+}
@@ -1,10 +1,10 @@
 use crate::{
-    api::common::{latest_workday_string, validate_base_date},
-    client::Client,
-    data::{
-        ApiResponse,
-        bond::{KtsDailyRecord, *},
+    api::{
+        common::latest_workday_string,
+        endpoint::{BondDaily, KtsDaily, SmallBondDaily},
     },
+    client::Client,
+    data::bond::*,
     error::Result,
 };
 use polars::prelude::DataFrame;
@@ -30,34 +30,34 @@ use polars::prelude::DataFrame;
 /// # Ok(())
 /// # }
 /// ```
-pub struct BondApi<'a> {
-    pub(crate) client: &'a Client,
+pub struct BondApi {
+    pub(crate) client: Client,
 }
 
-impl<'a> BondApi<'a> {
-    pub(crate) fn new(client: &'a Client) -> Self {
+impl BondApi {
+    pub(crate) fn new(client: Client) -> Self {
         Self { client }
     }
 
     /// 국고채(KTS) 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201040101)
-    pub fn kts_daily(&self) -> KtsDailyBuilder<'a> {
-        KtsDailyBuilder::new(self.client)
+    pub fn kts_daily(&self) -> KtsDailyBuilder {
+        KtsDailyBuilder::new(self.client.clone())
     }
 
     /// 일반채권 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201040201)
-    pub fn bond_daily(&self) -> BondDailyBuilder<'a> {
-        BondDailyBuilder::new(self.client)
+    pub fn bond_daily(&self) -> BondDailyBuilder {
+        BondDailyBuilder::new(self.client.clone())
     }
 
     /// 소액채권 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201040301)
-    pub fn small_bond_daily(&self) -> SmallBondDailyBuilder<'a> {
-        SmallBondDailyBuilder::new(self.client)
+    pub fn small_bond_daily(&self) -> SmallBondDailyBuilder {
+        SmallBondDailyBuilder::new(self.client.clone())
     }
 }
 
@@ -75,13 +75,13 @@ impl<'a> BondApi<'a> {
 /// # }
 /// ```
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct KtsDailyBuilder<'a> {
-    client: &'a Client,
+pub struct KtsDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> KtsDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl KtsDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -102,11 +102,7 @@ impl<'a> KtsDailyBuilder<'a> {
 
     /// 설정된 파라미터로 API를 호출하여 데이터를 가져옵니다.
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-        let response = self
-            .client
-            .get::<ApiResponse<KtsDailyRecord>>("/bon/kts_bydd_trd", &[("basDd", &base_date)])
-            .await?;
+        let response = self.client.get_records::<KtsDaily>(self.base_date).await?;
 
         parse_kts_daily(response)
     }
@@ -114,13 +110,13 @@ impl<'a> KtsDailyBuilder<'a> {
 
 /// 일반채권 일별매매정보 빌더
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct BondDailyBuilder<'a> {
-    client: &'a Client,
+pub struct BondDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> BondDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl BondDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -142,15 +138,7 @@ impl<'a> BondDailyBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
-        let response = self
-            .client
-            .get::<ApiResponse<crate::data::bond::BondDailyRecord>>(
-                "/bon/bnd_bydd_trd",
-                &[("basDd", &base_date)],
-            )
-            .await?;
+        let response = self.client.get_records::<BondDaily>(self.base_date).await?;
 
         parse_bond_daily(response)
     }
@@ -158,13 +146,13 @@ impl<'a> BondDailyBuilder<'a> {
 
 /// 소액채권 일별매매정보 빌더
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct SmallBondDailyBuilder<'a> {
-    client: &'a Client,
+pub struct SmallBondDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> SmallBondDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl SmallBondDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -186,14 +174,9 @@ impl<'a> SmallBondDailyBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
         let response = self
             .client
-            .get::<ApiResponse<crate::data::bond::SmallBondDailyRecord>>(
-                "/bon/smb_bydd_trd",
-                &[("basDd", &base_date)],
-            )
+            .get_records::<SmallBondDaily>(self.base_date)
             .await?;
 
         parse_small_bond_daily(response)
@@ -1,5 +1,5 @@
 use crate::error::{Error, Result};
-use chrono::{Duration, Local};
+use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
 
 /// 날짜 문자열이 "YYYYMMDD" 형식인지 검증합니다.
 pub fn is_valid_date_format(date: &str) -> bool {
@@ -31,11 +31,41 @@ pub fn latest_workday_string() -> String {
     yesterday.format("%Y%m%d").to_string()
 }
 
+/// 시작일부터 종료일까지(양 끝 포함) 주말을 제외한 날짜 문자열(YYYYMMDD) 목록을 생성합니다.
+///
+/// 공휴일은 걸러내지 않으므로, 기간 조회 시 빈 응답이 오는 날짜는 건너뛰어야 합니다.
+///
+/// # Errors
+/// - 날짜 형식이 유효하지 않거나 시작일이 종료일보다 늦으면 `Error::InvalidInput` 반환.
+pub fn weekdays_between(start_date: &str, end_date: &str) -> Result<Vec<String>> {
+    let parse = |date: &str| {
+        NaiveDate::parse_from_str(date, "%Y%m%d")
+            .ok()
+            .filter(|_| is_valid_date_format(date))
+            .ok_or_else(|| Error::InvalidInput("date must be in YYYYMMDD format".to_string()))
+    };
+    let start = parse(start_date)?;
+    let end = parse(end_date)?;
+
+    if start > end {
+        return Err(Error::InvalidInput(
+            "start date must not be after end date".to_string(),
+        ));
+    }
+
+    Ok(start
+        .iter_days()
+        .take_while(|date| *date <= end)
+        .filter(|date| !matches!(date.weekday(), Weekday::Sat | Weekday::Sun))
+        .map(|date| date.format("%Y%m%d").to_string())
+        .collect())
+}
+
 /// 기본 빌더 매크로 - 공통 날짜 설정 메서드들을 생성
 #[macro_export]
 macro_rules! impl_date_builder_methods {
     ($builder_type:ty) => {
-        impl<'a> $builder_type {
+        impl $builder_type {
             /// 조회 기준일자 설정 (YYYYMMDD).
             ///
             /// KRX 데이터는 2010년 이후부터 조회일 기준 전일까지만 제공됩니다.
@@ -103,6 +133,16 @@ mod tests {
         assert_eq!(result.unwrap(), "20240105");
     }
 
+    #[test]
+    fn test_weekdays_between() {
+        // 2024-01-05(금) ~ 2024-01-08(월)
+        let dates = weekdays_between("20240105", "20240108").unwrap();
+        assert_eq!(dates, vec!["20240105", "20240108"]);
+
+        assert!(weekdays_between("20240108", "20240105").is_err());
+        assert!(weekdays_between("2024-01-05", "20240108").is_err());
+    }
+
     #[test]
     fn test_latest_workday_string() {
         let date = latest_workday_string();
@@ -1,7 +1,13 @@
 use crate::{
-    api::common::{latest_workday_string, validate_base_date},
+    api::{
+        common::latest_workday_string,
+        endpoint::{
+            EquityKosdaqFuturesDaily, EquityKosdaqOptionsDaily, EquityStockFuturesDaily,
+            EquityStockOptionsDaily, FuturesDaily, OptionsDaily,
+        },
+    },
     client::Client,
-    data::{ApiResponse, derivative::*},
+    data::derivative::*,
     error::Result,
 };
 use polars::prelude::DataFrame;
@@ -27,45 +33,45 @@ use polars::prelude::DataFrame;
 /// # Ok(())
 /// # }
 /// ```
-pub struct DerivativeApi<'a> {
-    pub(crate) client: &'a Client,
+pub struct DerivativeApi {
+    pub(crate) client: Client,
 }
 
-impl<'a> DerivativeApi<'a> {
-    pub(crate) fn new(client: &'a Client) -> Self {
+impl DerivativeApi {
+    pub(crate) fn new(client: Client) -> Self {
         Self { client }
     }
 
     /// 선물 전종목 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201050101)
-    pub fn futures_daily(&self) -> FuturesDailyBuilder<'a> {
-        FuturesDailyBuilder::new(self.client)
+    pub fn futures_daily(&self) -> FuturesDailyBuilder {
+        FuturesDailyBuilder::new(self.client.clone())
     }
 
     /// 개별주식선물 일별매매정보 조회
-    pub fn equity_stock_futures_daily(&self) -> EquityStockFuturesDailyBuilder<'a> {
-        EquityStockFuturesDailyBuilder::new(self.client)
+    pub fn equity_stock_futures_daily(&self) -> EquityStockFuturesDailyBuilder {
+        EquityStockFuturesDailyBuilder::new(self.client.clone())
     }
 
     /// 개별주식선물(코스닥) 일별매매정보 조회
-    pub fn equity_kosdaq_futures_daily(&self) -> EquityKosdaqFuturesDailyBuilder<'a> {
-        EquityKosdaqFuturesDailyBuilder::new(self.client)
+    pub fn equity_kosdaq_futures_daily(&self) -> EquityKosdaqFuturesDailyBuilder {
+        EquityKosdaqFuturesDailyBuilder::new(self.client.clone())
     }
 
     /// 옵션 일별매매정보 조회
-    pub fn options_daily(&self) -> OptionsDailyBuilder<'a> {
-        OptionsDailyBuilder::new(self.client)
+    pub fn options_daily(&self) -> OptionsDailyBuilder {
+        OptionsDailyBuilder::new(self.client.clone())
     }
 
     /// 개별주식옵션 일별매매정보 조회
-    pub fn equity_stock_options_daily(&self) -> EquityStockOptionsDailyBuilder<'a> {
-        EquityStockOptionsDailyBuilder::new(self.client)
+    pub fn equity_stock_options_daily(&self) -> EquityStockOptionsDailyBuilder {
+        EquityStockOptionsDailyBuilder::new(self.client.clone())
     }
 
     /// 개별주식옵션(코스닥) 일별매매정보 조회
-    pub fn equity_kosdaq_options_daily(&self) -> EquityKosdaqOptionsDailyBuilder<'a> {
-        EquityKosdaqOptionsDailyBuilder::new(self.client)
+    pub fn equity_kosdaq_options_daily(&self) -> EquityKosdaqOptionsDailyBuilder {
+        EquityKosdaqOptionsDailyBuilder::new(self.client.clone())
     }
 }
 
@@ -83,13 +89,13 @@ impl<'a> DerivativeApi<'a> {
 /// # }
 /// ```
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct FuturesDailyBuilder<'a> {
-    client: &'a Client,
+pub struct FuturesDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> FuturesDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl FuturesDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -110,10 +116,9 @@ impl<'a> FuturesDailyBuilder<'a> {
 
     /// 설정된 파라미터로 API를 호출하여 데이터를 가져옵니다.
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
         let response = self
             .client
-            .get::<ApiResponse<FuturesDailyRecord>>("/drv/fut_bydd_trd", &[("basDd", &base_date)])
+            .get_records::<FuturesDaily>(self.base_date)
             .await?;
 
         parse_futures_daily(response)
@@ -122,13 +127,13 @@ impl<'a> FuturesDailyBuilder<'a> {
 
 /// 개별주식선물 일별매매정보 빌더
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct EquityStockFuturesDailyBuilder<'a> {
-    client: &'a Client,
+pub struct EquityStockFuturesDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> EquityStockFuturesDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl EquityStockFuturesDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -150,14 +155,9 @@ impl<'a> EquityStockFuturesDailyBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
         let response = self
             .client
-            .get::<ApiResponse<EquityStockFuturesDailyRecord>>(
-                "/drv/eqsfu_stk_bydd_trd",
-                &[("basDd", &base_date)],
-            )
+            .get_records::<EquityStockFuturesDaily>(self.base_date)
             .await?;
 
         parse_equity_stock_futures_daily(response)
@@ -166,13 +166,13 @@ impl<'a> EquityStockFuturesDailyBuilder<'a> {
 
 /// 개별주식선물(코스닥) 일별매매정보 빌더
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct EquityKosdaqFuturesDailyBuilder<'a> {
-    client: &'a Client,
+pub struct EquityKosdaqFuturesDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> EquityKosdaqFuturesDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl EquityKosdaqFuturesDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -194,14 +194,9 @@ impl<'a> EquityKosdaqFuturesDailyBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
         let response = self
             .client
-            .get::<ApiResponse<EquityKosdaqFuturesDailyRecord>>(
-                "/drv/eqkfu_ksq_bydd_trd",
-                &[("basDd", &base_date)],
-            )
+            .get_records::<EquityKosdaqFuturesDaily>(self.base_date)
             .await?;
 
         parse_equity_kosdaq_futures_daily(response)
@@ -210,13 +205,13 @@ impl<'a> EquityKosdaqFuturesDailyBuilder<'a> {
 
 /// 옵션 일별매매정보 빌더
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct OptionsDailyBuilder<'a> {
-    client: &'a Client,
+pub struct OptionsDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> OptionsDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl OptionsDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -238,11 +233,9 @@ impl<'a> OptionsDailyBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
         let response = self
             .client
-            .get::<ApiResponse<OptionsDailyRecord>>("/drv/opt_bydd_trd", &[("basDd", &base_date)])
+            .get_records::<OptionsDaily>(self.base_date)
             .await?;
 
         parse_options_daily(response)
@@ -251,13 +244,13 @@ impl<'a> OptionsDailyBuilder<'a> {
 
 /// 개별주식옵션 일별매매정보 빌더
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct EquityStockOptionsDailyBuilder<'a> {
-    client: &'a Client,
+pub struct EquityStockOptionsDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> EquityStockOptionsDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl EquityStockOptionsDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -279,14 +272,9 @@ impl<'a> EquityStockOptionsDailyBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
         let response = self
             .client
-            .get::<ApiResponse<EquityStockOptionsDailyRecord>>(
-                "/drv/eqsop_bydd_trd",
-                &[("basDd", &base_date)],
-            )
+            .get_records::<EquityStockOptionsDaily>(self.base_date)
             .await?;
 
         parse_equity_stock_options_daily(response)
@@ -295,13 +283,13 @@ impl<'a> EquityStockOptionsDailyBuilder<'a> {
 
 /// 개별주식옵션(코스닥) 일별매매정보 빌더
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct EquityKosdaqOptionsDailyBuilder<'a> {
-    client: &'a Client,
+pub struct EquityKosdaqOptionsDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> EquityKosdaqOptionsDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl EquityKosdaqOptionsDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -323,14 +311,9 @@ impl<'a> EquityKosdaqOptionsDailyBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
         let response = self
             .client
-            .get::<ApiResponse<EquityKosdaqOptionsDailyRecord>>(
-                "/drv/eqkop_bydd_trd",
-                &[("basDd", &base_date)],
-            )
+            .get_records::<EquityKosdaqOptionsDaily>(self.base_date)
             .await?;
 
         parse_equity_kosdaq_options_daily(response)
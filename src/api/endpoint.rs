@@ -0,0 +1,407 @@
+//! 모든 KRX 엔드포인트를 하나의 타입으로 다루기 위한 [`Endpoint`] 트레이트와
+//! 이름으로 엔드포인트를 찾는 레지스트리입니다.
+//!
+//! 엔드포인트마다 API 경로, 응답 레코드 타입, DataFrame 변환 함수를 묶은 표식 타입이
+//! 정의되어 있으므로, 여러 엔드포인트에 대한 처리를 제네릭 코드 하나로 작성할 수 있습니다.
+//! 설정 파일처럼 엔드포인트를 문자열(`sto/stk_bydd_trd`)로 다뤄야 할 때는 [`ALL`],
+//! [`find`], [`Client::fetch_by_name`](crate::Client::fetch_by_name)을 사용합니다.
+//!
+//! # 예시
+//! ```rust,no_run
+//! use krx_rs::{Client, api::endpoint::{Endpoint, EtfDaily, StockDaily}};
+//!
+//! async fn row_count<E: Endpoint>(client: &Client, date: &str) -> krx_rs::Result<usize> {
+//!     Ok(client.fetch::<E>(date).await?.height())
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() -> krx_rs::Result<()> {
+//! let client = Client::new("YOUR_AUTH_KEY");
+//! let stocks = row_count::<StockDaily>(&client, "20240105").await?;
+//! let etfs = row_count::<EtfDaily>(&client, "20240105").await?;
+//! println!("{}: {stocks}, {}: {etfs}", StockDaily::PATH, EtfDaily::PATH);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    client::Client,
+    data::{
+        ApiResponse,
+        bond::{self, BondDailyRecord, KtsDailyRecord, SmallBondDailyRecord},
+        derivative::{
+            self, EquityKosdaqFuturesDailyRecord, EquityKosdaqOptionsDailyRecord,
+            EquityStockFuturesDailyRecord, EquityStockOptionsDailyRecord, FuturesDailyRecord,
+            OptionsDailyRecord,
+        },
+        esg::{self, SriBondInfoRecord},
+        etp::{self, ElwDailyRecord, EtfDailyRecord, EtnDailyRecord},
+        general::{self, EmissionsDailyRecord, GoldDailyRecord, OilDailyRecord},
+        index::{
+            self, BondIndexDailyRecord, DerivativeIndexDailyRecord, KosdaqIndexDailyRecord,
+            KospiIndexDailyRecord, KrxIndexDailyRecord,
+        },
+        stock::{
+            self, KonexDailyRecord, KosdaqDailyRecord, StockBaseInfoRecord, StockDailyRecord,
+            StockRightDailyRecord, StockWarrantDailyRecord,
+        },
+    },
+    error::{Error, Result},
+    schema::{self, EndpointSchema},
+};
+use polars::prelude::{DataFrame, Schema};
+use serde::de::DeserializeOwned;
+use std::{fmt, future::Future, pin::Pin};
+
+/// 엔드포인트가 속한 API 카테고리
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Category {
+    /// 주식 (`/sto`)
+    Stock,
+    /// 지수 (`/idx`)
+    Index,
+    /// 채권 (`/bon`)
+    Bond,
+    /// ETP (`/etp`)
+    Etp,
+    /// 파생상품 (`/drv`)
+    Derivative,
+    /// 일반상품 (`/gen`)
+    General,
+    /// ESG (`/esg`)
+    Esg,
+}
+
+impl Category {
+    /// 전체 카테고리
+    pub const ALL: [Category; 7] = [
+        Category::Stock,
+        Category::Index,
+        Category::Bond,
+        Category::Etp,
+        Category::Derivative,
+        Category::General,
+        Category::Esg,
+    ];
+
+    /// API 경로의 첫 부분 (예: `sto`)
+    pub fn prefix(self) -> &'static str {
+        match self {
+            Category::Stock => "sto",
+            Category::Index => "idx",
+            Category::Bond => "bon",
+            Category::Etp => "etp",
+            Category::Derivative => "drv",
+            Category::General => "gen",
+            Category::Esg => "esg",
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.prefix())
+    }
+}
+
+/// 기준일자 하나로 조회하는 KRX 엔드포인트
+///
+/// [`Client::fetch`](crate::Client::fetch)로 호출하며, 이 모듈의 표식 타입들이
+/// 지원하는 모든 엔드포인트를 구현합니다.
+pub trait Endpoint {
+    /// 기본 URL 뒤에 붙는 API 경로 (예: `/sto/stk_bydd_trd`)
+    const PATH: &'static str;
+    /// 엔드포인트가 속한 카테고리
+    const CATEGORY: Category;
+    /// 엔드포인트 설명 (예: `유가증권 일별매매정보`)
+    const DESCRIPTION: &'static str;
+    /// 같은 기준일 안에서 행을 식별하는 DataFrame 컬럼 (예: `종목코드`)
+    const KEY_COLUMNS: &'static [&'static str];
+    /// `OutBlock_1`의 레코드 타입
+    type Record: DeserializeOwned + Send + 'static;
+
+    /// 응답을 DataFrame으로 변환합니다.
+    fn parse(response: ApiResponse<Self::Record>) -> Result<DataFrame>;
+}
+
+macro_rules! endpoints {
+    ($(#[doc = $description:literal] $name:ident => $category:ident, $path:literal, [$($key:literal),+], $record:ty, $parse:path;)+) => {
+        $(
+            #[doc = $description]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub struct $name;
+
+            impl Endpoint for $name {
+                const PATH: &'static str = $path;
+                const CATEGORY: Category = Category::$category;
+                const DESCRIPTION: &'static str = $description.trim_ascii();
+                const KEY_COLUMNS: &'static [&'static str] = &[$($key),+];
+                type Record = $record;
+
+                fn parse(response: ApiResponse<Self::Record>) -> Result<DataFrame> {
+                    $parse(response)
+                }
+            }
+        )+
+
+        /// 지원하는 전체 엔드포인트
+        pub const ALL: &[EndpointInfo] = &[$(EndpointInfo::of::<$name>()),+];
+    };
+}
+
+endpoints! {
+    /// 유가증권 일별매매정보
+    StockDaily => Stock, "/sto/stk_bydd_trd", ["종목코드"], StockDailyRecord, stock::parse_stock_daily;
+    /// 코스닥 일별매매정보
+    KosdaqDaily => Stock, "/sto/ksq_bydd_trd", ["종목코드"], KosdaqDailyRecord, stock::parse_kosdaq_daily;
+    /// 코넥스 일별매매정보
+    KonexDaily => Stock, "/sto/knx_bydd_trd", ["종목코드"], KonexDailyRecord, stock::parse_konex_daily;
+    /// 신주인수권증권 일별매매정보
+    StockWarrantDaily => Stock, "/sto/sw_bydd_trd", ["종목코드"], StockWarrantDailyRecord, stock::parse_stock_warrant_daily;
+    /// 신주인수권증서 일별매매정보
+    StockRightDaily => Stock, "/sto/sr_bydd_trd", ["종목코드"], StockRightDailyRecord, stock::parse_stock_right_daily;
+    /// 유가증권 종목기본정보
+    StockBaseInfo => Stock, "/sto/stk_isu_base_info", ["표준코드"], StockBaseInfoRecord, stock::parse_stock_base_info;
+    /// 코스닥 종목기본정보
+    KosdaqBaseInfo => Stock, "/sto/ksq_isu_base_info", ["표준코드"], StockBaseInfoRecord, stock::parse_stock_base_info;
+    /// 코넥스 종목기본정보
+    KonexBaseInfo => Stock, "/sto/knx_isu_base_info", ["표준코드"], StockBaseInfoRecord, stock::parse_stock_base_info;
+    /// KRX 시리즈 일별시세정보
+    KrxIndexDaily => Index, "/idx/krx_dd_trd", ["계열구분", "지수명"], KrxIndexDailyRecord, index::parse_krx_index_daily;
+    /// KOSPI 시리즈 일별시세정보
+    KospiIndexDaily => Index, "/idx/kospi_dd_trd", ["계열구분", "지수명"], KospiIndexDailyRecord, index::parse_kospi_index_daily;
+    /// KOSDAQ 시리즈 일별시세정보
+    KosdaqIndexDaily => Index, "/idx/kosdaq_dd_trd", ["계열구분", "지수명"], KosdaqIndexDailyRecord, index::parse_kosdaq_index_daily;
+    /// 채권지수 시세정보
+    BondIndexDaily => Index, "/idx/bon_dd_trd", ["지수명"], BondIndexDailyRecord, index::parse_bond_index_daily;
+    /// 파생상품지수 시세정보
+    DerivativeIndexDaily => Index, "/idx/drvprod_dd_trd", ["계열구분", "지수명"], DerivativeIndexDailyRecord, index::parse_derivative_index_daily;
+    /// 국채전문유통시장 일별매매정보
+    KtsDaily => Bond, "/bon/kts_bydd_trd", ["종목코드"], KtsDailyRecord, bond::parse_kts_daily;
+    /// 일반채권시장 일별매매정보
+    BondDaily => Bond, "/bon/bnd_bydd_trd", ["종목코드"], BondDailyRecord, bond::parse_bond_daily;
+    /// 소액채권시장 일별매매정보
+    SmallBondDaily => Bond, "/bon/smb_bydd_trd", ["종목코드"], SmallBondDailyRecord, bond::parse_small_bond_daily;
+    /// ETF 일별매매정보
+    EtfDaily => Etp, "/etp/etf_bydd_trd", ["종목코드"], EtfDailyRecord, etp::parse_etf_daily;
+    /// ETN 일별매매정보
+    EtnDaily => Etp, "/etp/etn_bydd_trd", ["종목코드"], EtnDailyRecord, etp::parse_etn_daily;
+    /// ELW 일별매매정보
+    ElwDaily => Etp, "/etp/elw_bydd_trd", ["종목코드"], ElwDailyRecord, etp::parse_elw_daily;
+    /// 선물 일별매매정보 (주식선물 제외)
+    FuturesDaily => Derivative, "/drv/fut_bydd_trd", ["종목코드"], FuturesDailyRecord, derivative::parse_futures_daily;
+    /// 주식선물(유가) 일별매매정보
+    EquityStockFuturesDaily => Derivative, "/drv/eqsfu_stk_bydd_trd", ["종목코드"], EquityStockFuturesDailyRecord, derivative::parse_equity_stock_futures_daily;
+    /// 주식선물(코스닥) 일별매매정보
+    EquityKosdaqFuturesDaily => Derivative, "/drv/eqkfu_ksq_bydd_trd", ["종목코드"], EquityKosdaqFuturesDailyRecord, derivative::parse_equity_kosdaq_futures_daily;
+    /// 옵션 일별매매정보 (주식옵션 제외)
+    OptionsDaily => Derivative, "/drv/opt_bydd_trd", ["종목코드"], OptionsDailyRecord, derivative::parse_options_daily;
+    /// 주식옵션(유가) 일별매매정보
+    EquityStockOptionsDaily => Derivative, "/drv/eqsop_bydd_trd", ["종목코드"], EquityStockOptionsDailyRecord, derivative::parse_equity_stock_options_daily;
+    /// 주식옵션(코스닥) 일별매매정보
+    EquityKosdaqOptionsDaily => Derivative, "/drv/eqkop_bydd_trd", ["종목코드"], EquityKosdaqOptionsDailyRecord, derivative::parse_equity_kosdaq_options_daily;
+    /// 석유시장 일별매매정보
+    OilDaily => General, "/gen/oil_bydd_trd", ["유종명"], OilDailyRecord, general::parse_oil_daily;
+    /// 금시장 일별매매정보
+    GoldDaily => General, "/gen/gold_bydd_trd", ["종목코드"], GoldDailyRecord, general::parse_gold_daily;
+    /// 배출권 시장 일별매매정보
+    EmissionsDaily => General, "/gen/ets_bydd_trd", ["종목코드"], EmissionsDailyRecord, general::parse_emissions_daily;
+    /// 사회책임투자채권 정보
+    SriBondInfo => Esg, "/esg/sri_bond_info", ["표준코드"], SriBondInfoRecord, esg::parse_sri_bond_info;
+}
+
+/// 기준일자 하나에 대한 조회 함수
+pub type FetchFn =
+    for<'a> fn(&'a Client, String) -> Pin<Box<dyn Future<Output = Result<DataFrame>> + Send + 'a>>;
+
+/// 레지스트리에 등록된 엔드포인트 정보
+///
+/// [`Endpoint`] 구현을 값으로 다룰 수 있게 한 것으로, [`ALL`]과 [`find`]로 얻습니다.
+#[derive(Clone, Copy)]
+pub struct EndpointInfo {
+    /// 기본 URL 뒤에 붙는 API 경로 (예: `/sto/stk_bydd_trd`)
+    pub path: &'static str,
+    /// 엔드포인트가 속한 카테고리
+    pub category: Category,
+    /// 엔드포인트 설명
+    pub description: &'static str,
+    /// 같은 기준일 안에서 행을 식별하는 DataFrame 컬럼 (예: `종목코드`)
+    pub key_columns: &'static [&'static str],
+    fetch: FetchFn,
+    schema: fn() -> Result<Schema>,
+    describe: fn() -> Result<EndpointSchema>,
+}
+
+impl EndpointInfo {
+    /// [`Endpoint`] 구현으로부터 엔드포인트 정보를 만듭니다.
+    pub const fn of<E: Endpoint + 'static>() -> Self {
+        Self {
+            path: E::PATH,
+            category: E::CATEGORY,
+            description: E::DESCRIPTION,
+            key_columns: E::KEY_COLUMNS,
+            fetch: |client, date| Box::pin(client.fetch::<E>(date)),
+            schema: schema::sample_schema::<E>,
+            describe: schema::describe::<E>,
+        }
+    }
+
+    /// 엔드포인트 이름 (앞의 `/`를 뺀 API 경로, 예: `sto/stk_bydd_trd`)
+    pub fn name(&self) -> &'static str {
+        &self.path[1..]
+    }
+
+    /// 기준일자(YYYYMMDD) 하나의 데이터를 API에서 조회합니다.
+    pub async fn fetch(&self, client: &Client, date: impl Into<String>) -> Result<DataFrame> {
+        (self.fetch)(client, date.into()).await
+    }
+
+    /// API를 호출하지 않고 조회 결과 DataFrame의 스키마를 반환합니다.
+    pub fn schema(&self) -> Result<Schema> {
+        (self.schema)()
+    }
+
+    /// API를 호출하지 않고 컬럼별 KRX 필드·단위·null 허용 여부를 포함한 스키마를 반환합니다.
+    ///
+    /// 자세한 내용은 [`crate::schema`] 모듈을 참고하세요.
+    pub fn describe(&self) -> Result<EndpointSchema> {
+        (self.describe)()
+    }
+}
+
+impl fmt::Debug for EndpointInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EndpointInfo")
+            .field("path", &self.path)
+            .field("category", &self.category)
+            .field("description", &self.description)
+            .field("key_columns", &self.key_columns)
+            .finish()
+    }
+}
+
+impl PartialEq for EndpointInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path
+    }
+}
+
+/// 이름(`sto/stk_bydd_trd`, 앞의 `/`는 있어도 됨)으로 엔드포인트를 찾습니다.
+///
+/// # Errors
+/// - 등록되지 않은 이름이면 비슷한 이름 목록과 함께 `Error::InvalidInput` 반환.
+pub fn find(name: &str) -> Result<&'static EndpointInfo> {
+    let name = name.trim().trim_start_matches('/');
+    if let Some(info) = ALL.iter().find(|info| info.name() == name) {
+        return Ok(info);
+    }
+
+    let mut candidates: Vec<(usize, &str)> = ALL
+        .iter()
+        .map(|info| (edit_distance(name, info.name()), info.name()))
+        .filter(|(distance, candidate)| {
+            *distance <= name.len().max(3) / 3 || (!name.is_empty() && candidate.contains(name))
+        })
+        .collect();
+    candidates.sort();
+
+    let message = if candidates.is_empty() {
+        format!("unknown endpoint `{name}`; see `api::endpoint::ALL` for supported endpoints")
+    } else {
+        let names: Vec<&str> = candidates.iter().take(5).map(|(_, name)| *name).collect();
+        format!(
+            "unknown endpoint `{name}`; did you mean: {}?",
+            names.join(", ")
+        )
+    };
+    Err(Error::InvalidInput(message))
+}
+
+/// 두 문자열의 편집 거리 (Levenshtein)
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_covers_every_category() {
+        assert_eq!(ALL.len(), 29);
+        for info in ALL {
+            assert!(info.name().starts_with(info.category.prefix()));
+            assert!(!info.description.is_empty());
+            assert!(!info.description.starts_with(' '));
+        }
+        for category in Category::ALL {
+            assert!(ALL.iter().any(|info| info.category == category));
+        }
+    }
+
+    #[test]
+    fn test_every_endpoint_has_schema() {
+        for info in ALL {
+            let schema = info.schema().unwrap();
+            assert!(!schema.is_empty(), "{} has empty schema", info.name());
+            for key in info.key_columns {
+                assert!(
+                    schema.contains(key),
+                    "{} has no key column {key}",
+                    info.name()
+                );
+            }
+        }
+        let schema = find("sto/stk_bydd_trd").unwrap().schema().unwrap();
+        assert!(schema.contains("종목코드"));
+        assert!(schema.contains("종가"));
+    }
+
+    #[test]
+    fn test_find_by_name() {
+        assert_eq!(find("etp/etf_bydd_trd").unwrap().path, EtfDaily::PATH);
+        assert_eq!(find("/drv/opt_bydd_trd").unwrap().path, OptionsDaily::PATH);
+    }
+
+    #[test]
+    fn test_find_unknown_name_suggests_near_matches() {
+        match find("etp/etf_byd_trd") {
+            Err(Error::InvalidInput(message)) => {
+                assert!(message.contains("did you mean"), "{message}");
+                assert!(message.contains("etp/etf_bydd_trd"), "{message}");
+            }
+            other => panic!("Expected InvalidInput error, got {other:?}"),
+        }
+
+        match find("opt_bydd_trd") {
+            Err(Error::InvalidInput(message)) => {
+                assert!(message.contains("drv/opt_bydd_trd"), "{message}");
+            }
+            other => panic!("Expected InvalidInput error, got {other:?}"),
+        }
+
+        match find("completely/unrelated") {
+            Err(Error::InvalidInput(message)) => {
+                assert!(!message.contains("did you mean"), "{message}");
+            }
+            other => panic!("Expected InvalidInput error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("same", "same"), 0);
+    }
+}
@@ -1,7 +1,11 @@
 use crate::{
-    api::common::{latest_workday_string, validate_base_date},
+    analysis::sri::sri_bond_view,
+    api::{
+        common::{latest_workday_string, validate_base_date},
+        endpoint::SriBondInfo,
+    },
     client::Client,
-    data::{ApiResponse, esg::*},
+    data::esg::*,
     error::Result,
 };
 use polars::prelude::DataFrame;
@@ -23,20 +27,20 @@ use polars::prelude::DataFrame;
 /// # Ok(())
 /// # }
 /// ```
-pub struct EsgApi<'a> {
-    pub(crate) client: &'a Client,
+pub struct EsgApi {
+    pub(crate) client: Client,
 }
 
-impl<'a> EsgApi<'a> {
-    pub(crate) fn new(client: &'a Client) -> Self {
+impl EsgApi {
+    pub(crate) fn new(client: Client) -> Self {
         Self { client }
     }
 
     /// 사회책임투자(SRI) 채권 종목정보.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201080101)
-    pub fn sri_bond_info(&self) -> SriBondInfoBuilder<'a> {
-        SriBondInfoBuilder::new(self.client)
+    pub fn sri_bond_info(&self) -> SriBondInfoBuilder {
+        SriBondInfoBuilder::new(self.client.clone())
     }
 }
 
@@ -54,13 +58,13 @@ impl<'a> EsgApi<'a> {
 /// # }
 /// ```
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct SriBondInfoBuilder<'a> {
-    client: &'a Client,
+pub struct SriBondInfoBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> SriBondInfoBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl SriBondInfoBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -81,12 +85,23 @@ impl<'a> SriBondInfoBuilder<'a> {
 
     /// 설정된 파라미터로 API를 호출하여 데이터를 가져옵니다.
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
         let response = self
             .client
-            .get::<ApiResponse<SriBondInfoRecord>>("/esg/sri_bond_info", &[("basDd", &base_date)])
+            .get_records::<SriBondInfo>(self.base_date)
             .await?;
 
         parse_sri_bond_info(response)
     }
+
+    /// 종목정보에 분류, 잔존만기 구간, 같은 날짜의 일반채권 시세를 결합하여 반환합니다.
+    ///
+    /// 자세한 컬럼 구성은 [`crate::analysis::sri::sri_bond_view`]를 참고하세요.
+    pub async fn fetch_with_prices(self) -> Result<DataFrame> {
+        let client = self.client.clone();
+        let base_date = validate_base_date(self.base_date.clone())?;
+        let info = self.fetch().await?;
+        let prices = client.bond().bond_daily().date(base_date).fetch().await?;
+
+        sri_bond_view(&info, &prices)
+    }
 }
@@ -1,7 +1,10 @@
 use crate::{
-    api::common::{latest_workday_string, validate_base_date},
+    api::{
+        common::latest_workday_string,
+        endpoint::{ElwDaily, EtfDaily, EtnDaily},
+    },
     client::Client,
-    data::{ApiResponse, etp::*},
+    data::etp::*,
     error::Result,
 };
 use polars::prelude::DataFrame;
@@ -27,34 +30,34 @@ use polars::prelude::DataFrame;
 /// # Ok(())
 /// # }
 /// ```
-pub struct EtpApi<'a> {
-    pub(crate) client: &'a Client,
+pub struct EtpApi {
+    pub(crate) client: Client,
 }
 
-impl<'a> EtpApi<'a> {
-    pub(crate) fn new(client: &'a Client) -> Self {
+impl EtpApi {
+    pub(crate) fn new(client: Client) -> Self {
         Self { client }
     }
 
     /// ETF 전종목 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201030101)
-    pub fn etf_daily(&self) -> EtfDailyBuilder<'a> {
-        EtfDailyBuilder::new(self.client)
+    pub fn etf_daily(&self) -> EtfDailyBuilder {
+        EtfDailyBuilder::new(self.client.clone())
     }
 
     /// ETN 전종목 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201030201)
-    pub fn etn_daily(&self) -> EtnDailyBuilder<'a> {
-        EtnDailyBuilder::new(self.client)
+    pub fn etn_daily(&self) -> EtnDailyBuilder {
+        EtnDailyBuilder::new(self.client.clone())
     }
 
     /// ELW 전종목 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201030301)
-    pub fn elw_daily(&self) -> ElwDailyBuilder<'a> {
-        ElwDailyBuilder::new(self.client)
+    pub fn elw_daily(&self) -> ElwDailyBuilder {
+        ElwDailyBuilder::new(self.client.clone())
     }
 }
 
@@ -72,13 +75,13 @@ impl<'a> EtpApi<'a> {
 /// # }
 /// ```
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct EtfDailyBuilder<'a> {
-    client: &'a Client,
+pub struct EtfDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> EtfDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl EtfDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -99,11 +102,7 @@ impl<'a> EtfDailyBuilder<'a> {
 
     /// 설정된 파라미터로 API를 호출하여 데이터를 가져옵니다.
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-        let response = self
-            .client
-            .get::<ApiResponse<EtfDailyRecord>>("/etp/etf_bydd_trd", &[("basDd", &base_date)])
-            .await?;
+        let response = self.client.get_records::<EtfDaily>(self.base_date).await?;
 
         parse_etf_daily(response)
     }
@@ -111,13 +110,13 @@ impl<'a> EtfDailyBuilder<'a> {
 
 /// ETN 일별매매정보 빌더
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct EtnDailyBuilder<'a> {
-    client: &'a Client,
+pub struct EtnDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> EtnDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl EtnDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -139,15 +138,7 @@ impl<'a> EtnDailyBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
-        let response = self
-            .client
-            .get::<ApiResponse<crate::data::etp::EtnDailyRecord>>(
-                "/etp/etn_bydd_trd",
-                &[("basDd", &base_date)],
-            )
-            .await?;
+        let response = self.client.get_records::<EtnDaily>(self.base_date).await?;
 
         parse_etn_daily(response)
     }
@@ -155,13 +146,13 @@ impl<'a> EtnDailyBuilder<'a> {
 
 /// ELW 일별매매정보 빌더
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct ElwDailyBuilder<'a> {
-    client: &'a Client,
+pub struct ElwDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> ElwDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl ElwDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -183,15 +174,7 @@ impl<'a> ElwDailyBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
-        let response = self
-            .client
-            .get::<ApiResponse<crate::data::etp::ElwDailyRecord>>(
-                "/etp/elw_bydd_trd",
-                &[("basDd", &base_date)],
-            )
-            .await?;
+        let response = self.client.get_records::<ElwDaily>(self.base_date).await?;
 
         parse_elw_daily(response)
     }
@@ -1,7 +1,10 @@
 use crate::{
-    api::common::{latest_workday_string, validate_base_date},
+    api::{
+        common::latest_workday_string,
+        endpoint::{EmissionsDaily, GoldDaily, OilDaily},
+    },
     client::Client,
-    data::{ApiResponse, general::*},
+    data::general::*,
     error::Result,
 };
 use polars::prelude::DataFrame;
@@ -27,34 +30,34 @@ use polars::prelude::DataFrame;
 /// # Ok(())
 /// # }
 /// ```
-pub struct GeneralApi<'a> {
-    pub(crate) client: &'a Client,
+pub struct GeneralApi {
+    pub(crate) client: Client,
 }
 
-impl<'a> GeneralApi<'a> {
-    pub(crate) fn new(client: &'a Client) -> Self {
+impl GeneralApi {
+    pub(crate) fn new(client: Client) -> Self {
         Self { client }
     }
 
     /// 유가증권시장 석유(오일) 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201070101)
-    pub fn oil_daily(&self) -> OilDailyBuilder<'a> {
-        OilDailyBuilder::new(self.client)
+    pub fn oil_daily(&self) -> OilDailyBuilder {
+        OilDailyBuilder::new(self.client.clone())
     }
 
     /// KRX 금시장 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201070201)
-    pub fn gold_daily(&self) -> GoldDailyBuilder<'a> {
-        GoldDailyBuilder::new(self.client)
+    pub fn gold_daily(&self) -> GoldDailyBuilder {
+        GoldDailyBuilder::new(self.client.clone())
     }
 
     /// 배출권시장 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201070301)
-    pub fn emissions_daily(&self) -> EmissionsDailyBuilder<'a> {
-        EmissionsDailyBuilder::new(self.client)
+    pub fn emissions_daily(&self) -> EmissionsDailyBuilder {
+        EmissionsDailyBuilder::new(self.client.clone())
     }
 }
 
@@ -72,13 +75,13 @@ impl<'a> GeneralApi<'a> {
 /// # }
 /// ```
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct OilDailyBuilder<'a> {
-    client: &'a Client,
+pub struct OilDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> OilDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl OilDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -99,11 +102,7 @@ impl<'a> OilDailyBuilder<'a> {
 
     /// 설정된 파라미터로 API를 호출하여 데이터를 가져옵니다.
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-        let response = self
-            .client
-            .get::<ApiResponse<OilDailyRecord>>("/gen/oil_bydd_trd", &[("basDd", &base_date)])
-            .await?;
+        let response = self.client.get_records::<OilDaily>(self.base_date).await?;
 
         parse_oil_daily(response)
     }
@@ -111,13 +110,13 @@ impl<'a> OilDailyBuilder<'a> {
 
 /// 금 일별매매정보 빌더
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct GoldDailyBuilder<'a> {
-    client: &'a Client,
+pub struct GoldDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> GoldDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl GoldDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -139,15 +138,7 @@ impl<'a> GoldDailyBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
-        let response = self
-            .client
-            .get::<ApiResponse<crate::data::general::GoldDailyRecord>>(
-                "/gen/gold_bydd_trd",
-                &[("basDd", &base_date)],
-            )
-            .await?;
+        let response = self.client.get_records::<GoldDaily>(self.base_date).await?;
 
         parse_gold_daily(response)
     }
@@ -155,13 +146,13 @@ impl<'a> GoldDailyBuilder<'a> {
 
 /// 배출권 일별매매정보 빌더
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct EmissionsDailyBuilder<'a> {
-    client: &'a Client,
+pub struct EmissionsDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> EmissionsDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl EmissionsDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -183,11 +174,9 @@ impl<'a> EmissionsDailyBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
         let response = self
             .client
-            .get::<ApiResponse<EmissionsDailyRecord>>("/gen/ets_bydd_trd", &[("basDd", &base_date)])
+            .get_records::<EmissionsDaily>(self.base_date)
             .await?;
 
         parse_emissions_daily(response)
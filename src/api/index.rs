@@ -1,9 +1,18 @@
 use crate::{
-    api::common::{latest_workday_string, validate_base_date},
+    analysis::bond_index::{BondIndexGroup, BondIndexVariant, bond_index_series, compare_groups},
+    api::{
+        common::{latest_workday_string, validate_base_date},
+        endpoint::{
+            BondIndexDaily, DerivativeIndexDaily, KosdaqIndexDaily, KospiIndexDaily, KrxIndexDaily,
+        },
+    },
+    calendar::TradingCalendar,
     client::Client,
-    data::{ApiResponse, index::*},
+    data::index::*,
     error::Result,
+    stream::{DEFAULT_CONCURRENCY, RangeStreamBuilder},
 };
+use futures::StreamExt;
 use polars::prelude::DataFrame;
 
 /// 주가지수(Index) 관련 API 엔드포인트를 제공합니다.
@@ -27,48 +36,59 @@ use polars::prelude::DataFrame;
 /// # Ok(())
 /// # }
 /// ```
-pub struct IndexApi<'a> {
-    pub(crate) client: &'a Client,
+pub struct IndexApi {
+    pub(crate) client: Client,
 }
 
-impl<'a> IndexApi<'a> {
-    pub(crate) fn new(client: &'a Client) -> Self {
+impl IndexApi {
+    pub(crate) fn new(client: Client) -> Self {
         Self { client }
     }
 
     /// KRX 지수 전종목 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201010101)
-    pub fn krx_daily(&self) -> KrxIndexDailyBuilder<'a> {
-        KrxIndexDailyBuilder::new(self.client)
+    pub fn krx_daily(&self) -> KrxIndexDailyBuilder {
+        KrxIndexDailyBuilder::new(self.client.clone())
     }
 
     /// 코스피 지수 전종목 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201010102)
-    pub fn stock_daily(&self) -> KospiIndexDailyBuilder<'a> {
-        KospiIndexDailyBuilder::new(self.client)
+    pub fn stock_daily(&self) -> KospiIndexDailyBuilder {
+        KospiIndexDailyBuilder::new(self.client.clone())
     }
 
     /// 코스닥 지수 전종목 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201010103)
-    pub fn kosdaq_daily(&self) -> KosdaqIndexDailyBuilder<'a> {
-        KosdaqIndexDailyBuilder::new(self.client)
+    pub fn kosdaq_daily(&self) -> KosdaqIndexDailyBuilder {
+        KosdaqIndexDailyBuilder::new(self.client.clone())
     }
 
     /// 채권 지수 전종목 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201010104)
-    pub fn bond_daily(&self) -> BondIndexDailyBuilder<'a> {
-        BondIndexDailyBuilder::new(self.client)
+    pub fn bond_daily(&self) -> BondIndexDailyBuilder {
+        BondIndexDailyBuilder::new(self.client.clone())
+    }
+
+    /// 채권지수 한 그룹·지수 구분의 기간 시계열.
+    ///
+    /// 자세한 컬럼 구성은 [`crate::analysis::bond_index::bond_index_series`]를 참고하세요.
+    pub fn bond_series(
+        &self,
+        group: BondIndexGroup,
+        variant: BondIndexVariant,
+    ) -> BondIndexSeriesBuilder {
+        BondIndexSeriesBuilder::new(self.client.clone(), group, variant)
     }
 
     /// 파생상품 지수 전종목 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201010105)
-    pub fn derivative_daily(&self) -> DerivativeIndexDailyBuilder<'a> {
-        DerivativeIndexDailyBuilder::new(self.client)
+    pub fn derivative_daily(&self) -> DerivativeIndexDailyBuilder {
+        DerivativeIndexDailyBuilder::new(self.client.clone())
     }
 }
 
@@ -86,13 +106,13 @@ impl<'a> IndexApi<'a> {
 /// # }
 /// ```
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct KrxIndexDailyBuilder<'a> {
-    client: &'a Client,
+pub struct KrxIndexDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> KrxIndexDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl KrxIndexDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -113,10 +133,9 @@ impl<'a> KrxIndexDailyBuilder<'a> {
 
     /// 설정된 파라미터로 API를 호출하여 데이터를 가져옵니다.
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
         let response = self
             .client
-            .get::<ApiResponse<KrxIndexDailyRecord>>("/idx/krx_dd_trd", &[("basDd", &base_date)])
+            .get_records::<KrxIndexDaily>(self.base_date)
             .await?;
 
         parse_krx_index_daily(response)
@@ -125,13 +144,13 @@ impl<'a> KrxIndexDailyBuilder<'a> {
 
 /// 코스피 지수 전종목 일별 시세를 조회하는 빌더입니다.
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct KospiIndexDailyBuilder<'a> {
-    client: &'a Client,
+pub struct KospiIndexDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> KospiIndexDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl KospiIndexDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -152,13 +171,9 @@ impl<'a> KospiIndexDailyBuilder<'a> {
 
     /// 설정된 파라미터로 API를 호출하여 데이터를 가져옵니다.
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
         let response = self
             .client
-            .get::<ApiResponse<KospiIndexDailyRecord>>(
-                "/idx/kospi_dd_trd",
-                &[("basDd", &base_date)],
-            )
+            .get_records::<KospiIndexDaily>(self.base_date)
             .await?;
 
         parse_kospi_index_daily(response)
@@ -167,13 +182,13 @@ impl<'a> KospiIndexDailyBuilder<'a> {
 
 /// KOSDAQ 지수 일별시세정보 빌더 (기본 구조)
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct KosdaqIndexDailyBuilder<'a> {
-    client: &'a Client,
+pub struct KosdaqIndexDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> KosdaqIndexDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl KosdaqIndexDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -195,14 +210,9 @@ impl<'a> KosdaqIndexDailyBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
         let response = self
             .client
-            .get::<ApiResponse<KosdaqIndexDailyRecord>>(
-                "/idx/kosdaq_dd_trd",
-                &[("basDd", &base_date)],
-            )
+            .get_records::<KosdaqIndexDaily>(self.base_date)
             .await?;
 
         parse_kosdaq_index_daily(response)
@@ -211,13 +221,13 @@ impl<'a> KosdaqIndexDailyBuilder<'a> {
 
 /// 채권지수 시세정보 빌더
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct BondIndexDailyBuilder<'a> {
-    client: &'a Client,
+pub struct BondIndexDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> BondIndexDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl BondIndexDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -239,26 +249,123 @@ impl<'a> BondIndexDailyBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
         let response = self
             .client
-            .get::<ApiResponse<BondIndexDailyRecord>>("/idx/bon_dd_trd", &[("basDd", &base_date)])
+            .get_records::<BondIndexDaily>(self.base_date)
             .await?;
 
         parse_bond_index_daily(response)
     }
+
+    /// 그룹별 듀레이션·컨벡시티·YTM 비교표를 반환합니다.
+    ///
+    /// 자세한 형식은 [`crate::analysis::bond_index::compare_groups`]를 참고하세요.
+    pub async fn fetch_group_comparison(self) -> Result<DataFrame> {
+        compare_groups(&self.fetch().await?)
+    }
+}
+
+/// 채권지수 기간 시계열 빌더
+///
+/// 시작일부터 종료일까지 거래일마다 채권지수를 조회하여 하나의 long 형식 DataFrame으로
+/// 합칩니다. 달력상 휴장일은 요청하지 않으며, 조회 결과가 비어 있는 날은 건너뜁니다.
+#[must_use = "Builder does nothing unless you call .fetch()"]
+pub struct BondIndexSeriesBuilder {
+    client: Client,
+    group: BondIndexGroup,
+    variant: BondIndexVariant,
+    start_date: Option<String>,
+    end_date: Option<String>,
+    calendar: TradingCalendar,
+    concurrency: usize,
+}
+
+impl BondIndexSeriesBuilder {
+    fn new(client: Client, group: BondIndexGroup, variant: BondIndexVariant) -> Self {
+        Self {
+            client,
+            group,
+            variant,
+            start_date: None,
+            end_date: None,
+            calendar: TradingCalendar::new(),
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// 조회 시작일 설정 (YYYYMMDD).
+    pub fn start_date(mut self, date: impl Into<String>) -> Self {
+        self.start_date = Some(date.into());
+        self
+    }
+
+    /// 조회 종료일 설정 (YYYYMMDD). 생략하면 가장 최신 거래일까지 조회합니다.
+    pub fn end_date(mut self, date: impl Into<String>) -> Self {
+        self.end_date = Some(date.into());
+        self
+    }
+
+    /// 요청할 거래일을 정할 달력 (기본값: [`TradingCalendar::new`], 내장 KRX 휴장일표 포함)
+    pub fn calendar(mut self, calendar: TradingCalendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// 동시에 보낼 최대 요청 수 (기본값: [`DEFAULT_CONCURRENCY`], 최소 1)
+    pub fn concurrency(mut self, limit: usize) -> Self {
+        self.concurrency = limit.max(1);
+        self
+    }
+
+    /// 기간 안의 거래일마다 채권지수를 조회해 날짜 순 시계열로 합칩니다.
+    ///
+    /// 달력상 휴장일은 요청하지 않으며, 조회 결과가 비어 있는 날은 건너뜁니다.
+    ///
+    /// # Errors
+    /// - 날짜 형식이 잘못되었거나 시작일이 종료일보다 늦으면 `Error::InvalidInput` 반환.
+    /// - 어느 하루라도 조회에 실패하면 그 오류를 반환합니다.
+    pub async fn fetch(self) -> Result<DataFrame> {
+        let start_date = validate_base_date(self.start_date)?;
+        let end_date = validate_base_date(self.end_date.or_else(|| Some(latest_workday_string())))?;
+
+        let client = self.client;
+        let mut days = RangeStreamBuilder::new(start_date, end_date, move |date| {
+            BondIndexDailyBuilder::new(client.clone())
+                .date(date)
+                .fetch()
+        })
+        .calendar(self.calendar)
+        .concurrency(self.concurrency)
+        .prefetch(0)
+        .stream()?;
+
+        let mut combined = DataFrame::empty();
+        while let Some(day) = days.next().await {
+            let (_, daily) = day?;
+            let series = bond_index_series(&daily, &self.group, self.variant)?;
+            if series.is_empty() {
+                continue;
+            }
+            if combined.is_empty() {
+                combined = series;
+            } else {
+                combined.vstack_mut(&series)?;
+            }
+        }
+
+        Ok(combined)
+    }
 }
 
 /// 파생상품지수 시세정보 빌더
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct DerivativeIndexDailyBuilder<'a> {
-    client: &'a Client,
+pub struct DerivativeIndexDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> DerivativeIndexDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl DerivativeIndexDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -280,14 +387,9 @@ impl<'a> DerivativeIndexDailyBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
         let response = self
             .client
-            .get::<ApiResponse<DerivativeIndexDailyRecord>>(
-                "/idx/drvprod_dd_trd",
-                &[("basDd", &base_date)],
-            )
+            .get_records::<DerivativeIndexDaily>(self.base_date)
             .await?;
 
         parse_derivative_index_daily(response)
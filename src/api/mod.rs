@@ -9,6 +9,8 @@ pub mod bond;
 pub mod common;
 /// 파생상품(Derivative) 관련 API
 pub mod derivative;
+/// 엔드포인트 공통 트레이트와 엔드포인트별 표식 타입
+pub mod endpoint;
 /// ESG 관련 API
 pub mod esg;
 /// ETP (ETF, ETN, ELW) 관련 API
@@ -1,7 +1,17 @@
 use crate::{
-    api::common::{latest_workday_string, validate_base_date},
+    analysis::{
+        listing::{ListingChanges, ListingHistoryBuilder, fetch_listing_changes},
+        valuation::{ValuationParams, parse_stock_right_valuation, parse_stock_warrant_valuation},
+    },
+    api::{
+        common::latest_workday_string,
+        endpoint::{
+            KonexBaseInfo, KonexDaily, KosdaqBaseInfo, KosdaqDaily, StockBaseInfo, StockDaily,
+            StockRightDaily, StockWarrantDaily,
+        },
+    },
     client::Client,
-    data::{ApiResponse, stock::*},
+    data::stock::*,
     error::Result,
 };
 use polars::prelude::DataFrame;
@@ -27,69 +37,92 @@ use polars::prelude::DataFrame;
 /// # Ok(())
 /// # }
 /// ```
-pub struct StockApi<'a> {
-    pub(crate) client: &'a Client,
+pub struct StockApi {
+    pub(crate) client: Client,
 }
 
-impl<'a> StockApi<'a> {
-    pub(crate) fn new(client: &'a Client) -> Self {
+impl StockApi {
+    pub(crate) fn new(client: Client) -> Self {
         Self { client }
     }
 
     /// 유가증권(KOSPI) 전종목 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201020101)
-    pub fn stock_daily(&self) -> StockDailyBuilder<'a> {
-        StockDailyBuilder::new(self.client)
+    pub fn stock_daily(&self) -> StockDailyBuilder {
+        StockDailyBuilder::new(self.client.clone())
     }
 
     /// 코스닥(KOSDAQ) 전종목 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201020201)
-    pub fn kosdaq_daily(&self) -> KosdaqDailyBuilder<'a> {
-        KosdaqDailyBuilder::new(self.client)
+    pub fn kosdaq_daily(&self) -> KosdaqDailyBuilder {
+        KosdaqDailyBuilder::new(self.client.clone())
     }
 
     /// 코넥스(KONEX) 전종목 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201020301)
-    pub fn konex_daily(&self) -> KonexDailyBuilder<'a> {
-        KonexDailyBuilder::new(self.client)
+    pub fn konex_daily(&self) -> KonexDailyBuilder {
+        KonexDailyBuilder::new(self.client.clone())
     }
 
     /// 신주인수권증권 전종목 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201020401)
-    pub fn stock_warrant_daily(&self) -> StockWarrantDailyBuilder<'a> {
-        StockWarrantDailyBuilder::new(self.client)
+    pub fn stock_warrant_daily(&self) -> StockWarrantDailyBuilder {
+        StockWarrantDailyBuilder::new(self.client.clone())
     }
 
     /// 신주인수권증서 전종목 일별 시세.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201020501)
-    pub fn stock_right_daily(&self) -> StockRightDailyBuilder<'a> {
-        StockRightDailyBuilder::new(self.client)
+    pub fn stock_right_daily(&self) -> StockRightDailyBuilder {
+        StockRightDailyBuilder::new(self.client.clone())
     }
 
     /// 유가증권(KOSPI) 종목 기본정보.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201020601)
-    pub fn stock_base_info(&self) -> StockBaseInfoBuilder<'a> {
-        StockBaseInfoBuilder::new(self.client)
+    pub fn stock_base_info(&self) -> StockBaseInfoBuilder {
+        StockBaseInfoBuilder::new(self.client.clone())
     }
 
     /// 코스닥(KOSDAQ) 종목 기본정보.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201020701)
-    pub fn kosdaq_base_info(&self) -> KosdaqBaseInfoBuilder<'a> {
-        KosdaqBaseInfoBuilder::new(self.client)
+    pub fn kosdaq_base_info(&self) -> KosdaqBaseInfoBuilder {
+        KosdaqBaseInfoBuilder::new(self.client.clone())
     }
 
     /// 코넥스(KONEX) 종목 기본정보.
     ///
     /// [API 명세](https://data.krx.co.kr/contents/MDC/MDI/mdiLoader/index.cmd?menuId=MDC0201020801)
-    pub fn konex_base_info(&self) -> KonexBaseInfoBuilder<'a> {
-        KonexBaseInfoBuilder::new(self.client)
+    pub fn konex_base_info(&self) -> KonexBaseInfoBuilder {
+        KonexBaseInfoBuilder::new(self.client.clone())
+    }
+
+    /// 두 기준일자(YYYYMMDD)의 종목기본정보를 비교한 상장 변동.
+    ///
+    /// 유가증권·코스닥·코넥스 기본정보를 모두 조회하여 비교합니다.
+    /// 자세한 내용은 [`crate::analysis::listing`] 모듈을 참고하세요.
+    ///
+    /// # Errors
+    /// - 어느 기준일자든 한 시장의 기본정보가 비어 있으면(휴장일, 공개 전 등)
+    ///   `Error::InvalidInput` 반환.
+    pub async fn listing_changes(&self, from: &str, to: &str) -> Result<ListingChanges> {
+        fetch_listing_changes(&self.client, from, to).await
+    }
+
+    /// 기간(YYYYMMDD) 안의 거래일별 상장 변동 이력.
+    ///
+    /// 자세한 내용은 [`crate::analysis::listing`] 모듈을 참고하세요.
+    pub fn listing_history(
+        &self,
+        start_date: impl Into<String>,
+        end_date: impl Into<String>,
+    ) -> ListingHistoryBuilder {
+        ListingHistoryBuilder::new(self.client.clone(), start_date.into(), end_date.into())
     }
 }
 
@@ -112,13 +145,13 @@ impl<'a> StockApi<'a> {
 /// # }
 /// ```
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct StockDailyBuilder<'a> {
-    client: &'a Client,
+pub struct StockDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> StockDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl StockDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -154,11 +187,9 @@ impl<'a> StockDailyBuilder<'a> {
     /// - `Error::Network`: 네트워크 요청 실패 시
     /// - `Error::Parsing`: 응답 데이터 파싱 실패 시
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
         let response = self
             .client
-            .get::<ApiResponse<StockDailyRecord>>("/sto/stk_bydd_trd", &[("basDd", &base_date)])
+            .get_records::<StockDaily>(self.base_date)
             .await?;
 
         parse_stock_daily(response)
@@ -184,13 +215,13 @@ impl<'a> StockDailyBuilder<'a> {
 /// # }
 /// ```
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct KosdaqDailyBuilder<'a> {
-    client: &'a Client,
+pub struct KosdaqDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> KosdaqDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl KosdaqDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -212,11 +243,9 @@ impl<'a> KosdaqDailyBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
         let response = self
             .client
-            .get::<ApiResponse<KosdaqDailyRecord>>("/sto/ksq_bydd_trd", &[("basDd", &base_date)])
+            .get_records::<KosdaqDaily>(self.base_date)
             .await?;
 
         parse_kosdaq_daily(response)
@@ -242,13 +271,13 @@ impl<'a> KosdaqDailyBuilder<'a> {
 /// # }
 /// ```
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct KonexDailyBuilder<'a> {
-    client: &'a Client,
+pub struct KonexDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> KonexDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl KonexDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -270,11 +299,9 @@ impl<'a> KonexDailyBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
         let response = self
             .client
-            .get::<ApiResponse<KonexDailyRecord>>("/sto/knx_bydd_trd", &[("basDd", &base_date)])
+            .get_records::<KonexDaily>(self.base_date)
             .await?;
 
         parse_konex_daily(response)
@@ -300,13 +327,13 @@ impl<'a> KonexDailyBuilder<'a> {
 /// # }
 /// ```
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct StockWarrantDailyBuilder<'a> {
-    client: &'a Client,
+pub struct StockWarrantDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> StockWarrantDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl StockWarrantDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -328,18 +355,25 @@ impl<'a> StockWarrantDailyBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
         let response = self
             .client
-            .get::<ApiResponse<StockWarrantDailyRecord>>(
-                "/sto/sw_bydd_trd",
-                &[("basDd", &base_date)],
-            )
+            .get_records::<StockWarrantDaily>(self.base_date)
             .await?;
 
         parse_stock_warrant_daily(response)
     }
+
+    /// 일별 시세에 내재가치, 프리미엄, 잔존일수, 이론가 등 가치평가 컬럼을 더해 반환합니다.
+    ///
+    /// 자세한 계산식은 [`crate::analysis::valuation`] 모듈을 참고하세요.
+    pub async fn fetch_valuation(self, params: &ValuationParams) -> Result<DataFrame> {
+        let response = self
+            .client
+            .get_records::<StockWarrantDaily>(self.base_date)
+            .await?;
+
+        parse_stock_warrant_valuation(response, params)
+    }
 }
 
 /// 신주인수권증서 전종목 일별 시세를 조회하는 빌더입니다.
@@ -361,13 +395,13 @@ impl<'a> StockWarrantDailyBuilder<'a> {
 /// # }
 /// ```
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct StockRightDailyBuilder<'a> {
-    client: &'a Client,
+pub struct StockRightDailyBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> StockRightDailyBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl StockRightDailyBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -389,15 +423,25 @@ impl<'a> StockRightDailyBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
-
         let response = self
             .client
-            .get::<ApiResponse<StockRightDailyRecord>>("/sto/sr_bydd_trd", &[("basDd", &base_date)])
+            .get_records::<StockRightDaily>(self.base_date)
             .await?;
 
         parse_stock_right_daily(response)
     }
+
+    /// 일별 시세에 내재가치, 프리미엄, 잔존일수, 이론가 등 가치평가 컬럼을 더해 반환합니다.
+    ///
+    /// 자세한 계산식은 [`crate::analysis::valuation`] 모듈을 참고하세요.
+    pub async fn fetch_valuation(self, params: &ValuationParams) -> Result<DataFrame> {
+        let response = self
+            .client
+            .get_records::<StockRightDaily>(self.base_date)
+            .await?;
+
+        parse_stock_right_valuation(response, params)
+    }
 }
 
 /// 유가증권(KOSPI) 종목 기본정보를 조회하는 빌더입니다.
@@ -419,13 +463,13 @@ impl<'a> StockRightDailyBuilder<'a> {
 /// # }
 /// ```
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct StockBaseInfoBuilder<'a> {
-    client: &'a Client,
+pub struct StockBaseInfoBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> StockBaseInfoBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl StockBaseInfoBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -447,13 +491,9 @@ impl<'a> StockBaseInfoBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
         let response = self
             .client
-            .get::<ApiResponse<crate::data::stock::StockBaseInfoRecord>>(
-                "/sto/stk_isu_base_info",
-                &[("basDd", &base_date)],
-            )
+            .get_records::<StockBaseInfo>(self.base_date)
             .await?;
 
         parse_stock_base_info(response)
@@ -479,13 +519,13 @@ impl<'a> StockBaseInfoBuilder<'a> {
 /// # }
 /// ```
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct KosdaqBaseInfoBuilder<'a> {
-    client: &'a Client,
+pub struct KosdaqBaseInfoBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> KosdaqBaseInfoBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl KosdaqBaseInfoBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -507,13 +547,9 @@ impl<'a> KosdaqBaseInfoBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
         let response = self
             .client
-            .get::<ApiResponse<crate::data::stock::StockBaseInfoRecord>>(
-                "/sto/ksq_isu_base_info",
-                &[("basDd", &base_date)],
-            )
+            .get_records::<KosdaqBaseInfo>(self.base_date)
             .await?;
 
         parse_stock_base_info(response)
@@ -539,13 +575,13 @@ impl<'a> KosdaqBaseInfoBuilder<'a> {
 /// # }
 /// ```
 #[must_use = "Builder does nothing unless you call .fetch()"]
-pub struct KonexBaseInfoBuilder<'a> {
-    client: &'a Client,
+pub struct KonexBaseInfoBuilder {
+    client: Client,
     base_date: Option<String>,
 }
 
-impl<'a> KonexBaseInfoBuilder<'a> {
-    fn new(client: &'a Client) -> Self {
+impl KonexBaseInfoBuilder {
+    fn new(client: Client) -> Self {
         Self {
             client,
             base_date: None,
@@ -567,13 +603,9 @@ impl<'a> KonexBaseInfoBuilder<'a> {
     }
 
     pub async fn fetch(self) -> Result<DataFrame> {
-        let base_date = validate_base_date(self.base_date)?;
         let response = self
             .client
-            .get::<ApiResponse<crate::data::stock::StockBaseInfoRecord>>(
-                "/sto/knx_isu_base_info",
-                &[("basDd", &base_date)],
-            )
+            .get_records::<KonexBaseInfo>(self.base_date)
             .await?;
 
         parse_stock_base_info(response)
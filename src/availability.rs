@@ -0,0 +1,224 @@
+//! 데이터 공개 여부 확인과 공개 대기.
+//!
+//! KRX는 전 거래일 데이터를 다음 날 아침에 공개하며, 공개 전에는 빈 `OutBlock_1`을
+//! 돌려줍니다. `parse_*` 함수는 이를 `DataFrame::empty()`로 변환하므로 휴장일과 구분되지
+//! 않습니다. [`AvailabilityChecker::fetch_outcome`]은 [`TradingCalendar`]와 공개 시각을
+//! 함께 보고 빈 응답을 [`FetchOutcome`]의 서로 다른 경우로 나눕니다.
+//!
+//! - [`FetchOutcome::NonTradingDay`]: 달력상 휴장일
+//! - [`FetchOutcome::NotPublished`]: 거래일이지만 공개 시각(+유예 시간) 전이라 아직 비어 있음
+//! - [`FetchOutcome::Empty`]: 공개 시각이 지났는데도 비어 있음
+//!
+//! # 예시
+//! ```rust,no_run
+//! # use krx_rs::Client;
+//! use krx_rs::{api::endpoint::StockDaily, availability::FetchOutcome};
+//! use std::time::Duration;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), krx_rs::error::Error> {
+//! let client = Client::new("YOUR_AUTH_KEY");
+//!
+//! if !client.is_available::<StockDaily>("20240105").await? {
+//!     match client
+//!         .wait_until_available::<StockDaily>("20240105", Duration::from_secs(3600))
+//!         .await?
+//!     {
+//!         FetchOutcome::Data(df) => println!("{}", df),
+//!         other => println!("still unavailable: {:?}", other),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    api::endpoint::{Endpoint, EndpointInfo},
+    calendar::{DEFAULT_PUBLISH_TIME, TradingCalendar, parse_date, publication_time, seoul},
+    client::Client,
+    error::Result,
+};
+use chrono::{DateTime, FixedOffset, NaiveTime, Utc};
+use polars::prelude::DataFrame;
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+/// 기준일자 하나를 조회한 결과
+#[derive(Debug, Clone, PartialEq)]
+pub enum FetchOutcome {
+    /// 데이터가 있음
+    Data(DataFrame),
+    /// 거래일이지만 아직 공개되지 않음
+    NotPublished,
+    /// 달력상 휴장일
+    NonTradingDay,
+    /// 공개 시각이 지났지만 데이터가 없음
+    Empty,
+}
+
+impl FetchOutcome {
+    /// 데이터가 있는지 여부
+    pub fn is_available(&self) -> bool {
+        matches!(self, FetchOutcome::Data(_))
+    }
+
+    /// 데이터가 있으면 DataFrame을 반환합니다.
+    pub fn into_data(self) -> Option<DataFrame> {
+        match self {
+            FetchOutcome::Data(df) => Some(df),
+            _ => None,
+        }
+    }
+}
+
+/// 공개 여부 확인 설정
+///
+/// [`Client::availability`](crate::Client::availability)로 만듭니다.
+#[derive(Clone)]
+pub struct AvailabilityChecker {
+    client: Client,
+    calendar: TradingCalendar,
+    publish_time: NaiveTime,
+    publish_grace: Duration,
+    poll_interval: Duration,
+    max_poll_interval: Duration,
+}
+
+impl AvailabilityChecker {
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            calendar: TradingCalendar::new(),
+            publish_time: DEFAULT_PUBLISH_TIME,
+            publish_grace: Duration::from_secs(2 * 60 * 60),
+            poll_interval: Duration::from_secs(30),
+            max_poll_interval: Duration::from_secs(10 * 60),
+        }
+    }
+
+    /// 휴장일 판단에 사용할 달력 (기본값: [`TradingCalendar::new`], 내장 KRX 휴장일표 포함)
+    pub fn calendar(mut self, calendar: TradingCalendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// 다음 날 데이터가 공개되기 시작하는 한국 시각 (기본값: 08:00)
+    pub fn publish_time(mut self, time: NaiveTime) -> Self {
+        self.publish_time = time;
+        self
+    }
+
+    /// 공개 시각 이후에도 빈 응답을 "아직 공개 전"으로 볼 시간 (기본값: 2시간)
+    pub fn publish_grace(mut self, grace: Duration) -> Self {
+        self.publish_grace = grace;
+        self
+    }
+
+    /// 대기 중 첫 재조회 간격과 최대 간격 (기본값: 30초부터 두 배씩, 최대 10분)
+    pub fn poll_interval(mut self, initial: Duration, max: Duration) -> Self {
+        self.poll_interval = initial;
+        self.max_poll_interval = max.max(initial);
+        self
+    }
+
+    /// 엔드포인트를 조회하고 결과를 [`FetchOutcome`]으로 분류합니다.
+    pub async fn fetch_outcome<E: Endpoint + 'static>(&self, date: &str) -> Result<FetchOutcome> {
+        self.fetch_outcome_of(&EndpointInfo::of::<E>(), date).await
+    }
+
+    /// 레지스트리의 엔드포인트를 조회하고 결과를 [`FetchOutcome`]으로 분류합니다.
+    pub async fn fetch_outcome_of(
+        &self,
+        endpoint: &EndpointInfo,
+        date: &str,
+    ) -> Result<FetchOutcome> {
+        self.fetch_outcome_at(endpoint, date, Utc::now().with_timezone(&seoul()))
+            .await
+    }
+
+    /// `now` 시점을 기준으로 빈 응답을 분류합니다.
+    pub(crate) async fn fetch_outcome_at(
+        &self,
+        endpoint: &EndpointInfo,
+        date: &str,
+        now: DateTime<FixedOffset>,
+    ) -> Result<FetchOutcome> {
+        let base_date = parse_date(date)?;
+        let df = endpoint.fetch(&self.client, date).await?;
+        if df.height() > 0 {
+            return Ok(FetchOutcome::Data(df));
+        }
+        if !self.calendar.is_trading_day(base_date) {
+            return Ok(FetchOutcome::NonTradingDay);
+        }
+
+        let published = publication_time(base_date, self.publish_time)?;
+        if now < published + self.publish_grace {
+            Ok(FetchOutcome::NotPublished)
+        } else {
+            Ok(FetchOutcome::Empty)
+        }
+    }
+
+    /// 기준일자의 데이터가 공개되어 있는지 확인합니다.
+    pub async fn is_available<E: Endpoint + 'static>(&self, date: &str) -> Result<bool> {
+        Ok(self.fetch_outcome::<E>(date).await?.is_available())
+    }
+
+    /// 데이터가 공개될 때까지 간격을 늘려 가며 다시 조회합니다.
+    ///
+    /// 데이터가 생기면 [`FetchOutcome::Data`]를, 휴장일이면 바로
+    /// [`FetchOutcome::NonTradingDay`]를 반환합니다. `timeout` 안에 데이터가 생기지 않으면
+    /// 마지막 조회 결과(`NotPublished` 또는 `Empty`)를 반환합니다.
+    pub async fn wait_until_available<E: Endpoint + 'static>(
+        &self,
+        date: &str,
+        timeout: Duration,
+    ) -> Result<FetchOutcome> {
+        self.wait_until_available_of(&EndpointInfo::of::<E>(), date, timeout)
+            .await
+    }
+
+    /// 레지스트리의 엔드포인트에 대해 [`AvailabilityChecker::wait_until_available`]을 수행합니다.
+    pub async fn wait_until_available_of(
+        &self,
+        endpoint: &EndpointInfo,
+        date: &str,
+        timeout: Duration,
+    ) -> Result<FetchOutcome> {
+        self.wait_until_available_at(endpoint, date, timeout, Utc::now().with_timezone(&seoul()))
+            .await
+    }
+
+    /// `now`에서 시작해 흐른 시간을 더한 시각을 기준으로 공개를 기다립니다.
+    pub(crate) async fn wait_until_available_at(
+        &self,
+        endpoint: &EndpointInfo,
+        date: &str,
+        timeout: Duration,
+        now: DateTime<FixedOffset>,
+    ) -> Result<FetchOutcome> {
+        let started = Instant::now();
+        let mut interval = self.poll_interval;
+        loop {
+            let elapsed = chrono::Duration::from_std(started.elapsed()).unwrap_or_default();
+            let outcome = self.fetch_outcome_at(endpoint, date, now + elapsed).await?;
+            let remaining = timeout.saturating_sub(started.elapsed());
+            if matches!(outcome, FetchOutcome::Data(_) | FetchOutcome::NonTradingDay)
+                || remaining.is_zero()
+            {
+                return Ok(outcome);
+            }
+
+            debug!(
+                endpoint = endpoint.path,
+                date = %date,
+                outcome = ?outcome,
+                retry_in_ms = interval.min(remaining).as_millis(),
+                "Data not available yet"
+            );
+            tokio::time::sleep(interval.min(remaining)).await;
+            interval = (interval * 2).min(self.max_poll_interval);
+        }
+    }
+}
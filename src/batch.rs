@@ -0,0 +1,231 @@
+//! 여러 조회를 동시에 실행하는 배치 실행기.
+//!
+//! [`Client::batch`](crate::Client::batch)로 만든 [`Batch`]에 빌더의 `fetch()` 또는
+//! 엔드포인트 이름과 기준일자를 담아 실행하면, 공유 클라이언트를 통해 동시 실행 수를
+//! 제한하며 요청을 보내고 키별 결과를 [`BatchResults`]로 돌려줍니다. 한 요청이
+//! 실패해도 나머지 요청은 계속 실행됩니다.
+//!
+//! 모든 요청은 같은 [`Client`]를 거치므로 인증키 풀의 키 교체, 요청 지표 기록 등
+//! 클라이언트 설정이 그대로 적용됩니다. 속도 제한(429) 응답은 해당 항목의
+//! `Error::RateLimit`로 기록되므로 [`BatchResults::failed_keys`]로 다시 실행할 수 있습니다.
+//!
+//! # 예시
+//! ```rust,no_run
+//! # use krx_rs::Client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), krx_rs::error::Error> {
+//! let client = Client::new("YOUR_AUTH_KEY");
+//!
+//! let results = client
+//!     .batch()
+//!     .concurrency(8)
+//!     .all_endpoints("20240105")
+//!     .add("kospi", client.stock().stock_daily().date("20240104").fetch())
+//!     .run()
+//!     .await;
+//!
+//! for (key, df) in results.successes() {
+//!     println!("{key}: {} rows", df.height());
+//! }
+//! for (key, error) in results.errors() {
+//!     eprintln!("{key}: {error}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    api::endpoint::{self, ALL},
+    client::Client,
+    error::{Error, Result},
+};
+use futures::{StreamExt, future::BoxFuture, stream};
+use polars::prelude::DataFrame;
+use std::{collections::BTreeMap, future::Future};
+use tracing::{debug, info};
+
+/// 기본 동시 실행 수
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// 동시에 실행할 조회 묶음
+#[must_use = "Batch does nothing unless you call .run()"]
+pub struct Batch {
+    client: Client,
+    concurrency: usize,
+    requests: Vec<(String, Result<BoxFuture<'static, Result<DataFrame>>>)>,
+}
+
+impl Batch {
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            concurrency: DEFAULT_CONCURRENCY,
+            requests: Vec::new(),
+        }
+    }
+
+    /// 엔드포인트 이름과 기준일자로 만든 결과 키 (예: `sto/stk_bydd_trd@20240105`)
+    pub fn endpoint_key(name: &str, date: &str) -> String {
+        format!("{name}@{date}")
+    }
+
+    /// 동시에 실행할 최대 요청 수 (기본값: [`DEFAULT_CONCURRENCY`], 최소 1)
+    pub fn concurrency(mut self, limit: usize) -> Self {
+        self.concurrency = limit.max(1);
+        self
+    }
+
+    /// 임의의 조회를 키와 함께 추가합니다.
+    ///
+    /// 빌더의 `fetch()` 결과를 그대로 넘기면 됩니다. 요청은 [`Batch::run`]에서
+    /// 실행되며, 같은 키를 여러 번 추가하면 마지막으로 끝난 요청의 결과가 남습니다.
+    pub fn add<F>(mut self, key: impl Into<String>, request: F) -> Self
+    where
+        F: Future<Output = Result<DataFrame>> + Send + 'static,
+    {
+        self.requests.push((key.into(), Ok(Box::pin(request))));
+        self
+    }
+
+    /// 엔드포인트 이름(`sto/stk_bydd_trd` 등)과 기준일자로 조회를 추가합니다.
+    ///
+    /// 결과 키는 [`Batch::endpoint_key`] 형식입니다. 등록되지 않은 이름이면
+    /// 해당 키의 결과가 `Error::InvalidInput`이 됩니다.
+    pub fn endpoint(mut self, name: &str, date: impl Into<String>) -> Self {
+        let date = date.into();
+        let key = Self::endpoint_key(name, &date);
+        let request = endpoint::find(name).map(|info| {
+            let client = self.client.clone();
+            Box::pin(async move { info.fetch(&client, date).await }) as BoxFuture<'static, _>
+        });
+        self.requests.push((key, request));
+        self
+    }
+
+    /// 여러 엔드포인트와 여러 기준일자의 모든 조합을 추가합니다.
+    pub fn endpoints<N, D>(mut self, names: N, dates: D) -> Self
+    where
+        N: IntoIterator,
+        N::Item: AsRef<str>,
+        D: IntoIterator,
+        D::Item: Into<String>,
+    {
+        let dates: Vec<String> = dates.into_iter().map(Into::into).collect();
+        for name in names {
+            for date in &dates {
+                self = self.endpoint(name.as_ref(), date.clone());
+            }
+        }
+        self
+    }
+
+    /// 지원하는 모든 엔드포인트를 한 기준일자로 조회하도록 추가합니다.
+    pub fn all_endpoints(self, date: impl Into<String>) -> Self {
+        self.endpoints(ALL.iter().map(|info| info.name()), [date.into()])
+    }
+
+    /// 추가된 요청 수
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// 추가된 요청이 없는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// 모든 요청을 동시 실행 수 제한 안에서 실행하고 키별 결과를 반환합니다.
+    pub async fn run(self) -> BatchResults {
+        info!(
+            requests = self.requests.len(),
+            concurrency = self.concurrency,
+            "Starting batch"
+        );
+
+        let mut results = BTreeMap::new();
+        let mut pending = Vec::with_capacity(self.requests.len());
+        for (key, request) in self.requests {
+            match request {
+                Ok(request) => pending.push(async move { (key, request.await) }),
+                Err(e) => {
+                    results.insert(key, Err(e));
+                }
+            }
+        }
+
+        let mut completed = stream::iter(pending).buffer_unordered(self.concurrency);
+        while let Some((key, result)) = completed.next().await {
+            debug!(key = %key, success = result.is_ok(), "Batch item completed");
+            results.insert(key, result);
+        }
+
+        let results = BatchResults { results };
+        info!(
+            succeeded = results.successes().count(),
+            failed = results.errors().count(),
+            "Batch completed"
+        );
+        results
+    }
+}
+
+/// [`Batch::run`]의 키별 결과
+#[derive(Debug, Default)]
+pub struct BatchResults {
+    results: BTreeMap<String, Result<DataFrame>>,
+}
+
+impl BatchResults {
+    /// 키의 결과
+    pub fn get(&self, key: &str) -> Option<&Result<DataFrame>> {
+        self.results.get(key)
+    }
+
+    /// 성공한 항목 (키 순)
+    pub fn successes(&self) -> impl Iterator<Item = (&str, &DataFrame)> {
+        self.results
+            .iter()
+            .filter_map(|(key, result)| result.as_ref().ok().map(|df| (key.as_str(), df)))
+    }
+
+    /// 실패한 항목 (키 순)
+    pub fn errors(&self) -> impl Iterator<Item = (&str, &Error)> {
+        self.results
+            .iter()
+            .filter_map(|(key, result)| result.as_ref().err().map(|e| (key.as_str(), e)))
+    }
+
+    /// 실패한 항목의 키
+    pub fn failed_keys(&self) -> Vec<&str> {
+        self.errors().map(|(key, _)| key).collect()
+    }
+
+    /// 모든 항목이 성공했는지 여부
+    pub fn all_succeeded(&self) -> bool {
+        self.results.values().all(Result::is_ok)
+    }
+
+    /// 결과 수
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// 결과가 없는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    /// 키별 결과 맵으로 변환합니다.
+    pub fn into_map(self) -> BTreeMap<String, Result<DataFrame>> {
+        self.results
+    }
+}
+
+impl IntoIterator for BatchResults {
+    type Item = (String, Result<DataFrame>);
+    type IntoIter = std::collections::btree_map::IntoIter<String, Result<DataFrame>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.into_iter()
+    }
+}
@@ -0,0 +1,285 @@
+//! KRX 거래일 달력.
+//!
+//! 주말, 양력 고정 휴장일(신정, 삼일절, 근로자의 날, 어린이날, 현충일, 광복절, 개천절,
+//! 한글날, 성탄절), 연말 휴장일(마지막 평일)과 함께 2010년부터
+//! [`HOLIDAY_TABLE_LAST_YEAR`]까지의 설·추석 연휴, 부처님오신날, 선거일, 대체·임시
+//! 공휴일을 내장 휴장일표로 제외합니다. 이후 연도나 새로 지정된 임시 휴장일은
+//! [`TradingCalendar::with_holidays`] 또는 [`TradingCalendar::load_holidays`]로 추가합니다.
+//!
+//! # 예시
+//! ```rust
+//! use krx_rs::calendar::TradingCalendar;
+//!
+//! let calendar = TradingCalendar::new();
+//! let days = calendar.trading_days("20240208", "20240213").unwrap();
+//! assert_eq!(days, vec!["20240208", "20240213"]);
+//!
+//! let calendar = calendar.with_holidays(["20270208"]).unwrap();
+//! assert!(calendar.trading_days("20270208", "20270208").unwrap().is_empty());
+//! ```
+
+use crate::{
+    api::common::weekdays_between,
+    error::{Error, Result},
+};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveTime, Weekday};
+use std::{collections::BTreeSet, path::Path};
+
+/// 매년 같은 날짜에 쉬는 KRX 휴장일 (월, 일, 시행 연도)
+const FIXED_HOLIDAYS: [(u32, u32, i32); 9] = [
+    (1, 1, 0),
+    (3, 1, 0),
+    (5, 1, 0),
+    (5, 5, 0),
+    (6, 6, 0),
+    (8, 15, 0),
+    (10, 3, 0),
+    // 한글날은 2013년에 공휴일로 재지정
+    (10, 9, 2013),
+    (12, 25, 0),
+];
+
+/// 내장 휴장일표가 다루는 마지막 연도
+pub const HOLIDAY_TABLE_LAST_YEAR: i32 = 2026;
+
+/// 해마다 달라지는 평일 휴장일 (연도, [(월, 일)])
+///
+/// 설·추석 연휴, 부처님오신날, 선거일, 대체공휴일, 임시공휴일을 포함합니다.
+#[rustfmt::skip]
+const KRX_HOLIDAYS: [(i32, &[(u32, u32)]); 17] = [
+    (2010, &[(2, 15), (5, 21), (6, 2), (9, 21), (9, 22), (9, 23)]),
+    (2011, &[(2, 2), (2, 3), (2, 4), (5, 10), (9, 12), (9, 13)]),
+    (2012, &[(1, 23), (1, 24), (4, 11), (5, 28), (10, 1), (12, 19)]),
+    (2013, &[(2, 11), (5, 17), (9, 18), (9, 19), (9, 20)]),
+    (2014, &[(1, 30), (1, 31), (5, 6), (6, 4), (9, 8), (9, 9), (9, 10)]),
+    (2015, &[(2, 18), (2, 19), (2, 20), (5, 25), (8, 14), (9, 28), (9, 29)]),
+    (2016, &[(2, 8), (2, 9), (2, 10), (4, 13), (5, 6), (9, 14), (9, 15), (9, 16)]),
+    (2017, &[(1, 27), (1, 30), (5, 3), (5, 9), (10, 2), (10, 4), (10, 5), (10, 6)]),
+    (2018, &[(2, 15), (2, 16), (5, 7), (5, 22), (6, 13), (9, 24), (9, 25), (9, 26)]),
+    (2019, &[(2, 4), (2, 5), (2, 6), (5, 6), (9, 12), (9, 13)]),
+    (2020, &[(1, 24), (1, 27), (4, 15), (4, 30), (8, 17), (9, 30), (10, 1), (10, 2)]),
+    (2021, &[(2, 11), (2, 12), (5, 19), (8, 16), (9, 20), (9, 21), (9, 22), (10, 4), (10, 11)]),
+    (2022, &[(1, 31), (2, 1), (2, 2), (3, 9), (6, 1), (9, 9), (9, 12), (10, 10)]),
+    (2023, &[(1, 23), (1, 24), (5, 29), (9, 28), (9, 29), (10, 2)]),
+    (2024, &[(2, 9), (2, 12), (4, 10), (5, 6), (5, 15), (9, 16), (9, 17), (9, 18), (10, 1)]),
+    (2025, &[(1, 27), (1, 28), (1, 29), (1, 30), (3, 3), (5, 6), (6, 3), (10, 6), (10, 7), (10, 8)]),
+    (2026, &[(2, 16), (2, 17), (2, 18), (3, 2), (5, 25), (6, 3), (8, 17), (9, 24), (9, 25), (10, 5)]),
+];
+
+/// KRX 거래일 달력
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TradingCalendar {
+    holidays: BTreeSet<NaiveDate>,
+}
+
+impl Default for TradingCalendar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TradingCalendar {
+    /// 주말, 고정 휴장일, 내장 휴장일표를 제외하는 달력을 만듭니다.
+    pub fn new() -> Self {
+        let holidays = KRX_HOLIDAYS
+            .iter()
+            .flat_map(|(year, days)| {
+                days.iter()
+                    .filter_map(|&(month, day)| NaiveDate::from_ymd_opt(*year, month, day))
+            })
+            .collect();
+        Self { holidays }
+    }
+
+    /// 추가 휴장일(YYYYMMDD)을 등록합니다.
+    ///
+    /// # Errors
+    /// - 날짜 형식이 유효하지 않으면 `Error::InvalidInput` 반환.
+    pub fn with_holidays<I, S>(mut self, dates: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for date in dates {
+            self.holidays.insert(parse_date(date.as_ref())?);
+        }
+        Ok(self)
+    }
+
+    /// 한 줄에 하나씩 YYYYMMDD 휴장일이 적힌 파일을 읽어 등록합니다.
+    ///
+    /// 빈 줄과 `#`으로 시작하는 주석 줄은 무시하며, 날짜 뒤의 내용(휴장 사유 등)도
+    /// 무시합니다.
+    ///
+    /// # Errors
+    /// - 파일을 읽을 수 없으면 `Error::Io`, 날짜 형식이 유효하지 않으면
+    ///   `Error::InvalidInput` 반환.
+    pub fn load_holidays(self, path: impl AsRef<Path>) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        self.with_holidays(
+            contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .filter_map(|line| line.split_whitespace().next()),
+        )
+    }
+
+    /// 해당 날짜가 거래일인지 확인합니다.
+    pub fn is_trading_day(&self, date: NaiveDate) -> bool {
+        !is_weekend(date)
+            && !FIXED_HOLIDAYS.iter().any(|&(month, day, since)| {
+                date.year() >= since && date.month() == month && date.day() == day
+            })
+            && !is_year_end_closing(date)
+            && !self.holidays.contains(&date)
+    }
+
+    /// 기간(양 끝 포함) 안의 거래일을 YYYYMMDD 문자열로 반환합니다.
+    ///
+    /// # Errors
+    /// - 날짜 형식이 유효하지 않거나 시작일이 종료일보다 늦으면 `Error::InvalidInput` 반환.
+    pub fn trading_days(&self, from: &str, to: &str) -> Result<Vec<String>> {
+        let mut days = weekdays_between(from, to)?;
+        days.retain(|date| parse_date(date).is_ok_and(|date| self.is_trading_day(date)));
+        Ok(days)
+    }
+}
+
+/// 기본 데이터 공개 시각 (한국 시간 08:00)
+///
+/// KRX는 거래일의 데이터를 다음 날 아침에 공개합니다.
+pub const DEFAULT_PUBLISH_TIME: NaiveTime = match NaiveTime::from_hms_opt(8, 0, 0) {
+    Some(time) => time,
+    None => unreachable!(),
+};
+
+/// 한국 표준시 (UTC+9, 일광 절약 시간 없음)
+pub fn seoul() -> FixedOffset {
+    FixedOffset::east_opt(9 * 3600).expect("UTC+9 is a valid offset")
+}
+
+/// 기준일자 데이터의 공개 시각 (다음 날 `publish_time`, 한국 시간)
+///
+/// # Errors
+/// - 날짜가 표현 가능한 범위를 벗어나면 `Error::InvalidInput` 반환.
+pub fn publication_time(date: NaiveDate, publish_time: NaiveTime) -> Result<DateTime<FixedOffset>> {
+    date.succ_opt()
+        .map(|next| next.and_time(publish_time))
+        .and_then(|local| local.and_local_timezone(seoul()).single())
+        .ok_or_else(|| Error::InvalidInput("date out of range".to_string()))
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// 연말 휴장일: 그해의 마지막 평일 (12월 31일이 주말이면 직전 평일)
+fn is_year_end_closing(date: NaiveDate) -> bool {
+    date.month() == 12
+        && date.day() >= 29
+        && (date.day() + 1..=31)
+            .filter_map(|day| NaiveDate::from_ymd_opt(date.year(), 12, day))
+            .all(is_weekend)
+}
+
+pub(crate) fn parse_date(date: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(date, "%Y%m%d")
+        .map_err(|_| Error::InvalidInput("date must be in YYYYMMDD format".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_holidays_and_weekends() {
+        let calendar = TradingCalendar::new();
+        let at = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert!(calendar.is_trading_day(at(2024, 1, 2)));
+        assert!(!calendar.is_trading_day(at(2024, 1, 1)));
+        assert!(!calendar.is_trading_day(at(2024, 1, 6)));
+        assert!(!calendar.is_trading_day(at(2024, 12, 31)));
+        assert!(!calendar.is_trading_day(at(2024, 5, 1)));
+        // 한글날은 2013년부터 휴장
+        assert!(calendar.is_trading_day(at(2012, 10, 9)));
+        assert!(!calendar.is_trading_day(at(2013, 10, 9)));
+    }
+
+    #[test]
+    fn test_year_end_closing_moves_to_last_weekday() {
+        let calendar = TradingCalendar::new();
+        let at = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // 2023-12-31은 일요일이므로 12-29(금) 휴장
+        assert!(!calendar.is_trading_day(at(2023, 12, 29)));
+        assert!(calendar.is_trading_day(at(2023, 12, 28)));
+        // 2022-12-31은 토요일이므로 12-30(금) 휴장
+        assert!(!calendar.is_trading_day(at(2022, 12, 30)));
+        assert!(calendar.is_trading_day(at(2024, 12, 30)));
+    }
+
+    #[test]
+    fn test_bundled_holidays() {
+        let calendar = TradingCalendar::new();
+
+        // 2024 설 연휴와 대체공휴일, 총선
+        assert_eq!(
+            calendar.trading_days("20240208", "20240213").unwrap(),
+            vec!["20240208", "20240213"]
+        );
+        assert!(
+            calendar
+                .trading_days("20240410", "20240410")
+                .unwrap()
+                .is_empty()
+        );
+        // 2025 임시공휴일과 설 연휴
+        assert_eq!(
+            calendar.trading_days("20250124", "20250131").unwrap(),
+            vec!["20250124", "20250131"]
+        );
+        // 2020 추석 연휴
+        assert_eq!(
+            calendar.trading_days("20200929", "20201005").unwrap(),
+            vec!["20200929", "20201005"]
+        );
+    }
+
+    #[test]
+    fn test_load_holidays() {
+        let path = std::env::temp_dir().join(format!("krx-holidays-{}.txt", std::process::id()));
+        std::fs::write(&path, "# 2027 설 연휴\n20270208 설날\n\n20270209\n").unwrap();
+
+        let calendar = TradingCalendar::new().load_holidays(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            calendar.trading_days("20270205", "20270210").unwrap(),
+            vec!["20270205", "20270210"]
+        );
+        assert!(
+            TradingCalendar::new()
+                .load_holidays("/nonexistent/krx-holidays.txt")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_trading_days_with_holidays() {
+        let calendar = TradingCalendar::new()
+            .with_holidays(["20270208", "20270209"])
+            .unwrap();
+
+        assert_eq!(
+            calendar.trading_days("20270205", "20270210").unwrap(),
+            vec!["20270205", "20270210"]
+        );
+        assert!(
+            TradingCalendar::new()
+                .with_holidays(["2024-02-09"])
+                .is_err()
+        );
+    }
+}
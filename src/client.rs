@@ -1,24 +1,43 @@
 use crate::{
-    api,
+    api::{self, common::validate_base_date, endpoint::Endpoint},
+    availability::{AvailabilityChecker, FetchOutcome},
+    batch::Batch,
+    credentials::{CredentialProvider, KeyPool, StaticCredentials},
+    data::{ApiResponse, RecordCount},
     error::{Error, Result},
-    logging::LoggingConfig,
+    logging::{LoggingConfig, redact},
+    metrics::{ClientStats, Metrics, NETWORK_ERROR_STATUS},
+    stream::RangeStreamBuilder,
 };
+use polars::prelude::DataFrame;
 use reqwest::{
     Client as HttpClient,
     header::{HeaderMap, HeaderValue},
 };
 use serde::de::DeserializeOwned;
-use std::time::Duration;
-use tracing::{debug, error, info, instrument, warn};
+use std::{sync::Arc, time::Duration};
+use tracing::{Span, debug, error, field, info, instrument, warn};
 
 const BASE_URL: &str = "http://data-dbg.krx.co.kr/svc/apis";
+const HTTPS_BASE_URL: &str = "https://data-dbg.krx.co.kr/svc/apis";
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
 
 /// KRX API 클라이언트
+///
+/// 내부 상태를 `Arc`로 공유하므로 복제 비용이 작습니다. 복제한 클라이언트는
+/// 같은 연결 풀, 인증키 풀과 요청 지표를 함께 사용하며, 스레드와 태스크 간에
+/// 자유롭게 옮길 수 있습니다.
+#[derive(Clone)]
 pub struct Client {
+    inner: Arc<ClientInner>,
+}
+
+/// 복제된 [`Client`]들이 공유하는 상태
+struct ClientInner {
     http_client: HttpClient,
-    auth_key: String,
+    credentials: KeyPool,
     base_url: String,
+    metrics: Metrics,
 }
 
 impl Client {
@@ -58,12 +77,63 @@ impl Client {
     }
 
     /// 내부 HTTP GET 요청 처리
-    #[instrument(skip(self, params), fields(endpoint = %endpoint))]
+    ///
+    /// span 속성(`bas_dd`, `status`, `rows`, `retry_attempt`)은
+    /// `otel` feature의 OpenTelemetry 레이어를 통해 트레이스로 내보내집니다.
+    #[instrument(
+        skip(self, params),
+        fields(
+            otel.kind = "client",
+            endpoint = %endpoint,
+            bas_dd = field::Empty,
+            status = field::Empty,
+            rows = field::Empty,
+            retry_attempt = 0,
+        )
+    )]
     pub(crate) async fn get<T>(&self, endpoint: &str, params: &[(&str, &str)]) -> Result<T>
     where
-        T: DeserializeOwned,
+        T: DeserializeOwned + RecordCount,
     {
-        let url = format!("{}{}", self.base_url, endpoint);
+        let span = Span::current();
+        if let Some((_, date)) = params.iter().find(|(key, _)| *key == "basDd") {
+            span.record("bas_dd", *date);
+        }
+
+        let mut attempt = 0;
+        loop {
+            let auth_key = self.inner.credentials.select()?;
+            match self.request(endpoint, params, &auth_key, &span).await {
+                // 인증키 풀에서는 한도에 도달한 키를 제외하고 다음 키로 재시도
+                Err(Error::RateLimit { .. })
+                    if self.inner.credentials.mark_exhausted(&auth_key) =>
+                {
+                    attempt += 1;
+                    span.record("retry_attempt", attempt);
+                    self.inner.metrics.record_retry(endpoint);
+                    warn!(
+                        endpoint = %endpoint,
+                        retry_attempt = attempt,
+                        "Auth key reached its quota, retrying with next key"
+                    );
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// 인증키 하나로 요청을 한 번 보냅니다.
+    async fn request<T>(
+        &self,
+        endpoint: &str,
+        params: &[(&str, &str)],
+        auth_key: &str,
+        span: &Span,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned + RecordCount,
+    {
+        let url = format!("{}{}", self.inner.base_url, endpoint);
         let start_time = std::time::Instant::now();
 
         // 요청 시작 로깅
@@ -80,13 +150,20 @@ impl Client {
         );
 
         let response = self
+            .inner
             .http_client
             .get(&url)
-            .header("AUTH_KEY", &self.auth_key)
+            .header("AUTH_KEY", auth_header(auth_key)?)
             .query(params)
             .send()
             .await
             .map_err(|e| {
+                span.record("status", NETWORK_ERROR_STATUS);
+                self.inner.metrics.record_response(
+                    endpoint,
+                    NETWORK_ERROR_STATUS,
+                    start_time.elapsed(),
+                );
                 error!(
                     endpoint = %endpoint,
                     error = %e,
@@ -98,9 +175,15 @@ impl Client {
 
         let status_code = response.status().as_u16();
         let duration = start_time.elapsed();
+        // OpenTelemetry 속성은 부호 없는 정수를 지원하지 않으므로 i64로 기록합니다.
+        span.record("status", i64::from(status_code));
+        self.inner
+            .metrics
+            .record_response(endpoint, &status_code.to_string(), duration);
 
         if response.status().is_success() {
             let body = response.text().await?;
+            self.inner.metrics.record_body(endpoint, body.len());
 
             debug!(
                 endpoint = %endpoint,
@@ -111,8 +194,11 @@ impl Client {
             );
 
             // 응답 파싱
-            match serde_json::from_str(&body) {
+            match serde_json::from_str::<T>(&body) {
                 Ok(parsed) => {
+                    let rows = parsed.record_count();
+                    span.record("rows", rows as i64);
+                    self.inner.metrics.record_rows(endpoint, Some(rows));
                     info!(
                         endpoint = %endpoint,
                         status_code = status_code,
@@ -122,6 +208,7 @@ impl Client {
                     Ok(parsed)
                 }
                 Err(e) => {
+                    self.inner.metrics.record_rows(endpoint, None);
                     error!(
                         endpoint = %endpoint,
                         error = %e,
@@ -131,7 +218,7 @@ impl Client {
                     Err(Error::Parsing {
                         details: format!("Failed to deserialize response from {endpoint}"),
                         source: e,
-                        response_body: body,
+                        response_body: redact::redact_owned(body),
                     })
                 }
             }
@@ -155,7 +242,7 @@ impl Client {
                 return Err(Error::RateLimit { retry_after });
             }
 
-            let message = response.text().await.unwrap_or_default();
+            let message = redact::redact_owned(response.text().await.unwrap_or_default());
 
             error!(
                 endpoint = %endpoint,
@@ -172,61 +259,241 @@ impl Client {
         }
     }
 
+    /// 엔드포인트 하나를 기준일자(YYYYMMDD)로 조회합니다.
+    ///
+    /// 카테고리별 빌더와 같은 결과를 반환하며, [`Endpoint`]를 구현한 모든 타입에
+    /// 대해 동작하므로 여러 엔드포인트를 다루는 코드를 한 번만 작성할 수 있습니다.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use krx_rs::{Client, api::endpoint::StockDaily};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), krx_rs::error::Error> {
+    /// # let client = Client::new("YOUR_AUTH_KEY");
+    /// let df = client.fetch::<StockDaily>("20240105").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch<E: Endpoint>(&self, date: impl Into<String>) -> Result<DataFrame> {
+        let response = self.get_records::<E>(Some(date.into())).await?;
+        E::parse(response)
+    }
+
+    /// 엔드포인트 하나를 기준일자(YYYYMMDD)로 조회하여 DataFrame 대신 레코드 목록을 반환합니다.
+    ///
+    /// [`crate::validate`]처럼 `data::*`의 레코드 타입을 직접 다룰 때 사용합니다.
+    pub async fn fetch_records<E: Endpoint>(
+        &self,
+        date: impl Into<String>,
+    ) -> Result<Vec<E::Record>> {
+        Ok(self.get_records::<E>(Some(date.into())).await?.data)
+    }
+
+    /// 이름(`etp/etf_bydd_trd` 등)으로 지정한 엔드포인트를 기준일자(YYYYMMDD)로 조회합니다.
+    ///
+    /// 지원하는 이름 목록은 [`api::endpoint::ALL`]을 참고하세요.
+    ///
+    /// # Errors
+    /// - 등록되지 않은 이름이면 비슷한 이름 목록과 함께 `Error::InvalidInput` 반환.
+    pub async fn fetch_by_name(&self, name: &str, date: impl Into<String>) -> Result<DataFrame> {
+        api::endpoint::find(name)?.fetch(self, date).await
+    }
+
+    /// 여러 조회를 동시 실행 수를 제한하여 실행하는 배치를 만듭니다.
+    ///
+    /// 자세한 내용은 [`crate::batch`] 모듈을 참고하세요.
+    pub fn batch(&self) -> Batch {
+        Batch::new(self.clone())
+    }
+
+    /// 엔드포인트의 기간 데이터를 거래일별 스트림으로 조회하는 빌더를 만듭니다.
+    ///
+    /// 자세한 내용은 [`crate::stream`] 모듈을 참고하세요.
+    pub fn fetch_range<E: Endpoint + 'static>(
+        &self,
+        start_date: impl Into<String>,
+        end_date: impl Into<String>,
+    ) -> RangeStreamBuilder {
+        let client = self.clone();
+        RangeStreamBuilder::new(start_date, end_date, move |date| {
+            let client = client.clone();
+            async move { client.fetch::<E>(date).await }
+        })
+    }
+
+    /// 이름으로 지정한 엔드포인트의 기간 데이터를 거래일별 스트림으로 조회하는 빌더를 만듭니다.
+    ///
+    /// # Errors
+    /// - 등록되지 않은 이름이면 비슷한 이름 목록과 함께 `Error::InvalidInput` 반환.
+    pub fn fetch_range_by_name(
+        &self,
+        name: &str,
+        start_date: impl Into<String>,
+        end_date: impl Into<String>,
+    ) -> Result<RangeStreamBuilder> {
+        let info = api::endpoint::find(name)?;
+        let client = self.clone();
+        Ok(RangeStreamBuilder::new(start_date, end_date, move |date| {
+            let client = client.clone();
+            async move { info.fetch(&client, date).await }
+        }))
+    }
+
+    /// 데이터 공개 여부를 확인하는 설정 객체를 만듭니다.
+    ///
+    /// 자세한 내용은 [`crate::availability`] 모듈을 참고하세요.
+    pub fn availability(&self) -> AvailabilityChecker {
+        AvailabilityChecker::new(self.clone())
+    }
+
+    /// 엔드포인트를 조회하고 결과를 데이터, 공개 전, 휴장일, 빈 결과로 구분합니다.
+    pub async fn fetch_outcome<E: Endpoint + 'static>(&self, date: &str) -> Result<FetchOutcome> {
+        self.availability().fetch_outcome::<E>(date).await
+    }
+
+    /// 기준일자(YYYYMMDD)의 데이터가 공개되어 있는지 확인합니다.
+    pub async fn is_available<E: Endpoint + 'static>(&self, date: &str) -> Result<bool> {
+        self.availability().is_available::<E>(date).await
+    }
+
+    /// 데이터가 공개될 때까지 간격을 늘려 가며 최대 `timeout` 동안 다시 조회합니다.
+    pub async fn wait_until_available<E: Endpoint + 'static>(
+        &self,
+        date: &str,
+        timeout: Duration,
+    ) -> Result<FetchOutcome> {
+        self.availability()
+            .wait_until_available::<E>(date, timeout)
+            .await
+    }
+
+    /// 기준일자를 검증한 뒤 엔드포인트의 응답 레코드를 가져옵니다.
+    pub(crate) async fn get_records<E: Endpoint>(
+        &self,
+        base_date: Option<String>,
+    ) -> Result<ApiResponse<E::Record>> {
+        let base_date = validate_base_date(base_date)?;
+        self.get(E::PATH, &[("basDd", &base_date)]).await
+    }
+
     /// 주식 API 접근
-    pub fn stock(&self) -> api::stock::StockApi<'_> {
-        api::stock::StockApi::new(self)
+    pub fn stock(&self) -> api::stock::StockApi {
+        api::stock::StockApi::new(self.clone())
     }
 
     /// 지수 API 접근
-    pub fn index(&self) -> api::index::IndexApi<'_> {
-        api::index::IndexApi::new(self)
+    pub fn index(&self) -> api::index::IndexApi {
+        api::index::IndexApi::new(self.clone())
     }
 
     /// 채권 API 접근
-    pub fn bond(&self) -> api::bond::BondApi<'_> {
-        api::bond::BondApi::new(self)
+    pub fn bond(&self) -> api::bond::BondApi {
+        api::bond::BondApi::new(self.clone())
     }
 
     /// ETP API 접근
-    pub fn etp(&self) -> api::etp::EtpApi<'_> {
-        api::etp::EtpApi::new(self)
+    pub fn etp(&self) -> api::etp::EtpApi {
+        api::etp::EtpApi::new(self.clone())
     }
 
     /// 파생상품 API 접근
-    pub fn derivative(&self) -> api::derivative::DerivativeApi<'_> {
-        api::derivative::DerivativeApi::new(self)
+    pub fn derivative(&self) -> api::derivative::DerivativeApi {
+        api::derivative::DerivativeApi::new(self.clone())
     }
 
     /// 일반상품 API 접근
-    pub fn general(&self) -> api::general::GeneralApi<'_> {
-        api::general::GeneralApi::new(self)
+    pub fn general(&self) -> api::general::GeneralApi {
+        api::general::GeneralApi::new(self.clone())
     }
 
     /// ESG API 접근
-    pub fn esg(&self) -> api::esg::EsgApi<'_> {
-        api::esg::EsgApi::new(self)
+    pub fn esg(&self) -> api::esg::EsgApi {
+        api::esg::EsgApi::new(self.clone())
+    }
+
+    /// 지금까지 기록된 요청 지표의 스냅샷을 반환합니다.
+    ///
+    /// 자세한 내용은 [`crate::metrics`] 모듈을 참고하세요.
+    pub fn stats(&self) -> ClientStats {
+        self.inner.metrics.snapshot()
+    }
+
+    /// 기록된 요청 지표를 초기화합니다.
+    pub fn reset_stats(&self) {
+        self.inner.metrics.reset();
+    }
+
+    /// 인증키 공급자에서 인증키를 다시 불러옵니다.
+    ///
+    /// 실패하면 기존 인증키를 그대로 사용합니다. 공급자의 I/O(파일 읽기, 외부 명령 실행)를
+    /// 현재 스레드에서 수행하므로, async 코드에서는 `tokio::task::spawn_blocking` 안에서
+    /// 호출하세요.
+    pub fn reload_credentials(&self) -> Result<()> {
+        self.inner.credentials.reload()
+    }
+
+    /// 현재 사용 중인 인증키 수
+    pub fn credential_count(&self) -> usize {
+        self.inner.credentials.len()
     }
 
     /// 현재 클라이언트의 기본 URL을 반환합니다. (테스트용)
     pub fn get_base_url(&self) -> &str {
-        &self.base_url
+        &self.inner.base_url
     }
 }
 
 /// 클라이언트 빌더
 #[derive(Default)]
 pub struct ClientBuilder {
-    auth_key: Option<String>,
+    credentials: Option<Box<dyn CredentialProvider>>,
+    credential_refresh: Option<Duration>,
     base_url: Option<String>,
     timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
     user_agent: Option<String>,
     logging_config: Option<LoggingConfig>,
+    https_only: bool,
+    proxy: Option<String>,
+    proxy_auth: Option<(String, String)>,
+    no_proxy: bool,
+    root_certificates: Vec<Vec<u8>>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    http2_prior_knowledge: bool,
+    gzip: Option<bool>,
+    http_client: Option<HttpClient>,
 }
 
 impl ClientBuilder {
     /// 인증키 설정
     pub fn auth_key(mut self, key: impl Into<String>) -> Self {
-        self.auth_key = Some(key.into());
+        self.credentials = Some(Box::new(StaticCredentials::new([key.into()])));
+        self
+    }
+
+    /// 여러 인증키를 풀로 설정합니다. 호출은 키마다 번갈아 보내집니다.
+    pub fn auth_keys<I, S>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.credentials = Some(Box::new(StaticCredentials::new(keys)));
+        self
+    }
+
+    /// 인증키 공급자 설정
+    pub fn credentials(mut self, provider: impl CredentialProvider + 'static) -> Self {
+        self.credentials = Some(Box::new(provider));
+        self
+    }
+
+    /// 인증키를 주기적으로 다시 불러오도록 설정합니다.
+    ///
+    /// 주기가 지난 뒤 첫 요청이 백그라운드 갱신을 시작하며, 갱신이 끝날 때까지는
+    /// 기존 인증키로 요청합니다.
+    pub fn credential_refresh(mut self, interval: Duration) -> Self {
+        self.credential_refresh = Some(interval);
         self
     }
 
@@ -254,43 +521,185 @@ impl ClientBuilder {
         self
     }
 
+    /// 연결 수립 타임아웃 설정
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// HTTPS 요청만 허용합니다.
+    ///
+    /// 기본 URL을 지정하지 않았다면 KRX API의 HTTPS 주소를 사용하며,
+    /// `http://` 기본 URL을 지정하면 빌드 시 오류를 반환합니다.
+    pub fn https_only(mut self, enabled: bool) -> Self {
+        self.https_only = enabled;
+        self
+    }
+
+    /// 모든 요청에 사용할 HTTP(S) 프록시 URL 설정 (예: `http://proxy.corp:3128`)
+    pub fn proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    /// 프록시 Basic 인증 정보 설정
+    pub fn proxy_auth(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.proxy_auth = Some((username.into(), password.into()));
+        self
+    }
+
+    /// 시스템 프록시 설정(`HTTP_PROXY` 등 환경 변수)을 무시합니다.
+    pub fn no_proxy(mut self) -> Self {
+        self.no_proxy = true;
+        self
+    }
+
+    /// 신뢰할 루트 인증서(PEM)를 추가합니다.
+    pub fn add_root_certificate_pem(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.root_certificates.push(pem.into());
+        self
+    }
+
+    /// 신뢰할 루트 인증서(PEM)를 파일에서 읽어 추가합니다.
+    ///
+    /// # Errors
+    /// - 파일을 읽을 수 없으면 `Error::Io` 반환.
+    pub fn root_certificate_file(self, path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let pem = std::fs::read(path)?;
+        Ok(self.add_root_certificate_pem(pem))
+    }
+
+    /// 호스트당 유지할 최대 유휴 연결 수
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// 유휴 연결 유지 시간
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// HTTP/2로만 연결합니다 (서버가 HTTP/2를 지원해야 함).
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.http2_prior_knowledge = true;
+        self
+    }
+
+    /// gzip 응답 압축 사용 여부 (기본값: 사용)
+    pub fn gzip(mut self, enabled: bool) -> Self {
+        self.gzip = Some(enabled);
+        self
+    }
+
+    /// 미리 구성한 `reqwest::Client`를 사용합니다.
+    ///
+    /// 이 경우 타임아웃, User-Agent, 프록시, TLS, 연결 풀 설정은 주입한 클라이언트의
+    /// 설정을 따르며 빌더에 지정한 값은 무시됩니다.
+    pub fn http_client(mut self, client: HttpClient) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
     /// 클라이언트 빌드
-    pub fn build(self) -> Result<Client> {
-        let auth_key = self
-            .auth_key
+    pub fn build(mut self) -> Result<Client> {
+        let provider = self
+            .credentials
+            .take()
             .ok_or_else(|| Error::InvalidInput("auth_key is required".to_string()))?;
+        let credentials = KeyPool::new(provider, self.credential_refresh)?;
 
         // Initialize logging if a configuration is provided.
-        if let Some(config) = self.logging_config {
+        if let Some(config) = self.logging_config.take() {
             // 로깅 초기화 시도하지만 실패해도 계속 진행
             if let Err(e) = crate::logging::init_logging(&config) {
                 eprintln!("Warning: Failed to initialize logging: {}", e);
             }
         }
 
+        let base_url = self.base_url.clone().unwrap_or_else(|| {
+            if self.https_only {
+                HTTPS_BASE_URL.to_string()
+            } else {
+                BASE_URL.to_string()
+            }
+        });
+        if self.https_only && !base_url.starts_with("https://") {
+            return Err(Error::InvalidInput(
+                "base_url must use https when https_only is enabled".to_string(),
+            ));
+        }
+
+        let http_client = match self.http_client.take() {
+            Some(client) => client,
+            None => self.build_http_client()?,
+        };
+
+        Ok(Client {
+            inner: Arc::new(ClientInner {
+                http_client,
+                credentials,
+                base_url,
+                metrics: Metrics::default(),
+            }),
+        })
+    }
+}
+
+impl ClientBuilder {
+    /// 빌더 설정으로 reqwest 클라이언트를 만듭니다.
+    fn build_http_client(self) -> Result<HttpClient> {
         let mut headers = HeaderMap::new();
-        headers.insert(
-            "AUTH_KEY",
-            HeaderValue::from_str(&auth_key)
-                .map_err(|_| Error::InvalidInput("Invalid auth_key format".to_string()))?,
-        );
         headers.insert("Content-Type", HeaderValue::from_static("application/json"));
 
-        let http_client = HttpClient::builder()
+        let mut builder = HttpClient::builder()
             .default_headers(headers)
             .timeout(self.timeout.unwrap_or(DEFAULT_TIMEOUT))
             .user_agent(
                 self.user_agent
                     .unwrap_or_else(|| format!("krx-rs/{}", env!("CARGO_PKG_VERSION"))),
             )
-            .build()?;
+            .https_only(self.https_only)
+            .gzip(self.gzip.unwrap_or(true));
 
-        let base_url = self.base_url.unwrap_or_else(|| BASE_URL.to_string());
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if self.no_proxy {
+            builder = builder.no_proxy();
+        }
+        if let Some(url) = &self.proxy {
+            let mut proxy = reqwest::Proxy::all(url)
+                .map_err(|e| Error::InvalidInput(format!("Invalid proxy URL: {e}")))?;
+            if let Some((username, password)) = &self.proxy_auth {
+                proxy = proxy.basic_auth(username, password);
+            }
+            builder = builder.proxy(proxy);
+        }
+        for pem in &self.root_certificates {
+            let certificate = reqwest::Certificate::from_pem(pem)
+                .map_err(|e| Error::InvalidInput(format!("Invalid root certificate: {e}")))?;
+            builder = builder.add_root_certificate(certificate);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if self.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
 
-        Ok(Client {
-            http_client,
-            auth_key,
-            base_url,
-        })
+        Ok(builder.build()?)
     }
 }
+
+/// 인증키 헤더 값 (Debug 출력에서 숨겨지도록 민감 값으로 표시)
+fn auth_header(auth_key: &str) -> Result<HeaderValue> {
+    let mut value = HeaderValue::from_str(auth_key)
+        .map_err(|_| Error::InvalidInput("Invalid auth_key format".to_string()))?;
+    value.set_sensitive(true);
+    Ok(value)
+}
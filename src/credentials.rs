@@ -0,0 +1,476 @@
+//! 인증키 공급자와 인증키 풀.
+//!
+//! [`ClientBuilder::credentials`](crate::ClientBuilder::credentials)에
+//! [`CredentialProvider`]를 지정하면 클라이언트를 다시 만들지 않고도
+//! [`Client::reload_credentials`](crate::Client::reload_credentials)로 인증키를 다시 불러올 수 있습니다.
+//!
+//! 공급자가 인증키를 여러 개 반환하면 호출을 순서대로 나누어 보내며, 어떤 키가 일일 호출
+//! 한도에 도달해 속도 제한(429) 응답을 받으면 그날(KST)은 해당 키를 제외하고 다음 키로 재시도합니다.
+//!
+//! | 공급자                  | 인증키 출처                                   |
+//! |-------------------------|-----------------------------------------------|
+//! | [`StaticCredentials`]   | 코드에 지정한 값                              |
+//! | [`EnvCredentials`]      | 환경 변수 (쉼표·공백으로 여러 키 구분)        |
+//! | [`FileCredentials`]     | 파일 (한 줄에 키 하나, `#` 주석 허용)         |
+//! | [`CommandCredentials`]  | 외부 명령(비밀 관리 CLI)의 표준 출력          |
+//!
+//! # 예시
+//! ```rust,no_run
+//! use krx_rs::{Client, credentials::CommandCredentials};
+//! use std::time::Duration;
+//!
+//! # fn main() -> Result<(), krx_rs::Error> {
+//! let client = Client::builder()
+//!     .credentials(CommandCredentials::new("vault").args(["kv", "get", "-field=key", "secret/krx"]))
+//!     .credential_refresh(Duration::from_secs(3600))
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    calendar::seoul,
+    error::{Error, Result},
+    logging::redact,
+};
+use chrono::{NaiveDate, Utc};
+use std::{
+    fmt, fs,
+    path::PathBuf,
+    process::Command,
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+/// 인증키를 불러오는 공급자
+pub trait CredentialProvider: Send + Sync + fmt::Debug {
+    /// 사용할 인증키 목록(하나 이상)을 불러옵니다.
+    ///
+    /// 인증키를 다시 불러올 때마다 호출되며, 블로킹 I/O를 수행할 수 있습니다.
+    fn load(&self) -> Result<Vec<String>>;
+}
+
+/// 코드에 지정한 고정 인증키
+#[derive(Clone)]
+pub struct StaticCredentials {
+    keys: Vec<String>,
+}
+
+impl StaticCredentials {
+    /// 하나 이상의 인증키로 공급자를 만듭니다.
+    pub fn new<I, S>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            keys: keys.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl fmt::Debug for StaticCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StaticCredentials")
+            .field("keys", &self.keys.len())
+            .finish()
+    }
+}
+
+impl CredentialProvider for StaticCredentials {
+    fn load(&self) -> Result<Vec<String>> {
+        Ok(self.keys.clone())
+    }
+}
+
+/// 환경 변수에서 읽는 인증키 (쉼표 또는 공백으로 여러 키 구분)
+#[derive(Debug, Clone)]
+pub struct EnvCredentials {
+    var: String,
+}
+
+impl EnvCredentials {
+    /// 환경 변수 이름으로 공급자를 만듭니다.
+    pub fn new(var: impl Into<String>) -> Self {
+        Self { var: var.into() }
+    }
+}
+
+impl CredentialProvider for EnvCredentials {
+    fn load(&self) -> Result<Vec<String>> {
+        let value = std::env::var(&self.var).map_err(|_| {
+            Error::Authentication(format!("environment variable {} is not set", self.var))
+        })?;
+        Ok(value
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|key| !key.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+}
+
+/// 파일에서 읽는 인증키 (한 줄에 키 하나, 빈 줄과 `#` 주석 무시)
+#[derive(Debug, Clone)]
+pub struct FileCredentials {
+    path: PathBuf,
+}
+
+impl FileCredentials {
+    /// 파일 경로로 공급자를 만듭니다.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl CredentialProvider for FileCredentials {
+    fn load(&self) -> Result<Vec<String>> {
+        Ok(parse_lines(&fs::read_to_string(&self.path)?))
+    }
+}
+
+/// 외부 명령(비밀 관리 CLI)의 표준 출력에서 읽는 인증키 (한 줄에 키 하나)
+#[derive(Debug, Clone)]
+pub struct CommandCredentials {
+    program: String,
+    args: Vec<String>,
+}
+
+impl CommandCredentials {
+    /// 실행할 프로그램으로 공급자를 만듭니다.
+    pub fn new(program: impl Into<String>) -> Self {
+        Self {
+            program: program.into(),
+            args: Vec::new(),
+        }
+    }
+
+    /// 프로그램 인자를 설정합니다.
+    #[must_use]
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args = args.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl CredentialProvider for CommandCredentials {
+    fn load(&self) -> Result<Vec<String>> {
+        let output = Command::new(&self.program).args(&self.args).output()?;
+        if !output.status.success() {
+            return Err(Error::Authentication(format!(
+                "credential command {} exited with {}",
+                self.program, output.status
+            )));
+        }
+        Ok(parse_lines(&String::from_utf8_lossy(&output.stdout)))
+    }
+}
+
+fn parse_lines(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// 풀에 속한 인증키 하나의 상태
+#[derive(Debug, Clone)]
+struct KeyState {
+    key: Arc<str>,
+    /// 일일 한도에 도달한 날짜 (KST)
+    exhausted_on: Option<NaiveDate>,
+}
+
+#[derive(Debug)]
+struct PoolState {
+    keys: Vec<KeyState>,
+    loaded_at: Instant,
+}
+
+/// 공급자에서 불러온 인증키 풀
+///
+/// 주기적 갱신은 요청 경로를 막지 않도록 tokio 블로킹 스레드에서 한 번에 하나씩
+/// 수행되며, 갱신이 끝날 때까지는 기존 인증키를 계속 사용합니다.
+#[derive(Debug)]
+pub(crate) struct KeyPool {
+    provider: Arc<dyn CredentialProvider>,
+    refresh: Option<Duration>,
+    state: Arc<RwLock<PoolState>>,
+    refreshing: Arc<AtomicBool>,
+    next: AtomicUsize,
+}
+
+impl KeyPool {
+    pub(crate) fn new(
+        provider: Box<dyn CredentialProvider>,
+        refresh: Option<Duration>,
+    ) -> Result<Self> {
+        let keys = load_keys(provider.as_ref())?;
+        Ok(Self {
+            provider: Arc::from(provider),
+            refresh,
+            state: Arc::new(RwLock::new(PoolState {
+                keys: keys
+                    .into_iter()
+                    .map(|key| KeyState {
+                        key,
+                        exhausted_on: None,
+                    })
+                    .collect(),
+                loaded_at: Instant::now(),
+            })),
+            refreshing: Arc::new(AtomicBool::new(false)),
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// 공급자에서 인증키를 다시 불러옵니다. 유지되는 키의 한도 상태는 보존됩니다.
+    ///
+    /// 공급자의 I/O를 현재 스레드에서 수행합니다.
+    pub(crate) fn reload(&self) -> Result<()> {
+        reload_into(self.provider.as_ref(), &self.state)
+    }
+
+    /// 풀에 있는 인증키 수
+    pub(crate) fn len(&self) -> usize {
+        self.state
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .keys
+            .len()
+    }
+
+    /// 다음 요청에 사용할 인증키를 고릅니다.
+    ///
+    /// # Errors
+    /// - 모든 키가 오늘 한도에 도달했으면 `Error::RateLimit` 반환 (다음 KST 자정까지의 초).
+    pub(crate) fn select(&self) -> Result<Arc<str>> {
+        self.refresh_if_due();
+
+        let today = kst_today();
+        let state = self.state.read().unwrap_or_else(|e| e.into_inner());
+        let len = state.keys.len();
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+        (0..len)
+            .map(|offset| &state.keys[(start + offset) % len])
+            .find(|key| key.exhausted_on != Some(today))
+            .map(|key| Arc::clone(&key.key))
+            .ok_or(Error::RateLimit {
+                retry_after: seconds_until_kst_midnight(),
+            })
+    }
+
+    /// 인증키를 오늘 한도에 도달한 것으로 표시합니다.
+    ///
+    /// 풀에 키가 하나뿐이면 표시하지 않습니다. 아직 사용할 수 있는 다른 키가 남아 있으면 `true`.
+    pub(crate) fn mark_exhausted(&self, key: &str) -> bool {
+        let today = kst_today();
+        let mut state = self.state.write().unwrap_or_else(|e| e.into_inner());
+        if state.keys.len() < 2 {
+            return false;
+        }
+        for state in state.keys.iter_mut().filter(|state| &*state.key == key) {
+            state.exhausted_on = Some(today);
+        }
+        state
+            .keys
+            .iter()
+            .any(|state| state.exhausted_on != Some(today))
+    }
+
+    /// 갱신 주기가 지났으면 갱신을 시작합니다.
+    ///
+    /// 이미 진행 중인 갱신이 있으면 아무것도 하지 않습니다. tokio 런타임 안에서는
+    /// 블로킹 스레드에서 갱신하고 바로 반환하며, 런타임 밖에서는 현재 스레드에서 갱신합니다.
+    fn refresh_if_due(&self) {
+        let Some(refresh) = self.refresh else {
+            return;
+        };
+        let loaded_at = self
+            .state
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .loaded_at;
+        if loaded_at.elapsed() < refresh
+            || self
+                .refreshing
+                .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+                .is_err()
+        {
+            return;
+        }
+
+        let provider = Arc::clone(&self.provider);
+        let state = Arc::clone(&self.state);
+        let refreshing = Arc::clone(&self.refreshing);
+        let task = move || {
+            if let Err(e) = reload_into(provider.as_ref(), &state) {
+                tracing::warn!(error = %e, "Failed to reload credentials, keeping previous keys");
+                // 실패한 공급자를 매 요청마다 다시 호출하지 않도록 다음 주기까지 기다립니다.
+                state.write().unwrap_or_else(|e| e.into_inner()).loaded_at = Instant::now();
+            }
+            refreshing.store(false, Ordering::Release);
+        };
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => drop(handle.spawn_blocking(task)),
+            Err(_) => task(),
+        }
+    }
+}
+
+/// 공급자에서 인증키를 불러와 풀 상태를 교체합니다. 유지되는 키의 한도 상태는 보존됩니다.
+fn reload_into(provider: &dyn CredentialProvider, state: &RwLock<PoolState>) -> Result<()> {
+    let keys = load_keys(provider)?;
+    let mut state = state.write().unwrap_or_else(|e| e.into_inner());
+    let previous = std::mem::take(&mut state.keys);
+    state.keys = keys
+        .into_iter()
+        .map(|key| {
+            let exhausted_on = previous
+                .iter()
+                .find(|old| old.key == key)
+                .and_then(|old| old.exhausted_on);
+            KeyState { key, exhausted_on }
+        })
+        .collect();
+    state.loaded_at = Instant::now();
+    Ok(())
+}
+
+/// 공급자에서 키를 불러와 검증하고 마스킹 대상으로 등록합니다.
+fn load_keys(provider: &dyn CredentialProvider) -> Result<Vec<Arc<str>>> {
+    let mut keys: Vec<Arc<str>> = Vec::new();
+    for key in provider.load()? {
+        let key = key.trim();
+        if key.is_empty() || keys.iter().any(|k| &**k == key) {
+            continue;
+        }
+        if !key.bytes().all(|b| b.is_ascii_graphic()) {
+            return Err(Error::InvalidInput("Invalid auth_key format".to_string()));
+        }
+        redact::add_secret(key);
+        keys.push(Arc::from(key));
+    }
+    if keys.is_empty() {
+        return Err(Error::Authentication(
+            "credential provider returned no auth key".to_string(),
+        ));
+    }
+    Ok(keys)
+}
+
+fn kst_today() -> NaiveDate {
+    Utc::now().with_timezone(&seoul()).date_naive()
+}
+
+fn seconds_until_kst_midnight() -> u64 {
+    let now = Utc::now().with_timezone(&seoul());
+    let midnight = (now.date_naive() + chrono::Duration::days(1))
+        .and_hms_opt(0, 0, 0)
+        .expect("valid midnight");
+    (midnight - now.naive_local()).num_seconds().max(1) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(keys: &[&str]) -> KeyPool {
+        KeyPool::new(Box::new(StaticCredentials::new(keys.iter().copied())), None).unwrap()
+    }
+
+    #[test]
+    fn test_round_robin_and_exhaustion() {
+        let pool = pool(&["key-aaaa-1111", "key-bbbb-2222"]);
+        let first = pool.select().unwrap();
+        let second = pool.select().unwrap();
+        assert_ne!(first, second);
+
+        assert!(pool.mark_exhausted(&first));
+        for _ in 0..3 {
+            assert_eq!(pool.select().unwrap(), second);
+        }
+        assert!(!pool.mark_exhausted(&second));
+        assert!(matches!(pool.select(), Err(Error::RateLimit { .. })));
+    }
+
+    #[test]
+    fn test_single_key_is_never_exhausted() {
+        let pool = pool(&["only-key-1234"]);
+        assert!(!pool.mark_exhausted("only-key-1234"));
+        assert_eq!(&*pool.select().unwrap(), "only-key-1234");
+    }
+
+    #[test]
+    fn test_file_credentials_and_reload() {
+        let path = std::env::temp_dir().join(format!("krx-keys-{}", std::process::id()));
+        fs::write(&path, "# krx keys\nfile-key-0001\n\nfile-key-0002\n").unwrap();
+        let pool = KeyPool::new(Box::new(FileCredentials::new(&path)), None).unwrap();
+        assert_eq!(pool.len(), 2);
+
+        fs::write(&path, "file-key-0003\n").unwrap();
+        pool.reload().unwrap();
+        assert_eq!(&*pool.select().unwrap(), "file-key-0003");
+
+        fs::write(&path, "# empty\n").unwrap();
+        assert!(matches!(pool.reload(), Err(Error::Authentication(_))));
+        assert_eq!(pool.len(), 1);
+        fs::remove_file(path).unwrap();
+    }
+
+    /// 불러올 때마다 횟수를 세고 잠시 블로킹하는 공급자
+    #[derive(Debug, Default)]
+    struct SlowCredentials {
+        loads: Arc<AtomicUsize>,
+    }
+
+    impl CredentialProvider for SlowCredentials {
+        fn load(&self) -> Result<Vec<String>> {
+            let n = self.loads.fetch_add(1, Ordering::SeqCst);
+            if n > 0 {
+                std::thread::sleep(Duration::from_millis(200));
+            }
+            Ok(vec![format!("slow-key-{n:04}")])
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_refresh_runs_in_background_once() {
+        let loads = Arc::new(AtomicUsize::new(0));
+        let provider = SlowCredentials {
+            loads: Arc::clone(&loads),
+        };
+        let pool = KeyPool::new(Box::new(provider), Some(Duration::ZERO)).unwrap();
+
+        // 갱신이 진행되는 동안에도 기존 키로 바로 응답하고, 갱신은 한 번만 시작합니다.
+        let started = Instant::now();
+        for _ in 0..10 {
+            assert_eq!(&*pool.select().unwrap(), "slow-key-0000");
+        }
+        assert!(started.elapsed() < Duration::from_millis(150));
+
+        tokio::time::sleep(Duration::from_millis(400)).await;
+        assert_eq!(loads.load(Ordering::SeqCst), 2);
+        assert_eq!(&*pool.state.read().unwrap().keys[0].key, "slow-key-0001");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_env_and_command_credentials() {
+        assert!(EnvCredentials::new("KRX_RS_TEST_UNSET_VAR").load().is_err());
+        assert_eq!(
+            CommandCredentials::new("echo")
+                .args(["cmd-key-0001"])
+                .load()
+                .unwrap(),
+            vec!["cmd-key-0001"]
+        );
+    }
+}
@@ -16,6 +16,17 @@ pub struct ApiResponse<T> {
     pub data: Vec<T>,
 }
 
+/// 응답에 포함된 레코드 수 (요청 지표 기록용)
+pub(crate) trait RecordCount {
+    fn record_count(&self) -> usize;
+}
+
+impl<T> RecordCount for ApiResponse<T> {
+    fn record_count(&self) -> usize {
+        self.data.len()
+    }
+}
+
 /// KRX 날짜 형식(YYYYMMDD)을 NaiveDate로 변환하는 헬퍼
 pub fn deserialize_krx_date<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
 where
@@ -56,6 +67,22 @@ where
     }
 }
 
+/// 액면가를 Option<f64>로 파싱하는 헬퍼 ("무액면"은 None)
+pub fn deserialize_optional_par_value<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s: String = Deserialize::deserialize(deserializer)?;
+    if s.is_empty() || s == "-" || s == "무액면" {
+        Ok(None)
+    } else {
+        s.replace(',', "")
+            .parse::<f64>()
+            .map(Some)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 /// 문자열을 Option<u64>로 파싱하는 헬퍼 (거래량 등의 정수값용)
 pub fn deserialize_optional_u64<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
 where
@@ -317,6 +344,22 @@ mod tests {
         assert_eq!(result.unwrap(), None);
     }
 
+    #[test]
+    fn test_deserialize_optional_par_value() {
+        let deserializer = TestDeserializer {
+            value: Value::String("무액면".to_string()),
+        };
+        assert_eq!(deserialize_optional_par_value(deserializer).unwrap(), None);
+
+        let deserializer = TestDeserializer {
+            value: Value::String("5,000".to_string()),
+        };
+        assert_eq!(
+            deserialize_optional_par_value(deserializer).unwrap(),
+            Some(5000.0)
+        );
+    }
+
     #[test]
     fn test_deserialize_optional_u64_dash() {
         let deserializer = TestDeserializer {
@@ -1,6 +1,6 @@
 use super::{
-    ApiResponse, deserialize_krx_date, deserialize_optional_f64, deserialize_optional_percentage,
-    deserialize_optional_u64,
+    ApiResponse, deserialize_krx_date, deserialize_optional_f64, deserialize_optional_krx_date,
+    deserialize_optional_par_value, deserialize_optional_percentage, deserialize_optional_u64,
 };
 use crate::error::Result;
 use chrono::NaiveDate;
@@ -153,12 +153,18 @@ pub struct StockWarrantDailyRecord {
     pub exercise_price: Option<f64>,
 
     /// 존속기간_시작일
-    #[serde(rename = "EXST_STRT_DD")]
-    pub existence_start_date: String,
+    #[serde(
+        rename = "EXST_STRT_DD",
+        deserialize_with = "deserialize_optional_krx_date"
+    )]
+    pub existence_start_date: Option<NaiveDate>,
 
     /// 존속기간_종료일
-    #[serde(rename = "EXST_END_DD")]
-    pub existence_end_date: String,
+    #[serde(
+        rename = "EXST_END_DD",
+        deserialize_with = "deserialize_optional_krx_date"
+    )]
+    pub existence_end_date: Option<NaiveDate>,
 
     /// 목적주권_종목코드
     #[serde(rename = "TARSTK_ISU_SRT_CD")]
@@ -246,8 +252,11 @@ pub struct StockRightDailyRecord {
     pub issue_price: Option<f64>,
 
     /// 상장폐지일
-    #[serde(rename = "DELIST_DD")]
-    pub delisting_date: String,
+    #[serde(
+        rename = "DELIST_DD",
+        deserialize_with = "deserialize_optional_krx_date"
+    )]
+    pub delisting_date: Option<NaiveDate>,
 
     /// 목적주권_종목코드
     #[serde(rename = "TARSTK_ISU_SRT_CD")]
@@ -309,7 +318,7 @@ pub struct StockBaseInfoRecord {
     pub stock_type: String,
 
     /// 액면가
-    #[serde(rename = "PARVAL", deserialize_with = "deserialize_optional_f64")]
+    #[serde(rename = "PARVAL", deserialize_with = "deserialize_optional_par_value")]
     pub par_value: Option<f64>,
 
     /// 상장주식수
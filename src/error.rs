@@ -29,6 +29,10 @@ pub enum Error {
     #[error("DataFrame operation failed")]
     DataFrame(#[from] polars::prelude::PolarsError),
 
+    /// 파일 입출력 오류
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
     /// 인증 오류
     #[error("Authentication failed: {0}")]
     Authentication(String),
@@ -36,6 +40,11 @@ pub enum Error {
     /// 속도 제한 초과
     #[error("Rate limit exceeded, retry after {retry_after} seconds")]
     RateLimit { retry_after: u64 },
+
+    /// 로컬 SQLite 저장소 오류
+    #[cfg(feature = "sqlite")]
+    #[error("SQLite store error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -48,6 +48,7 @@
 //!         json_format: false,
 //!         filter_sensitive: true,
 //!         file_path: None,
+//!         ..Default::default()
 //!     };
 //!     let client = Client::builder()
 //!         .auth_key("your_auth_key")
@@ -99,13 +100,25 @@
 //!
 //! _문의/기여/이슈는 GitHub에서 환영합니다!_
 
+pub mod analysis;
 pub mod api;
+pub mod availability;
+pub mod batch;
+pub mod calendar;
 pub mod client;
+pub mod credentials;
 pub mod data;
 pub mod error;
 pub mod logging;
+pub mod metrics;
+pub mod scheduler;
+pub mod schema;
+pub mod store;
+pub mod stream;
+pub mod validate;
 
 // Re-export main types for convenience
+pub use api::endpoint::Endpoint;
 pub use client::{Client, ClientBuilder};
 pub use error::{Error, Result};
 pub use logging::LoggingConfig;
@@ -0,0 +1,194 @@
+//! 파일 로그 출력과 회전.
+
+use super::LogRotation;
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+
+/// 설정에 맞는 파일 writer를 만듭니다.
+///
+/// - [`LogRotation::Never`]: `path`에 계속 이어 씁니다.
+/// - [`LogRotation::Daily`]: `{stem}.{YYYY-MM-DD}.{ext}` 파일을 날짜별로 만듭니다.
+/// - [`LogRotation::Size`]: `path`가 한도를 넘으면 `path.1`, `path.2`, ... 로 밀어냅니다.
+///
+/// `max_files`는 현재 파일을 포함해 보관할 로그 파일 수입니다.
+pub(crate) fn writer(
+    path: &str,
+    rotation: LogRotation,
+    max_files: Option<usize>,
+) -> io::Result<Box<dyn Write + Send>> {
+    let path = Path::new(path);
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid log file path: {}", path.display()),
+            )
+        })?;
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    fs::create_dir_all(dir)?;
+
+    match rotation {
+        LogRotation::Never => Ok(Box::new(tracing_appender::rolling::never(dir, file_name))),
+        LogRotation::Daily => {
+            let (prefix, suffix) = match file_name.rsplit_once('.') {
+                Some((stem, ext)) if !stem.is_empty() => (stem, Some(ext)),
+                _ => (file_name, None),
+            };
+            let mut builder = RollingFileAppender::builder()
+                .rotation(Rotation::DAILY)
+                .filename_prefix(prefix);
+            if let Some(suffix) = suffix {
+                builder = builder.filename_suffix(suffix);
+            }
+            if let Some(max_files) = max_files {
+                builder = builder.max_log_files(max_files.max(1));
+            }
+            builder
+                .build(dir)
+                .map(|appender| Box::new(appender) as Box<dyn Write + Send>)
+                .map_err(io::Error::other)
+        }
+        LogRotation::Size(max_bytes) => Ok(Box::new(SizeRollingWriter::open(
+            dir.join(file_name),
+            max_bytes,
+            max_files,
+        )?)),
+    }
+}
+
+/// 파일 크기 기준으로 회전하는 writer
+struct SizeRollingWriter {
+    path: PathBuf,
+    max_bytes: u64,
+    max_files: Option<usize>,
+    file: File,
+    written: u64,
+}
+
+impl SizeRollingWriter {
+    fn open(path: PathBuf, max_bytes: u64, max_files: Option<usize>) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let written = file.metadata()?.len();
+        Ok(Self {
+            path,
+            max_bytes,
+            max_files,
+            file,
+            written,
+        })
+    }
+
+    fn backup_path(&self, index: usize) -> PathBuf {
+        let mut name = self.path.as_os_str().to_owned();
+        name.push(format!(".{index}"));
+        PathBuf::from(name)
+    }
+
+    /// 현재 파일을 `.1`로, 기존 백업은 번호를 하나씩 밀고 보관 한도를 넘는 파일은 삭제합니다.
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        // 현재 파일을 제외하고 보관할 백업 수
+        let keep = self.max_files.map(|n| n.max(1) - 1);
+        let mut last = 0;
+        while self.backup_path(last + 1).exists() {
+            last += 1;
+        }
+        for index in (1..=last).rev() {
+            if keep.is_some_and(|keep| index + 1 > keep) {
+                fs::remove_file(self.backup_path(index))?;
+            } else {
+                fs::rename(self.backup_path(index), self.backup_path(index + 1))?;
+            }
+        }
+        if keep == Some(0) {
+            fs::remove_file(&self.path)?;
+        } else {
+            fs::rename(&self.path, self.backup_path(1))?;
+        }
+
+        self.file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl Write for SizeRollingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.written > 0 && self.written + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        let n = self.file.write(buf)?;
+        self.written += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("krx-log-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_size_rotation_with_retention() {
+        let dir = temp_dir("size");
+        let path = dir.join("krx.log");
+        let mut writer = SizeRollingWriter::open(path.clone(), 10, Some(3)).unwrap();
+
+        for line in ["first-12\n", "second-1\n", "third-12\n", "fourth-1\n"] {
+            writer.write_all(line.as_bytes()).unwrap();
+        }
+        writer.flush().unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fourth-1\n");
+        assert_eq!(
+            fs::read_to_string(dir.join("krx.log.1")).unwrap(),
+            "third-12\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("krx.log.2")).unwrap(),
+            "second-1\n"
+        );
+        assert!(!dir.join("krx.log.3").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_daily_writer_file_name() {
+        let dir = temp_dir("daily");
+        let path = dir.join("krx-api.log");
+        let mut writer = writer(path.to_str().unwrap(), LogRotation::Daily, Some(7)).unwrap();
+        writer.write_all(b"hello\n").unwrap();
+        writer.flush().unwrap();
+
+        let names: Vec<String> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        assert_eq!(names.len(), 1);
+        assert!(names[0].starts_with("krx-api.") && names[0].ends_with(".log"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,361 @@
+mod file;
+#[cfg(feature = "otel")]
+/// OpenTelemetry 트레이스 내보내기
+pub mod otel;
+/// 민감한 정보 마스킹
+pub mod redact;
+
+use std::sync::Mutex;
+use tracing::{Subscriber, info};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_subscriber::{
+    EnvFilter, Layer, Registry, fmt::MakeWriter, layer::SubscriberExt, registry::LookupSpan,
+    util::SubscriberInitExt,
+};
+
+/// [`init_logging`]이 설치한 파일 writer의 백그라운드 작업 핸들
+static FILE_GUARD: Mutex<Option<WorkerGuard>> = Mutex::new(None);
+
+/// 로깅 설정 구조체
+#[derive(Debug, Clone)]
+pub struct LoggingConfig {
+    /// 로깅 레벨 (기본값: "info")
+    pub level: String,
+    /// JSON 형태 출력 여부 (표준 출력과 파일에 함께 적용)
+    pub json_format: bool,
+    /// 민감한 정보 필터링 여부 (인증키 등을 로그와 오류 메시지에서 마스킹)
+    pub filter_sensitive: bool,
+    /// 추가로 마스킹할 정규식 패턴 (`filter_sensitive`가 켜져 있을 때 적용)
+    pub redact_patterns: Vec<String>,
+    /// 파일 출력 경로 (옵션)
+    pub file_path: Option<String>,
+    /// 파일 로그 회전 방식 (기본값: [`LogRotation::Never`])
+    pub rotation: LogRotation,
+    /// 보관할 최대 로그 파일 수 (현재 파일 포함, `None`이면 제한 없음)
+    pub max_files: Option<usize>,
+    /// 표준 출력 여부 (기본값: true)
+    pub stdout: bool,
+    /// OpenTelemetry 트레이스 내보내기 설정 (옵션, `otel` feature 필요)
+    pub otlp: Option<OtlpConfig>,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            level: "info".to_string(),
+            json_format: false,
+            filter_sensitive: true,
+            redact_patterns: Vec::new(),
+            file_path: None,
+            rotation: LogRotation::Never,
+            max_files: None,
+            stdout: true,
+            otlp: None,
+        }
+    }
+}
+
+/// 파일 로그 회전 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogRotation {
+    /// 회전하지 않고 `file_path`에 계속 기록
+    #[default]
+    Never,
+    /// 날짜별 파일(`{이름}.{YYYY-MM-DD}.{확장자}`)로 기록
+    Daily,
+    /// 파일이 주어진 바이트 수를 넘으면 `{file_path}.1`, `{file_path}.2`, ... 로 회전
+    Size(u64),
+}
+
+/// OTLP(HTTP/protobuf) 트레이스 내보내기 설정
+#[derive(Debug, Clone)]
+pub struct OtlpConfig {
+    /// 수집기 트레이스 엔드포인트 (기본값: "http://localhost:4318/v1/traces")
+    pub endpoint: String,
+    /// `service.name` 리소스 속성 (기본값: "krx-rs")
+    pub service_name: String,
+}
+
+impl Default for OtlpConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: "http://localhost:4318/v1/traces".to_string(),
+            service_name: "krx-rs".to_string(),
+        }
+    }
+}
+
+/// Subscriber 빌드 (테스트 가능한 부분)
+///
+/// 파일 출력이 설정되면 반환된 subscriber가 drop될 때 남은 로그를 기록합니다.
+pub fn build_subscriber(
+    config: &LoggingConfig,
+) -> Result<Box<dyn tracing::Subscriber + Send + Sync>, Box<dyn std::error::Error>> {
+    let (subscriber, guard) = build(config)?;
+    Ok(match guard {
+        Some(guard) => Box::new(subscriber.with(GuardLayer { _guard: guard })),
+        None => subscriber,
+    })
+}
+
+type BoxedSubscriber = Box<dyn tracing::Subscriber + Send + Sync>;
+
+/// subscriber와 파일 writer의 작업 핸들을 만듭니다.
+fn build(
+    config: &LoggingConfig,
+) -> Result<(BoxedSubscriber, Option<WorkerGuard>), Box<dyn std::error::Error>> {
+    let env_filter =
+        EnvFilter::try_from_default_env().or_else(|_| EnvFilter::try_new(&config.level))?;
+
+    #[cfg(feature = "otel")]
+    let otel_layer = config.otlp.as_ref().map(otel::layer).transpose()?;
+    #[cfg(not(feature = "otel"))]
+    let otel_layer: Option<tracing_subscriber::layer::Identity> = match config.otlp {
+        Some(_) => return Err("OTLP export requires the `otel` feature".into()),
+        None => None,
+    };
+
+    if config.filter_sensitive {
+        for pattern in &config.redact_patterns {
+            redact::add_pattern(pattern)?;
+        }
+    }
+
+    let mut layers = Vec::new();
+    if config.stdout {
+        layers.push(output_layer(config, std::io::stdout, true));
+    }
+
+    let guard = match &config.file_path {
+        Some(path) => {
+            let writer = file::writer(path, config.rotation, config.max_files)?;
+            // 파일 쓰기는 백그라운드 스레드에서 처리하여 호출 스레드를 막지 않습니다.
+            let (writer, guard) = tracing_appender::non_blocking(writer);
+            layers.push(output_layer(config, writer, false));
+            Some(guard)
+        }
+        None => None,
+    };
+
+    let registry = Registry::default()
+        .with(env_filter)
+        .with(otel_layer)
+        .with(layers);
+
+    Ok((Box::new(registry), guard))
+}
+
+/// 설정에 따라 마스킹을 적용한 출력 레이어를 만듭니다.
+fn output_layer<S, W>(
+    config: &LoggingConfig,
+    writer: W,
+    ansi: bool,
+) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    if config.filter_sensitive {
+        fmt_layer(
+            config.json_format,
+            redact::RedactingMakeWriter::new(writer),
+            ansi,
+        )
+    } else {
+        fmt_layer(config.json_format, writer, ansi)
+    }
+}
+
+/// 출력 형식에 맞는 fmt 레이어를 만듭니다.
+fn fmt_layer<S, W>(json: bool, writer: W, ansi: bool) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    if json {
+        // JSON 형태 출력
+        tracing_subscriber::fmt::layer()
+            .json()
+            .with_current_span(true)
+            .with_span_list(true)
+            .with_writer(writer)
+            .boxed()
+    } else {
+        // 일반 텍스트 출력
+        tracing_subscriber::fmt::layer()
+            .with_target(true)
+            .with_thread_ids(true)
+            .with_file(true)
+            .with_line_number(true)
+            .with_ansi(ansi)
+            .with_writer(writer)
+            .boxed()
+    }
+}
+
+/// subscriber가 살아 있는 동안 파일 writer의 작업 핸들을 보관하는 레이어
+struct GuardLayer {
+    _guard: WorkerGuard,
+}
+
+impl<S: Subscriber> Layer<S> for GuardLayer {}
+
+/// 로깅 초기화
+pub fn init_logging(config: &LoggingConfig) -> Result<(), Box<dyn std::error::Error>> {
+    // 테스트 환경에서는 초기화 실패를 무시
+    #[cfg(test)]
+    {
+        if let Err(e) = do_init_logging(config) {
+            // 이미 초기화된 경우는 무시
+            if e.to_string().contains("subscriber") || e.to_string().contains("already been set") {
+                return Ok(());
+            }
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    #[cfg(not(test))]
+    do_init_logging(config)
+}
+
+/// 실제 초기화 로직
+fn do_init_logging(config: &LoggingConfig) -> Result<(), Box<dyn std::error::Error>> {
+    let (subscriber, guard) = build(config)?;
+    subscriber.try_init()?;
+    redact::set_enabled(config.filter_sensitive);
+    *FILE_GUARD.lock().unwrap_or_else(|e| e.into_inner()) = guard;
+
+    info!("krx-rs logging initialized with level: {}", config.level);
+    Ok(())
+}
+
+/// 버퍼에 남은 로그를 기록하고 백그라운드 작업을 종료합니다.
+///
+/// 파일 로그와 OpenTelemetry span은 백그라운드에서 기록되므로, 프로그램 종료 직전에
+/// 호출하면 마지막 이벤트까지 남길 수 있습니다. 호출 이후의 파일 로그는 기록되지 않습니다.
+pub fn shutdown() {
+    drop(FILE_GUARD.lock().unwrap_or_else(|e| e.into_inner()).take());
+
+    #[cfg(feature = "otel")]
+    otel::shutdown();
+}
+
+/// 민감한 정보 필터링 매크로
+#[macro_export]
+macro_rules! log_filtered {
+    ($level:ident, $msg:expr, $($key:ident = $value:expr),*) => {
+        $level!(
+            message = $msg,
+            $(
+                $key = if stringify!($key).contains("key") ||
+                         stringify!($key).contains("auth") ||
+                         stringify!($key).contains("token") {
+                    "***FILTERED***"
+                } else {
+                    &$value.to_string()
+                }
+            ),*
+        );
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_subscriber_with_json_format() {
+        let config = LoggingConfig {
+            level: "debug".to_string(),
+            json_format: true,
+            filter_sensitive: false,
+            file_path: None,
+            ..Default::default()
+        };
+
+        let result = build_subscriber(&config);
+        assert!(result.is_ok(), "Should successfully build JSON subscriber");
+    }
+
+    #[test]
+    fn test_build_subscriber_with_text_format() {
+        let config = LoggingConfig {
+            level: "info".to_string(),
+            json_format: false,
+            filter_sensitive: true,
+            file_path: None,
+            ..Default::default()
+        };
+
+        let result = build_subscriber(&config);
+        assert!(result.is_ok(), "Should successfully build text subscriber");
+    }
+
+    #[test]
+    fn test_build_subscriber_with_invalid_level() {
+        let config = LoggingConfig {
+            level: "invalid[[[level".to_string(), // Invalid filter syntax
+            json_format: false,
+            filter_sensitive: false,
+            file_path: None,
+            ..Default::default()
+        };
+
+        let result = build_subscriber(&config);
+        assert!(result.is_err(), "Should fail with invalid log level");
+    }
+
+    #[cfg(not(feature = "otel"))]
+    #[test]
+    fn test_build_subscriber_otlp_requires_feature() {
+        let config = LoggingConfig {
+            otlp: Some(OtlpConfig::default()),
+            ..Default::default()
+        };
+
+        assert!(build_subscriber(&config).is_err());
+    }
+
+    #[test]
+    fn test_build_subscriber_writes_json_to_file() {
+        let dir = std::env::temp_dir().join(format!("krx-log-json-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("krx.log");
+        let config = LoggingConfig {
+            json_format: true,
+            file_path: Some(path.to_string_lossy().into_owned()),
+            stdout: false,
+            ..Default::default()
+        };
+
+        let subscriber = build_subscriber(&config).unwrap();
+        tracing::subscriber::with_default(subscriber, || {
+            info!(endpoint = "/sto/stk_bydd_trd", "file logging works");
+        });
+
+        // subscriber가 drop되면서 남은 로그가 기록됩니다.
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let line: serde_json::Value =
+            serde_json::from_str(contents.lines().next().unwrap()).unwrap();
+        assert_eq!(line["fields"]["message"], "file logging works");
+        assert_eq!(line["fields"]["endpoint"], "/sto/stk_bydd_trd");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_init_logging_handles_multiple_calls() {
+        let config = LoggingConfig::default();
+
+        // First call
+        let result1 = init_logging(&config);
+        assert!(result1.is_ok(), "First init should succeed");
+
+        // Second call - should also succeed in test environment
+        let result2 = init_logging(&config);
+        assert!(
+            result2.is_ok(),
+            "Second init should succeed in test environment"
+        );
+    }
+}
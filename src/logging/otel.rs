@@ -0,0 +1,101 @@
+//! OpenTelemetry 트레이스 내보내기.
+//!
+//! `Client`의 모든 API 호출은 `get` span을 만들며, 이 모듈의 레이어를 통해
+//! OpenTelemetry span으로 내보내집니다. span에는 다음 속성이 기록됩니다.
+//!
+//! | 속성            | 내용                                        |
+//! |-----------------|---------------------------------------------|
+//! | `endpoint`      | API 경로 (예: `/sto/stk_bydd_trd`)          |
+//! | `bas_dd`        | 기준일자 (`basDd` 파라미터)                 |
+//! | `status`        | HTTP 상태 코드 또는 `network_error`         |
+//! | `rows`          | 파싱된 레코드 수                            |
+//! | `retry_attempt` | 재시도 순번 (첫 시도는 0)                   |
+//!
+//! [`LoggingConfig::otlp`](super::LoggingConfig::otlp)를 설정하면
+//! [`init_logging`](super::init_logging)이 OTLP(HTTP/protobuf) 내보내기를 구성합니다.
+//! 다른 exporter를 쓰려면 [`layer_with_provider`]로 레이어를 직접 만들 수 있습니다.
+//!
+//! # 예시
+//! ```rust,no_run
+//! use krx_rs::logging::{self, LoggingConfig, OtlpConfig};
+//!
+//! let config = LoggingConfig {
+//!     otlp: Some(OtlpConfig {
+//!         endpoint: "http://collector:4318/v1/traces".to_string(),
+//!         service_name: "krx-collector".to_string(),
+//!     }),
+//!     ..Default::default()
+//! };
+//! logging::init_logging(&config).unwrap();
+//!
+//! // 프로그램 종료 전에 남은 span을 내보냅니다.
+//! logging::shutdown();
+//! ```
+
+use super::OtlpConfig;
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{
+    Resource,
+    trace::{SdkTracer, SdkTracerProvider},
+};
+use std::sync::OnceLock;
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::registry::LookupSpan;
+
+/// 트레이서 이름 (instrumentation scope)
+const TRACER_NAME: &str = "krx-rs";
+
+/// [`init_logging`](super::init_logging)이 설치한 OTLP 트레이서 프로바이더
+static PROVIDER: OnceLock<SdkTracerProvider> = OnceLock::new();
+
+/// 주어진 트레이서 프로바이더로 span을 내보내는 레이어를 만듭니다.
+///
+/// 테스트에서는 `opentelemetry_sdk`의 `InMemorySpanExporter`를 사용하는
+/// 프로바이더를 넘겨 내보낸 span을 확인할 수 있습니다.
+pub fn layer_with_provider<S>(provider: &SdkTracerProvider) -> OpenTelemetryLayer<S, SdkTracer>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    tracing_opentelemetry::layer().with_tracer(provider.tracer(TRACER_NAME))
+}
+
+/// OTLP 설정으로 트레이서 프로바이더를 만들고 레이어를 반환합니다.
+pub(crate) fn layer<S>(
+    config: &OtlpConfig,
+) -> Result<OpenTelemetryLayer<S, SdkTracer>, Box<dyn std::error::Error>>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .with_endpoint(&config.endpoint)
+        .build()?;
+
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(
+            Resource::builder()
+                .with_service_name(config.service_name.clone())
+                .build(),
+        )
+        .build();
+
+    let layer = layer_with_provider(&provider);
+    // 구독자는 프로세스당 한 번만 설치되므로 처음 만든 프로바이더만 보관합니다.
+    let _ = PROVIDER.set(provider);
+    Ok(layer)
+}
+
+/// 버퍼에 남은 span을 내보내고 OTLP 프로바이더를 종료합니다.
+///
+/// [`init_logging`](super::init_logging)으로 OTLP를 구성하지 않았다면 아무 일도 하지 않습니다.
+/// 파일 로그까지 함께 정리하려면 [`super::shutdown`]을 사용하세요.
+pub fn shutdown() {
+    if let Some(provider) = PROVIDER.get() {
+        if let Err(e) = provider.shutdown() {
+            eprintln!("Warning: Failed to shut down OpenTelemetry provider: {e}");
+        }
+    }
+}
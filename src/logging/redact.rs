@@ -0,0 +1,232 @@
+//! 민감한 정보 마스킹.
+//!
+//! [`LoggingConfig::filter_sensitive`](super::LoggingConfig::filter_sensitive)가 켜져 있으면
+//! 모든 로그 출력(표준 출력, 파일)과 [`Error`](crate::Error)에 담기는 응답 본문·오류 메시지에서
+//! 다음 항목을 [`MASK`]로 바꿉니다.
+//!
+//! - 클라이언트가 [인증키 공급자](crate::credentials)에서 불러온 모든 인증키
+//! - `AUTH_KEY` 헤더 값 형태의 문자열
+//! - [`LoggingConfig::redact_patterns`](super::LoggingConfig::redact_patterns)로 추가한 정규식
+//!
+//! 인증키는 `AUTH_KEY` 헤더에 민감 값으로 표시되므로 reqwest의 요청 Debug 출력에도 노출되지 않습니다.
+
+use regex::Regex;
+use std::{
+    borrow::Cow,
+    io::{self, Write},
+    sync::{
+        OnceLock, RwLock,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+use tracing_subscriber::fmt::MakeWriter;
+
+/// 마스킹된 값을 대신하는 문자열
+pub const MASK: &str = "***FILTERED***";
+
+/// 이보다 짧은 비밀값은 일반 문자열과 구분할 수 없으므로 등록하지 않습니다.
+const MIN_SECRET_LEN: usize = 8;
+
+/// `AUTH_KEY` 헤더 값 형태 (`AUTH_KEY: xxx`, `"auth_key": "xxx"`, `AUTH_KEY=xxx`)
+const AUTH_KEY_PATTERN: &str = r#"(?i)(auth_key"?\s*[:=]\s*"?)[^\s",}&]+"#;
+
+/// 오류 메시지 마스킹 여부 (`filter_sensitive` 기본값과 동일하게 켜진 상태로 시작)
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+static REDACTOR: RwLock<Redactor> = RwLock::new(Redactor::new());
+
+/// 비밀값과 정규식 패턴 목록
+#[derive(Debug, Default)]
+struct Redactor {
+    secrets: Vec<String>,
+    patterns: Vec<Regex>,
+}
+
+impl Redactor {
+    const fn new() -> Self {
+        Self {
+            secrets: Vec::new(),
+            patterns: Vec::new(),
+        }
+    }
+
+    fn add_secret(&mut self, secret: &str) {
+        if secret.len() >= MIN_SECRET_LEN && !self.secrets.iter().any(|s| s == secret) {
+            self.secrets.push(secret.to_string());
+        }
+    }
+
+    fn add_pattern(&mut self, pattern: Regex) {
+        if !self.patterns.iter().any(|p| p.as_str() == pattern.as_str()) {
+            self.patterns.push(pattern);
+        }
+    }
+
+    fn redact<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let mut text = Cow::Borrowed(text);
+        for secret in &self.secrets {
+            if text.contains(secret.as_str()) {
+                text = Cow::Owned(text.replace(secret.as_str(), MASK));
+            }
+        }
+        if let Cow::Owned(replaced) = auth_key_pattern().replace_all(&text, format!("${{1}}{MASK}"))
+        {
+            text = Cow::Owned(replaced);
+        }
+        for pattern in &self.patterns {
+            if let Cow::Owned(replaced) = pattern.replace_all(&text, MASK) {
+                text = Cow::Owned(replaced);
+            }
+        }
+        text
+    }
+}
+
+fn auth_key_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| Regex::new(AUTH_KEY_PATTERN).expect("valid auth key pattern"))
+}
+
+/// 마스킹할 비밀값(예: 인증키)을 등록합니다.
+///
+/// 8자 미만의 값은 무시됩니다.
+pub fn add_secret(secret: &str) {
+    REDACTOR
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .add_secret(secret);
+}
+
+/// 마스킹할 정규식 패턴을 등록합니다. 일치하는 부분 전체가 [`MASK`]로 바뀝니다.
+pub fn add_pattern(pattern: &str) -> Result<(), regex::Error> {
+    let pattern = Regex::new(pattern)?;
+    REDACTOR
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .add_pattern(pattern);
+    Ok(())
+}
+
+/// 등록된 비밀값과 패턴을 마스킹합니다. 마스킹이 꺼져 있으면 그대로 반환합니다.
+pub fn redact(text: &str) -> Cow<'_, str> {
+    if !is_enabled() {
+        return Cow::Borrowed(text);
+    }
+    redact_always(text)
+}
+
+/// 오류에 담을 문자열을 마스킹합니다.
+pub(crate) fn redact_owned(text: String) -> String {
+    match redact(&text) {
+        Cow::Borrowed(_) => text,
+        Cow::Owned(redacted) => redacted,
+    }
+}
+
+fn redact_always(text: &str) -> Cow<'_, str> {
+    REDACTOR
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .redact(text)
+}
+
+/// 마스킹 사용 여부
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub(crate) fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// 출력 전에 마스킹을 적용하는 [`MakeWriter`]
+pub(crate) struct RedactingMakeWriter<M> {
+    inner: M,
+}
+
+impl<M> RedactingMakeWriter<M> {
+    pub(crate) fn new(inner: M) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'a, M: MakeWriter<'a>> MakeWriter<'a> for RedactingMakeWriter<M> {
+    type Writer = RedactingWriter<M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RedactingWriter {
+            inner: self.inner.make_writer(),
+        }
+    }
+}
+
+/// fmt 레이어가 이벤트 한 건을 한 번에 기록하는 점을 이용해 기록 단위로 마스킹합니다.
+pub(crate) struct RedactingWriter<W> {
+    inner: W,
+}
+
+impl<W: Write> Write for RedactingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match std::str::from_utf8(buf) {
+            Ok(text) => {
+                self.inner.write_all(redact_always(text).as_bytes())?;
+                Ok(buf.len())
+            }
+            Err(_) => self.inner.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_secrets_and_patterns() {
+        let mut redactor = Redactor::new();
+        redactor.add_secret("abcd1234efgh5678");
+        redactor.add_secret("short");
+        redactor.add_pattern(Regex::new(r"\d{6}-\d{7}").unwrap());
+
+        assert_eq!(
+            redactor.redact("key=abcd1234efgh5678 id=900101-1234567"),
+            format!("key={MASK} id={MASK}")
+        );
+        assert_eq!(redactor.redact("short text"), "short text");
+        assert!(matches!(redactor.redact("nothing here"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_redact_auth_key_header_forms() {
+        let redactor = Redactor::new();
+
+        assert_eq!(
+            redactor.redact(r#"{"auth_key": "zzz", "content-type": "application/json"}"#),
+            format!(r#"{{"auth_key": "{MASK}", "content-type": "application/json"}}"#)
+        );
+        assert_eq!(
+            redactor.redact("AUTH_KEY: zzz basDd=20240105"),
+            format!("AUTH_KEY: {MASK} basDd=20240105")
+        );
+    }
+
+    #[test]
+    fn test_redacting_writer() {
+        add_secret("writer-secret-key");
+        let mut out = Vec::new();
+        {
+            let mut writer = RedactingWriter { inner: &mut out };
+            writer
+                .write_all(b"request with writer-secret-key failed\n")
+                .unwrap();
+        }
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("request with {MASK} failed\n")
+        );
+    }
+}
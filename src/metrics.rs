@@ -0,0 +1,341 @@
+//! 클라이언트 요청 지표 수집.
+//!
+//! [`Client`](crate::Client)는 모든 API 호출마다 엔드포인트별 요청 수(상태 코드별),
+//! 지연 시간 히스토그램, 재시도·속도 제한 횟수, 응답 바이트 수, 파싱된 행 수를
+//! 내부 레지스트리에 기록합니다. [`Client::stats`](crate::Client::stats)로 스냅샷을 얻고,
+//! [`ClientStats::to_prometheus`]로 Prometheus 텍스트 형식으로 내보낼 수 있습니다.
+//!
+//! # 예시
+//! ```rust,no_run
+//! # use krx_rs::Client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), krx_rs::error::Error> {
+//! let client = Client::new("YOUR_AUTH_KEY");
+//! client.stock().stock_daily().date("20240105").fetch().await?;
+//!
+//! let stats = client.stats();
+//! println!("requests: {}", stats.total_requests());
+//! println!("{}", stats.to_prometheus());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{collections::BTreeMap, fmt::Write, sync::Mutex, time::Duration};
+
+/// 지연 시간 히스토그램 버킷 상한 (초)
+pub const LATENCY_BUCKETS: [f64; 10] = [0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0];
+
+/// 네트워크 오류로 상태 코드가 없는 요청의 상태 라벨
+pub const NETWORK_ERROR_STATUS: &str = "network_error";
+
+/// 엔드포인트 지표에서 카운터 값을 읽는 함수
+type CounterFn = fn(&EndpointStats) -> u64;
+
+/// 지연 시간 히스토그램
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    /// [`LATENCY_BUCKETS`] 각 구간에 속한 관측 수 (누적 아님)
+    pub buckets: [u64; LATENCY_BUCKETS.len()],
+    /// 가장 큰 버킷보다 큰 관측 수
+    pub overflow: u64,
+    /// 관측값 합계 (초)
+    pub sum: f64,
+    /// 관측 수
+    pub count: u64,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self {
+            buckets: [0; LATENCY_BUCKETS.len()],
+            overflow: 0,
+            sum: 0.0,
+            count: 0,
+        }
+    }
+}
+
+impl Histogram {
+    fn observe(&mut self, duration: Duration) {
+        let seconds = duration.as_secs_f64();
+        match LATENCY_BUCKETS.iter().position(|bound| seconds <= *bound) {
+            Some(i) => self.buckets[i] += 1,
+            None => self.overflow += 1,
+        }
+        self.sum += seconds;
+        self.count += 1;
+    }
+
+    /// 평균 지연 시간
+    pub fn mean(&self) -> Option<Duration> {
+        (self.count > 0).then(|| Duration::from_secs_f64(self.sum / self.count as f64))
+    }
+}
+
+/// 엔드포인트 하나의 누적 지표
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EndpointStats {
+    /// 상태 코드별 요청 수 (네트워크 오류는 [`NETWORK_ERROR_STATUS`])
+    pub requests: BTreeMap<String, u64>,
+    /// 응답 지연 시간
+    pub latency: Histogram,
+    /// 재시도 횟수 (인증키 풀의 다른 키로 다시 보낸 요청)
+    pub retries: u64,
+    /// 속도 제한(429) 응답 수
+    pub rate_limited: u64,
+    /// 응답 파싱 실패 수
+    pub parse_errors: u64,
+    /// 수신한 응답 본문 바이트 수
+    pub response_bytes: u64,
+    /// 파싱된 레코드(행) 수
+    pub rows: u64,
+}
+
+impl EndpointStats {
+    /// 전체 요청 수
+    pub fn total_requests(&self) -> u64 {
+        self.requests.values().sum()
+    }
+
+    /// 2xx가 아닌 응답과 네트워크 오류 수
+    pub fn failed_requests(&self) -> u64 {
+        self.requests
+            .iter()
+            .filter(|(status, _)| !status.starts_with('2'))
+            .map(|(_, count)| count)
+            .sum()
+    }
+}
+
+/// [`Client::stats`](crate::Client::stats)가 반환하는 지표 스냅샷
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClientStats {
+    /// 엔드포인트 경로별 지표
+    pub endpoints: BTreeMap<String, EndpointStats>,
+}
+
+impl ClientStats {
+    /// 전체 요청 수
+    pub fn total_requests(&self) -> u64 {
+        self.endpoints
+            .values()
+            .map(EndpointStats::total_requests)
+            .sum()
+    }
+
+    /// 전체 실패 요청 수
+    pub fn failed_requests(&self) -> u64 {
+        self.endpoints
+            .values()
+            .map(EndpointStats::failed_requests)
+            .sum()
+    }
+
+    /// 전체 속도 제한 응답 수
+    pub fn rate_limited(&self) -> u64 {
+        self.endpoints.values().map(|s| s.rate_limited).sum()
+    }
+
+    /// 전체 재시도 횟수
+    pub fn retries(&self) -> u64 {
+        self.endpoints.values().map(|s| s.retries).sum()
+    }
+
+    /// 전체 파싱된 행 수
+    pub fn rows(&self) -> u64 {
+        self.endpoints.values().map(|s| s.rows).sum()
+    }
+
+    /// Prometheus 텍스트 노출 형식(0.0.4)으로 변환합니다.
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("# HELP krx_requests_total KRX API requests by endpoint and status.\n");
+        out.push_str("# TYPE krx_requests_total counter\n");
+        for (endpoint, stats) in &self.endpoints {
+            for (status, count) in &stats.requests {
+                let _ = writeln!(
+                    out,
+                    "krx_requests_total{{endpoint=\"{endpoint}\",status=\"{status}\"}} {count}"
+                );
+            }
+        }
+
+        out.push_str("# HELP krx_request_duration_seconds KRX API request latency.\n");
+        out.push_str("# TYPE krx_request_duration_seconds histogram\n");
+        for (endpoint, stats) in &self.endpoints {
+            let histogram = &stats.latency;
+            let mut cumulative = 0;
+            for (bound, count) in LATENCY_BUCKETS.iter().zip(histogram.buckets) {
+                cumulative += count;
+                let _ = writeln!(
+                    out,
+                    "krx_request_duration_seconds_bucket{{endpoint=\"{endpoint}\",le=\"{bound}\"}} {cumulative}"
+                );
+            }
+            let _ = writeln!(
+                out,
+                "krx_request_duration_seconds_bucket{{endpoint=\"{endpoint}\",le=\"+Inf\"}} {}",
+                histogram.count
+            );
+            let _ = writeln!(
+                out,
+                "krx_request_duration_seconds_sum{{endpoint=\"{endpoint}\"}} {}",
+                histogram.sum
+            );
+            let _ = writeln!(
+                out,
+                "krx_request_duration_seconds_count{{endpoint=\"{endpoint}\"}} {}",
+                histogram.count
+            );
+        }
+
+        let counters: [(&str, &str, CounterFn); 5] = [
+            ("krx_retries_total", "KRX API request retries.", |s| {
+                s.retries
+            }),
+            (
+                "krx_rate_limited_total",
+                "KRX API rate-limited responses.",
+                |s| s.rate_limited,
+            ),
+            (
+                "krx_parse_errors_total",
+                "KRX API responses that failed to parse.",
+                |s| s.parse_errors,
+            ),
+            (
+                "krx_response_bytes_total",
+                "KRX API response body bytes.",
+                |s| s.response_bytes,
+            ),
+            (
+                "krx_rows_total",
+                "Records parsed from KRX API responses.",
+                |s| s.rows,
+            ),
+        ];
+        for (name, help, value) in counters {
+            let _ = writeln!(out, "# HELP {name} {help}");
+            let _ = writeln!(out, "# TYPE {name} counter");
+            for (endpoint, stats) in &self.endpoints {
+                let _ = writeln!(out, "{name}{{endpoint=\"{endpoint}\"}} {}", value(stats));
+            }
+        }
+
+        out
+    }
+}
+
+/// 클라이언트 내부 지표 레지스트리
+#[derive(Debug, Default)]
+pub(crate) struct Metrics {
+    endpoints: Mutex<BTreeMap<String, EndpointStats>>,
+}
+
+impl Metrics {
+    fn update(&self, endpoint: &str, f: impl FnOnce(&mut EndpointStats)) {
+        let mut endpoints = self.endpoints.lock().unwrap_or_else(|e| e.into_inner());
+        f(endpoints.entry(endpoint.to_string()).or_default());
+    }
+
+    /// 응답 상태와 지연 시간을 기록합니다.
+    pub(crate) fn record_response(&self, endpoint: &str, status: &str, duration: Duration) {
+        self.update(endpoint, |stats| {
+            *stats.requests.entry(status.to_string()).or_default() += 1;
+            stats.latency.observe(duration);
+            if status == "429" {
+                stats.rate_limited += 1;
+            }
+        });
+    }
+
+    /// 수신한 응답 본문 크기를 기록합니다.
+    pub(crate) fn record_body(&self, endpoint: &str, bytes: usize) {
+        self.update(endpoint, |stats| stats.response_bytes += bytes as u64);
+    }
+
+    /// 재시도를 기록합니다.
+    pub(crate) fn record_retry(&self, endpoint: &str) {
+        self.update(endpoint, |stats| stats.retries += 1);
+    }
+
+    /// 파싱 결과를 기록합니다. `rows`가 `None`이면 파싱 실패입니다.
+    pub(crate) fn record_rows(&self, endpoint: &str, rows: Option<usize>) {
+        self.update(endpoint, |stats| match rows {
+            Some(rows) => stats.rows += rows as u64,
+            None => stats.parse_errors += 1,
+        });
+    }
+
+    pub(crate) fn snapshot(&self) -> ClientStats {
+        let endpoints = self.endpoints.lock().unwrap_or_else(|e| e.into_inner());
+        ClientStats {
+            endpoints: endpoints.clone(),
+        }
+    }
+
+    pub(crate) fn reset(&self) {
+        self.endpoints
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_and_snapshot() {
+        let metrics = Metrics::default();
+        metrics.record_response("/sto/stk_bydd_trd", "200", Duration::from_millis(30));
+        metrics.record_body("/sto/stk_bydd_trd", 1024);
+        metrics.record_rows("/sto/stk_bydd_trd", Some(950));
+        metrics.record_response("/sto/stk_bydd_trd", "429", Duration::from_millis(700));
+        metrics.record_response(
+            "/idx/krx_dd_trd",
+            NETWORK_ERROR_STATUS,
+            Duration::from_secs(90),
+        );
+        metrics.record_rows("/idx/krx_dd_trd", None);
+
+        let stats = metrics.snapshot();
+        assert_eq!(stats.total_requests(), 3);
+        assert_eq!(stats.failed_requests(), 2);
+        assert_eq!(stats.rate_limited(), 1);
+        assert_eq!(stats.rows(), 950);
+
+        let stock = &stats.endpoints["/sto/stk_bydd_trd"];
+        assert_eq!(stock.response_bytes, 1024);
+        assert_eq!(stock.latency.buckets[0], 1);
+        assert_eq!(stock.latency.buckets[4], 1);
+        assert_eq!(stats.endpoints["/idx/krx_dd_trd"].latency.overflow, 1);
+        assert_eq!(stats.endpoints["/idx/krx_dd_trd"].parse_errors, 1);
+
+        metrics.reset();
+        assert_eq!(metrics.snapshot().total_requests(), 0);
+    }
+
+    #[test]
+    fn test_to_prometheus() {
+        let metrics = Metrics::default();
+        metrics.record_response("/sto/stk_bydd_trd", "200", Duration::from_millis(200));
+        metrics.record_response("/sto/stk_bydd_trd", "200", Duration::from_millis(80));
+        metrics.record_rows("/sto/stk_bydd_trd", Some(10));
+
+        let text = metrics.snapshot().to_prometheus();
+        assert!(
+            text.contains("krx_requests_total{endpoint=\"/sto/stk_bydd_trd\",status=\"200\"} 2")
+        );
+        assert!(text.contains(
+            "krx_request_duration_seconds_bucket{endpoint=\"/sto/stk_bydd_trd\",le=\"0.1\"} 1"
+        ));
+        assert!(text.contains(
+            "krx_request_duration_seconds_bucket{endpoint=\"/sto/stk_bydd_trd\",le=\"0.25\"} 2"
+        ));
+        assert!(text.contains("krx_rows_total{endpoint=\"/sto/stk_bydd_trd\"} 10"));
+        assert!(text.contains("# TYPE krx_request_duration_seconds histogram"));
+    }
+}
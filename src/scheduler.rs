@@ -0,0 +1,545 @@
+//! 장 마감 후 데이터를 매일 수집하는 스케줄러(데몬).
+//!
+//! KRX는 거래일(T)의 데이터를 다음 날 아침에 공개합니다. [`Scheduler`]는
+//! [`TradingCalendar`]와 한국 시간(Asia/Seoul, UTC+9)의 공개 시각을 기준으로
+//! 공개된 거래일을 계산하고, 엔드포인트마다 데이터가 채워질 때까지 조회를 반복한 뒤
+//! 결과를 [`Sink`]에 넘깁니다. 공개 대기는 [`AvailabilityChecker`]가 맡습니다.
+//!
+//! - 실행할 때마다 최근 `catch_up_days` 거래일 중 싱크에 없는 날짜를 모두 처리하므로,
+//!   중단되었던 기간은 다음 실행에서 자동으로 따라잡습니다.
+//! - 작업은 `concurrency`개까지 동시에 조회하므로, 아직 비어 있는 엔드포인트가 다른
+//!   엔드포인트의 수집을 막지 않습니다. 싱크 저장은 조회가 끝난 순서대로 이루어집니다.
+//! - 공개 시각부터 `poll_timeout`이 지나도 비어 있는 날짜는 휴장일로 보고 건너뜁니다.
+//! - 요청 실패는 지수 백오프로 `max_retries`번까지 다시 시도하며, 그래도 실패한
+//!   날짜는 `failure_delay` 뒤 다음 실행에서 다시 처리합니다.
+//! - 처리 상태는 `tracing` 로그와 [`RunReport`]로 남습니다.
+//!
+//! `sqlite`, `parquet` feature를 켜면 [`SqliteStore`](crate::store)와
+//! [`ParquetLake`](crate::store)도 싱크로 사용할 수 있습니다.
+//!
+//! # 예시
+//! ```rust,no_run
+//! use chrono::NaiveTime;
+//! use krx_rs::{
+//!     Client, DataFrame,
+//!     api::endpoint::{KosdaqDaily, StockDaily},
+//!     scheduler::Scheduler,
+//!     store::Dataset,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), krx_rs::error::Error> {
+//! let client = Client::new("YOUR_AUTH_KEY");
+//! let sink = |dataset: &Dataset, date: &str, df: &DataFrame| -> krx_rs::Result<()> {
+//!     println!("{} {date}: {} rows", dataset.name(), df.height());
+//!     Ok(())
+//! };
+//!
+//! Scheduler::new(client, sink)
+//!     .datasets([Dataset::of::<StockDaily>(), Dataset::of::<KosdaqDaily>()])
+//!     .publish_time(NaiveTime::from_hms_opt(8, 0, 0).unwrap())
+//!     .run()
+//!     .await
+//! # }
+//! ```
+
+pub use crate::calendar::{DEFAULT_PUBLISH_TIME, seoul};
+use crate::{
+    api::endpoint,
+    availability::AvailabilityChecker,
+    calendar::{TradingCalendar, parse_date, publication_time},
+    client::Client,
+    error::{Error, Result},
+    store::Dataset,
+};
+use chrono::{DateTime, Days, FixedOffset, NaiveDate, NaiveTime, Utc};
+use futures::stream::{self, StreamExt};
+use polars::prelude::DataFrame;
+use std::{
+    collections::HashSet,
+    time::{Duration, Instant},
+};
+use tracing::{error, info, warn};
+
+/// 기본 동시 작업 수
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// 수집한 데이터를 저장하는 대상
+pub trait Sink: Send {
+    /// 한 기준일자(YYYYMMDD)의 데이터를 저장합니다.
+    fn write(&mut self, dataset: &Dataset, date: &str, df: &DataFrame) -> Result<()>;
+
+    /// 이미 저장된 기준일자인지 확인합니다. (기본값: 항상 `false`)
+    ///
+    /// `true`를 반환하면 스케줄러가 해당 날짜를 다시 조회하지 않습니다.
+    fn contains(&self, _dataset: &Dataset, _date: &str) -> Result<bool> {
+        Ok(false)
+    }
+}
+
+impl<F> Sink for F
+where
+    F: FnMut(&Dataset, &str, &DataFrame) -> Result<()> + Send,
+{
+    fn write(&mut self, dataset: &Dataset, date: &str, df: &DataFrame) -> Result<()> {
+        self(dataset, date, df)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl Sink for crate::store::sqlite::SqliteStore {
+    fn write(&mut self, dataset: &Dataset, date: &str, df: &DataFrame) -> Result<()> {
+        crate::store::sqlite::SqliteStore::write(self, dataset, date, df).map(|_| ())
+    }
+
+    fn contains(&self, dataset: &Dataset, date: &str) -> Result<bool> {
+        Ok(self
+            .synced_dates(dataset)?
+            .iter()
+            .any(|synced| synced == date))
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl Sink for crate::store::lake::ParquetLake {
+    fn write(&mut self, dataset: &Dataset, date: &str, df: &DataFrame) -> Result<()> {
+        crate::store::lake::ParquetLake::write(self, dataset, date, df).map(|_| ())
+    }
+
+    fn contains(&self, dataset: &Dataset, date: &str) -> Result<bool> {
+        Ok(self.partition_path(dataset, date)?.exists())
+    }
+}
+
+/// 한 `(엔드포인트, 기준일자)` 작업의 처리 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JobStatus {
+    /// 데이터를 싱크에 저장함
+    Written {
+        /// 저장한 행 수
+        rows: usize,
+    },
+    /// 대기 시간이 지나도 데이터가 없어 건너뜀 (휴장일로 간주)
+    Empty,
+    /// 재시도 후에도 조회 또는 저장에 실패함
+    Failed(String),
+}
+
+/// 한 작업의 처리 기록
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobReport {
+    /// 엔드포인트 이름 (예: `sto/stk_bydd_trd`)
+    pub dataset: &'static str,
+    /// 기준일자 (YYYYMMDD)
+    pub date: String,
+    /// 처리 결과
+    pub status: JobStatus,
+}
+
+/// [`Scheduler::run_once`] 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunReport {
+    /// 처리한 작업 (기준일자, 엔드포인트 순)
+    pub jobs: Vec<JobReport>,
+}
+
+impl RunReport {
+    /// 실패한 작업이 있는지 여부
+    pub fn has_failures(&self) -> bool {
+        self.jobs
+            .iter()
+            .any(|job| matches!(job.status, JobStatus::Failed(_)))
+    }
+
+    /// 저장한 전체 행 수
+    pub fn rows(&self) -> usize {
+        self.jobs
+            .iter()
+            .map(|job| match job.status {
+                JobStatus::Written { rows } => rows,
+                _ => 0,
+            })
+            .sum()
+    }
+}
+
+/// 일별 수집 스케줄러
+#[must_use = "Scheduler does nothing unless you call .run() or .run_once()"]
+pub struct Scheduler<S> {
+    client: Client,
+    sink: S,
+    datasets: Vec<Dataset>,
+    calendar: TradingCalendar,
+    publish_time: NaiveTime,
+    catch_up_days: usize,
+    concurrency: usize,
+    poll_interval: Duration,
+    poll_timeout: Duration,
+    max_retries: u32,
+    retry_backoff: Duration,
+    failure_delay: Duration,
+    done: HashSet<(&'static str, String)>,
+}
+
+impl<S: Sink> Scheduler<S> {
+    /// 모든 엔드포인트([`endpoint::ALL`])를 수집하는 스케줄러를 만듭니다.
+    pub fn new(client: Client, sink: S) -> Self {
+        Self {
+            client,
+            sink,
+            datasets: endpoint::ALL.to_vec(),
+            calendar: TradingCalendar::new(),
+            publish_time: DEFAULT_PUBLISH_TIME,
+            catch_up_days: 5,
+            concurrency: DEFAULT_CONCURRENCY,
+            poll_interval: Duration::from_secs(10 * 60),
+            poll_timeout: Duration::from_secs(6 * 60 * 60),
+            max_retries: 3,
+            retry_backoff: Duration::from_secs(30),
+            failure_delay: Duration::from_secs(15 * 60),
+            done: HashSet::new(),
+        }
+    }
+
+    /// 수집할 엔드포인트
+    pub fn datasets(mut self, datasets: impl IntoIterator<Item = Dataset>) -> Self {
+        self.datasets = datasets.into_iter().collect();
+        self
+    }
+
+    /// 거래일 달력 (기본값: [`TradingCalendar::new`], 내장 KRX 휴장일표 포함)
+    pub fn calendar(mut self, calendar: TradingCalendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// 전 거래일 데이터가 공개되는 한국 시각 (기본값: 08:00)
+    pub fn publish_time(mut self, time: NaiveTime) -> Self {
+        self.publish_time = time;
+        self
+    }
+
+    /// 실행할 때마다 확인할 최근 거래일 수 (기본값: 5, 최소 1)
+    pub fn catch_up_days(mut self, days: usize) -> Self {
+        self.catch_up_days = days.max(1);
+        self
+    }
+
+    /// 동시에 처리할 최대 작업 수 (기본값: [`DEFAULT_CONCURRENCY`], 최소 1)
+    pub fn concurrency(mut self, limit: usize) -> Self {
+        self.concurrency = limit.max(1);
+        self
+    }
+
+    /// 데이터가 비어 있을 때 다시 조회하기까지의 간격 (기본값: 10분)
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// 공개 시각 이후 빈 응답을 기다리는 최대 시간 (기본값: 6시간)
+    pub fn poll_timeout(mut self, timeout: Duration) -> Self {
+        self.poll_timeout = timeout;
+        self
+    }
+
+    /// 요청 실패 시 재시도 횟수와 첫 재시도 대기 시간 (기본값: 3회, 30초부터 두 배씩)
+    pub fn retries(mut self, max_retries: u32, backoff: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// 실패한 작업이 있을 때 다음 실행까지 기다리는 시간 (기본값: 15분)
+    pub fn failure_delay(mut self, delay: Duration) -> Self {
+        self.failure_delay = delay;
+        self
+    }
+
+    /// 데이터를 넘겨받는 싱크
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// 중단될 때까지 수집을 반복합니다.
+    ///
+    /// 매 실행 후 다음 거래일 데이터의 공개 시각까지 기다리며, 실패한 작업이 있으면
+    /// `failure_delay` 뒤에 다시 실행합니다.
+    pub async fn run(mut self) -> Result<()> {
+        loop {
+            let report = self.run_once().await?;
+            let now = Utc::now().with_timezone(&seoul());
+            let mut wake = self.next_publication(now)?;
+            if report.has_failures() {
+                wake = wake.min(now + self.failure_delay);
+            }
+
+            info!(
+                jobs = report.jobs.len(),
+                rows = report.rows(),
+                next_run = %wake,
+                "Scheduler run completed"
+            );
+            tokio::time::sleep((wake - now).to_std().unwrap_or_default()).await;
+        }
+    }
+
+    /// 현재 시각 기준으로 공개된 날짜 중 아직 저장되지 않은 작업을 한 번 처리합니다.
+    pub async fn run_once(&mut self) -> Result<RunReport> {
+        self.run_once_at(Utc::now().with_timezone(&seoul())).await
+    }
+
+    /// 주어진 시각 기준으로 공개된 날짜 중 아직 저장되지 않은 작업을 한 번 처리합니다.
+    pub async fn run_once_at(&mut self, now: DateTime<FixedOffset>) -> Result<RunReport> {
+        let Some(latest) = self.latest_published(now) else {
+            return Ok(RunReport::default());
+        };
+
+        let from = latest
+            .checked_sub_days(Days::new(self.catch_up_days as u64 * 2 + 14))
+            .unwrap_or(NaiveDate::MIN);
+        let days = self
+            .calendar
+            .trading_days(&format_date(from), &format_date(latest))?;
+        let window = &days[days.len().saturating_sub(self.catch_up_days)..];
+
+        let mut pending = Vec::new();
+        for date in window {
+            for dataset in &self.datasets {
+                if self.done.contains(&(dataset.name(), date.clone()))
+                    || self.sink.contains(dataset, date)?
+                {
+                    continue;
+                }
+                pending.push((*dataset, date.clone()));
+            }
+        }
+
+        let collector = self.collector();
+        let mut completed = stream::iter(pending.into_iter().enumerate().map(
+            |(index, (dataset, date))| {
+                let collector = &collector;
+                async move {
+                    let result = collector.fetch(&dataset, &date, now).await;
+                    (index, dataset, date, result)
+                }
+            },
+        ))
+        .buffer_unordered(self.concurrency);
+
+        let mut jobs = Vec::new();
+        while let Some((index, dataset, date, result)) = completed.next().await {
+            let status = match result {
+                Ok(Some(df)) => match self.sink.write(&dataset, &date, &df) {
+                    Ok(()) => JobStatus::Written { rows: df.height() },
+                    Err(e) => JobStatus::Failed(e.to_string()),
+                },
+                Ok(None) => JobStatus::Empty,
+                Err(e) => JobStatus::Failed(e.to_string()),
+            };
+            match &status {
+                JobStatus::Written { rows } => {
+                    info!(dataset = dataset.name(), date = %date, rows, "Collected KRX data");
+                }
+                JobStatus::Empty => {
+                    warn!(dataset = dataset.name(), date = %date, "No data published, skipping");
+                }
+                JobStatus::Failed(message) => {
+                    error!(dataset = dataset.name(), date = %date, error = %message, "Collection failed");
+                }
+            }
+            if !matches!(status, JobStatus::Failed(_)) {
+                self.done.insert((dataset.name(), date.clone()));
+            }
+            jobs.push((
+                index,
+                JobReport {
+                    dataset: dataset.name(),
+                    date,
+                    status,
+                },
+            ));
+        }
+
+        jobs.sort_by_key(|(index, _)| *index);
+        Ok(RunReport {
+            jobs: jobs.into_iter().map(|(_, job)| job).collect(),
+        })
+    }
+
+    /// 현재 설정으로 작업 조회기를 만듭니다.
+    fn collector(&self) -> Collector {
+        Collector {
+            checker: self
+                .client
+                .availability()
+                .calendar(self.calendar.clone())
+                .publish_time(self.publish_time)
+                .publish_grace(self.poll_timeout)
+                .poll_interval(self.poll_interval, self.poll_interval),
+            publish_time: self.publish_time,
+            poll_timeout: self.poll_timeout,
+            max_retries: self.max_retries,
+            retry_backoff: self.retry_backoff,
+        }
+    }
+
+    /// `now` 시점에 공개되어 있는 가장 최근 거래일
+    fn latest_published(&self, now: DateTime<FixedOffset>) -> Option<NaiveDate> {
+        let today = now.with_timezone(&seoul()).date_naive();
+        let lag = if now.with_timezone(&seoul()).time() >= self.publish_time {
+            1
+        } else {
+            2
+        };
+        let mut date = today.checked_sub_days(Days::new(lag))?;
+        // 달력이 모든 날을 휴장일로 두는 경우를 막기 위해 한 달 이내에서만 찾습니다.
+        for _ in 0..31 {
+            if self.calendar.is_trading_day(date) {
+                return Some(date);
+            }
+            date = date.pred_opt()?;
+        }
+        None
+    }
+
+    /// `now` 이후 처음 공개되는 거래일 데이터의 공개 시각
+    fn next_publication(&self, now: DateTime<FixedOffset>) -> Result<DateTime<FixedOffset>> {
+        let mut date = now
+            .with_timezone(&seoul())
+            .date_naive()
+            .pred_opt()
+            .unwrap_or(NaiveDate::MIN);
+        loop {
+            if self.calendar.is_trading_day(date) {
+                let published = publication_time(date, self.publish_time)?;
+                if published > now {
+                    return Ok(published);
+                }
+            }
+            date = date
+                .succ_opt()
+                .ok_or_else(|| Error::InvalidInput("date out of range".to_string()))?;
+        }
+    }
+}
+
+/// 한 작업의 조회와 공개 대기를 맡습니다. 싱크와 분리되어 있어 동시에 실행할 수 있습니다.
+struct Collector {
+    checker: AvailabilityChecker,
+    publish_time: NaiveTime,
+    poll_timeout: Duration,
+    max_retries: u32,
+    retry_backoff: Duration,
+}
+
+impl Collector {
+    /// 데이터가 공개될 때까지 조회합니다.
+    ///
+    /// 공개 시각부터 `poll_timeout`이 지나도 비어 있으면 `None`을 반환합니다. 대기 시간은
+    /// `now`에 실제로 흐른 시간을 더해 계산합니다.
+    async fn fetch(
+        &self,
+        dataset: &Dataset,
+        date: &str,
+        now: DateTime<FixedOffset>,
+    ) -> Result<Option<DataFrame>> {
+        let deadline = publication_time(parse_date(date)?, self.publish_time)? + self.poll_timeout;
+        let started = Instant::now();
+        let mut failures = 0;
+        loop {
+            let elapsed = chrono::Duration::from_std(started.elapsed()).unwrap_or_default();
+            let current = now + elapsed;
+            let timeout = (deadline - current).to_std().unwrap_or_default();
+            let e = match self
+                .checker
+                .wait_until_available_at(dataset, date, timeout, current)
+                .await
+            {
+                Ok(outcome) => return Ok(outcome.into_data()),
+                Err(e) => e,
+            };
+
+            failures += 1;
+            if failures > self.max_retries {
+                return Err(e);
+            }
+            let wait = match &e {
+                Error::RateLimit { retry_after } => Duration::from_secs(*retry_after),
+                _ => self.retry_backoff * 2u32.saturating_pow(failures - 1),
+            };
+            warn!(
+                dataset = dataset.name(),
+                date = %date,
+                error = %e,
+                retry_attempt = failures,
+                "Collection request failed, retrying"
+            );
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y%m%d").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scheduler() -> Scheduler<impl Sink> {
+        let sink = |_: &Dataset, _: &str, _: &DataFrame| -> Result<()> { Ok(()) };
+        Scheduler::new(Client::new("test_key"), sink)
+            .calendar(TradingCalendar::new().with_holidays(["20240208"]).unwrap())
+    }
+
+    fn at(date: &str, time: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_str(&format!("{date} {time} +0900"), "%Y%m%d %H:%M %z").unwrap()
+    }
+
+    fn day(date: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(date, "%Y%m%d").unwrap()
+    }
+
+    #[test]
+    fn test_latest_published_respects_publish_time() {
+        let scheduler = scheduler();
+        // 화요일 공개 전에는 금요일, 공개 후에는 월요일 데이터가 최신입니다.
+        assert_eq!(
+            scheduler.latest_published(at("20240109", "07:59")),
+            Some(day("20240105"))
+        );
+        assert_eq!(
+            scheduler.latest_published(at("20240109", "08:00")),
+            Some(day("20240108"))
+        );
+        // 설 연휴(20240209~12), 추가 휴장일(20240208)과 주말은 건너뜁니다.
+        assert_eq!(
+            scheduler.latest_published(at("20240213", "09:00")),
+            Some(day("20240207"))
+        );
+    }
+
+    #[test]
+    fn test_latest_published_uses_seoul_time() {
+        let scheduler = scheduler();
+        // UTC 2024-01-08 23:30 = 한국 시간 2024-01-09 08:30
+        let now = DateTime::parse_from_rfc3339("2024-01-08T23:30:00Z").unwrap();
+        assert_eq!(scheduler.latest_published(now), Some(day("20240108")));
+    }
+
+    #[test]
+    fn test_next_publication() {
+        let scheduler = scheduler();
+        assert_eq!(
+            scheduler.next_publication(at("20240105", "09:00")).unwrap(),
+            at("20240106", "08:00")
+        );
+        // 금요일 데이터 공개 후에는 월요일 데이터 공개(화요일 아침)를 기다립니다.
+        assert_eq!(
+            scheduler.next_publication(at("20240106", "09:00")).unwrap(),
+            at("20240109", "08:00")
+        );
+    }
+}
@@ -0,0 +1,752 @@
+//! 엔드포인트 스키마 조회와 데이터 사전, DDL 생성.
+//!
+//! API를 호출하지 않고도 엔드포인트가 돌려주는 컬럼을 알 수 있도록, 각 컬럼의 polars
+//! 타입과 원본 KRX 필드(`TDD_CLSPRC` 등), 한글 라벨(컬럼명), 영문명, 단위, 값 영역과
+//! null 허용 여부를 [`EndpointSchema`]로 제공합니다. 값 영역과 자리표시자 규칙은
+//! `docs/field-domains.md`를 따릅니다.
+//!
+//! 컬럼과 KRX 필드의 대응과 null 허용 여부는 레코드 타입과 `parse_*` 함수에 표본 레코드를
+//! 통과시켜 구하므로 변환 코드와 항상 일치합니다.
+//!
+//! # 예시
+//! ```rust
+//! use krx_rs::{api::endpoint::StockDaily, schema::{self, SqlDialect}};
+//!
+//! # fn main() -> krx_rs::Result<()> {
+//! let stock = schema::describe::<StockDaily>()?;
+//! let close = stock.field("종가").unwrap();
+//! assert_eq!(close.krx_field, Some("TDD_CLSPRC"));
+//! assert_eq!(close.unit, schema::Unit::Krw);
+//!
+//! println!("{}", stock.create_table_sql("stk_bydd_trd", SqlDialect::Postgres));
+//! let arrow = stock.arrow_schema();
+//! println!("{}", schema::data_dictionary()?);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    api::endpoint::{ALL, Endpoint},
+    data::ApiResponse,
+    error::{Error, Result},
+};
+use chrono::{Days, NaiveDate};
+use polars::prelude::*;
+use serde::{
+    Deserialize,
+    de::{self, Deserializer, Visitor},
+};
+use serde_json::{Map, Value};
+use std::{collections::HashMap, fmt};
+
+/// 컬럼 값의 단위
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// 원
+    Krw,
+    /// 주 (증권 수)
+    Shares,
+    /// 계약
+    Contracts,
+    /// 상품별 수량 (석유 L, 금 g, 배출권 tCO2eq)
+    Quantity,
+    /// 파생상품 가격 포인트 (상품에 따라 원일 수 있음)
+    Points,
+    /// 지수 포인트
+    IndexPoints,
+    /// 퍼센트
+    Percent,
+    /// 년
+    Years,
+    /// 단위 없음 (코드, 명칭, 날짜 등)
+    None,
+}
+
+impl Unit {
+    /// 단위 표기
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Unit::Krw => "KRW",
+            Unit::Shares => "shares",
+            Unit::Contracts => "contracts",
+            Unit::Quantity => "quantity",
+            Unit::Points => "points",
+            Unit::IndexPoints => "index points",
+            Unit::Percent => "%",
+            Unit::Years => "years",
+            Unit::None => "",
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// `docs/field-domains.md`의 필드 값 영역
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldDomain {
+    /// 가격 (`TDD_CLSPRC` 등). 거래가 없으면 `"-"`
+    Price,
+    /// 전일 대비 (`CMPPREVDD_PRC` 등). 자료가 없으면 `""` 또는 `"-"`
+    Change,
+    /// 거래량·거래대금·주식수·금액. `"0"`은 실제 0
+    Volume,
+    /// 비율·수익률 (`FLUC_RT`, `*_YD` 등). `"0.00"`은 실제 0
+    Rate,
+    /// 분류 문자열 (`MKT_NM` 등). 해당 없으면 `"-"`를 그대로 유지
+    Category,
+    /// 날짜 (YYYYMMDD)
+    Date,
+    /// 코드·명칭 (`ISU_CD`, `ISU_NM` 등)
+    Code,
+    /// 그 외 수치 (듀레이션, 컨벡시티 등)
+    Other,
+}
+
+impl FieldDomain {
+    /// 값이 없음을 나타내는 KRX 자리표시자
+    pub fn placeholders(&self) -> &'static [&'static str] {
+        match self {
+            FieldDomain::Price | FieldDomain::Category => &["-"],
+            FieldDomain::Change | FieldDomain::Other => &["", "-"],
+            FieldDomain::Volume | FieldDomain::Rate | FieldDomain::Date | FieldDomain::Code => &[],
+        }
+    }
+
+    /// 영역 이름
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FieldDomain::Price => "price",
+            FieldDomain::Change => "change",
+            FieldDomain::Volume => "volume",
+            FieldDomain::Rate => "rate",
+            FieldDomain::Category => "category",
+            FieldDomain::Date => "date",
+            FieldDomain::Code => "code",
+            FieldDomain::Other => "other",
+        }
+    }
+}
+
+impl fmt::Display for FieldDomain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 컬럼 하나의 메타데이터
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldInfo {
+    /// DataFrame 컬럼명 (한글 라벨)
+    pub name: String,
+    /// polars 타입
+    pub dtype: DataType,
+    /// 원본 KRX 필드 ID (변환 중 만들어진 컬럼이면 `None`)
+    pub krx_field: Option<&'static str>,
+    /// 영문명 (snake_case)
+    pub english_name: &'static str,
+    /// 단위
+    pub unit: Unit,
+    /// 값 영역
+    pub domain: FieldDomain,
+    /// 자리표시자가 null로 변환될 수 있는지 여부
+    pub nullable: bool,
+}
+
+/// 엔드포인트 하나의 컬럼 메타데이터
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointSchema {
+    /// API 경로 (예: `/sto/stk_bydd_trd`)
+    pub path: &'static str,
+    fields: Vec<FieldInfo>,
+}
+
+/// SQL 방언
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlDialect {
+    /// SQLite (`store::sqlite`와 같은 타입 대응)
+    Sqlite,
+    /// PostgreSQL
+    Postgres,
+}
+
+impl EndpointSchema {
+    /// 컬럼 메타데이터 (DataFrame 컬럼 순)
+    pub fn fields(&self) -> &[FieldInfo] {
+        &self.fields
+    }
+
+    /// 컬럼명으로 메타데이터를 찾습니다.
+    pub fn field(&self, name: &str) -> Option<&FieldInfo> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// KRX 필드 ID로 메타데이터를 찾습니다.
+    pub fn krx_field(&self, id: &str) -> Option<&FieldInfo> {
+        self.fields.iter().find(|field| field.krx_field == Some(id))
+    }
+
+    /// polars 스키마
+    pub fn schema(&self) -> Schema {
+        self.fields
+            .iter()
+            .map(|field| Field::new(field.name.as_str().into(), field.dtype.clone()))
+            .collect()
+    }
+
+    /// 컬럼 메타데이터를 필드 메타데이터(`krx_field`, `english_name`, `unit`, `domain`)로
+    /// 담은 Arrow 스키마
+    pub fn arrow_schema(&self) -> ArrowSchema {
+        self.fields
+            .iter()
+            .map(|field| {
+                let mut metadata = std::collections::BTreeMap::new();
+                if let Some(id) = field.krx_field {
+                    metadata.insert("krx_field".into(), id.into());
+                }
+                metadata.insert("english_name".into(), field.english_name.into());
+                if field.unit != Unit::None {
+                    metadata.insert("unit".into(), field.unit.as_str().into());
+                }
+                metadata.insert("domain".into(), field.domain.as_str().into());
+                ArrowField::new(
+                    field.name.as_str().into(),
+                    field.dtype.to_arrow(CompatLevel::newest()),
+                    field.nullable,
+                )
+                .with_metadata(metadata)
+            })
+            .collect()
+    }
+
+    /// `CREATE TABLE` 문을 만듭니다.
+    ///
+    /// null을 허용하지 않는 컬럼은 `NOT NULL`로 선언하며, 영문명과 단위를 주석으로 남깁니다.
+    pub fn create_table_sql(&self, table: &str, dialect: SqlDialect) -> String {
+        let columns: Vec<String> = self
+            .fields
+            .iter()
+            .map(|field| {
+                let not_null = if field.nullable { "" } else { " NOT NULL" };
+                format!(
+                    "{} {}{not_null}",
+                    quote(&field.name),
+                    sql_type(&field.dtype, dialect)
+                )
+            })
+            .collect();
+
+        match dialect {
+            SqlDialect::Sqlite => {
+                let last = columns.len().saturating_sub(1);
+                let body: Vec<String> = columns
+                    .iter()
+                    .zip(&self.fields)
+                    .enumerate()
+                    .map(|(i, (column, field))| {
+                        let comma = if i == last { "" } else { "," };
+                        format!("    {column}{comma} -- {}", comment(field))
+                    })
+                    .collect();
+                format!("CREATE TABLE {} (\n{}\n);", quote(table), body.join("\n"))
+            }
+            SqlDialect::Postgres => {
+                let mut sql = format!(
+                    "CREATE TABLE {} (\n    {}\n);",
+                    quote(table),
+                    columns.join(",\n    ")
+                );
+                for field in &self.fields {
+                    sql.push_str(&format!(
+                        "\nCOMMENT ON COLUMN {}.{} IS '{}';",
+                        quote(table),
+                        quote(&field.name),
+                        comment(field).replace('\'', "''")
+                    ));
+                }
+                sql
+            }
+        }
+    }
+
+    /// 데이터 사전 DataFrame
+    ///
+    /// 컬럼: 컬럼명, KRX필드, 영문명, 타입, 단위, 값영역, null허용
+    pub fn to_dataframe(&self) -> Result<DataFrame> {
+        dictionary_frame(self.fields.iter().map(|field| (None, field)))
+    }
+}
+
+/// 엔드포인트의 컬럼 메타데이터를 구합니다.
+pub fn describe<E: Endpoint>() -> Result<EndpointSchema> {
+    let (df, values) = sample_frame::<E>(None)?;
+    let traced: Vec<Option<&'static str>> = df
+        .get_columns()
+        .iter()
+        .map(|column| trace(column, &values))
+        .collect();
+
+    // 필드 하나씩 자리표시자 "-"를 넣어 보고, 해당 컬럼이 null이 되면 null 허용으로 봅니다.
+    let mut nullable: HashMap<&'static str, bool> = HashMap::new();
+    for (index, field) in values.iter().map(|(_, field)| *field).enumerate() {
+        let is_null = match sample_frame::<E>(Some(index)) {
+            Ok((blank, _)) => traced
+                .iter()
+                .zip(blank.get_columns())
+                .filter(|(id, _)| **id == Some(field))
+                .any(|(_, column)| column.null_count() > 0),
+            Err(_) => false,
+        };
+        nullable.insert(field, is_null);
+    }
+
+    let fields = df
+        .get_columns()
+        .iter()
+        .zip(traced)
+        .map(|(column, id)| {
+            let known = id.and_then(lookup);
+            let domain = known.map_or(FieldDomain::Other, |known| known.domain);
+            FieldInfo {
+                name: column.name().to_string(),
+                dtype: column.dtype().clone(),
+                krx_field: id,
+                english_name: known.map_or("", |known| known.english_name),
+                unit: known.map_or(Unit::None, |known| unit_for(known, E::PATH)),
+                domain,
+                nullable: id.is_none_or(|id| nullable.get(id).copied().unwrap_or(true)),
+            }
+        })
+        .collect();
+    Ok(EndpointSchema {
+        path: E::PATH,
+        fields,
+    })
+}
+
+/// 모든 엔드포인트의 데이터 사전 DataFrame
+///
+/// 컬럼: 엔드포인트, 컬럼명, KRX필드, 영문명, 타입, 단위, 값영역, null허용
+pub fn data_dictionary() -> Result<DataFrame> {
+    let schemas = ALL
+        .iter()
+        .map(|info| info.describe())
+        .collect::<Result<Vec<_>>>()?;
+    dictionary_frame(schemas.iter().flat_map(|schema| {
+        schema
+            .fields
+            .iter()
+            .map(|field| (Some(&schema.path[1..]), field))
+    }))
+}
+
+fn dictionary_frame<'a>(
+    rows: impl Iterator<Item = (Option<&'a str>, &'a FieldInfo)>,
+) -> Result<DataFrame> {
+    let mut endpoints = Vec::new();
+    let mut names = Vec::new();
+    let mut krx_fields = Vec::new();
+    let mut english_names = Vec::new();
+    let mut dtypes = Vec::new();
+    let mut units = Vec::new();
+    let mut domains = Vec::new();
+    let mut nullables = Vec::new();
+
+    for (endpoint, field) in rows {
+        endpoints.push(endpoint);
+        names.push(field.name.as_str());
+        krx_fields.push(field.krx_field);
+        english_names.push(field.english_name);
+        dtypes.push(field.dtype.to_string());
+        units.push(field.unit.as_str());
+        domains.push(field.domain.as_str());
+        nullables.push(field.nullable);
+    }
+
+    let with_endpoints = endpoints.iter().any(Option::is_some);
+    let mut df = df! {
+        "컬럼명" => names,
+        "KRX필드" => krx_fields,
+        "영문명" => english_names,
+        "타입" => dtypes,
+        "단위" => units,
+        "값영역" => domains,
+        "null허용" => nullables,
+    }?;
+    if with_endpoints {
+        df.insert_column(0, Column::new("엔드포인트".into(), endpoints))?;
+    }
+    Ok(df)
+}
+
+fn quote(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+fn sql_type(dtype: &DataType, dialect: SqlDialect) -> &'static str {
+    match (dialect, dtype) {
+        (SqlDialect::Sqlite, DataType::Float32 | DataType::Float64) => "REAL",
+        (SqlDialect::Sqlite, dtype) if dtype.is_integer() => "INTEGER",
+        (SqlDialect::Sqlite, _) => "TEXT",
+        (SqlDialect::Postgres, DataType::Float32 | DataType::Float64) => "DOUBLE PRECISION",
+        (SqlDialect::Postgres, dtype) if dtype.is_integer() => "BIGINT",
+        (SqlDialect::Postgres, DataType::Boolean) => "BOOLEAN",
+        (SqlDialect::Postgres, DataType::Date) => "DATE",
+        (SqlDialect::Postgres, _) => "TEXT",
+    }
+}
+
+fn comment(field: &FieldInfo) -> String {
+    let mut comment = field.english_name.to_string();
+    if let Some(id) = field.krx_field {
+        comment.push_str(&format!(" ({id})"));
+    }
+    if field.unit != Unit::None {
+        comment.push_str(&format!(" [{}]", field.unit));
+    }
+    comment
+}
+
+/// KRX 필드 사전 항목
+struct KnownField {
+    id: &'static str,
+    english_name: &'static str,
+    domain: FieldDomain,
+    unit: Unit,
+}
+
+macro_rules! known_fields {
+    ($($id:literal => $english:literal, $domain:ident, $unit:ident;)+) => {
+        const KNOWN_FIELDS: &[KnownField] = &[$(KnownField {
+            id: $id,
+            english_name: $english,
+            domain: FieldDomain::$domain,
+            unit: Unit::$unit,
+        }),+];
+    };
+}
+
+known_fields! {
+    "BAS_DD" => "base_date", Date, None;
+    "ISU_CD" => "issue_code", Code, None;
+    "ISU_SRT_CD" => "issue_short_code", Code, None;
+    "ISU_NM" => "issue_name", Code, None;
+    "ISU_ABBRV" => "issue_abbreviation", Code, None;
+    "ISU_ENG_NM" => "issue_english_name", Code, None;
+    "ISUR_NM" => "issuer_name", Code, None;
+    "IDX_NM" => "index_name", Code, None;
+    "IDX_IND_NM" => "index_indicator_name", Code, None;
+    "BND_IDX_GRP_NM" => "bond_index_group_name", Code, None;
+    "OIL_NM" => "oil_name", Code, None;
+    "ULY_NM" => "underlying_name", Code, None;
+    "TARSTK_ISU_SRT_CD" => "target_stock_code", Code, None;
+    "TARSTK_ISU_NM" => "target_stock_name", Code, None;
+    "MKT_NM" => "market_name", Category, None;
+    "MKT_TP_NM" => "market_type", Category, None;
+    "SECT_TP_NM" => "sector_type", Category, None;
+    "SECUGRP_NM" => "security_group", Category, None;
+    "KIND_STKCERT_TP_NM" => "stock_type", Category, None;
+    "IDX_CLSS" => "index_class", Category, None;
+    "PROD_NM" => "product_name", Category, None;
+    "RGHT_TP_NM" => "right_type", Category, None;
+    "GOVBND_ISU_TP_NM" => "government_bond_issue_type", Category, None;
+    "BND_EXP_TP_NM" => "bond_expiry_type", Category, None;
+    "SRI_BND_TP_NM" => "sri_bond_type", Category, None;
+    "BND_TP_NM" => "bond_type", Category, None;
+    "TDD_CLSPRC" => "close_price", Price, Krw;
+    "TDD_OPNPRC" => "open_price", Price, Krw;
+    "TDD_HGPRC" => "high_price", Price, Krw;
+    "TDD_LWPRC" => "low_price", Price, Krw;
+    "CLSPRC" => "close_price", Price, Krw;
+    "OPNPRC" => "open_price", Price, Krw;
+    "HGPRC" => "high_price", Price, Krw;
+    "LWPRC" => "low_price", Price, Krw;
+    "CLSPRC_IDX" => "close_price", Price, IndexPoints;
+    "OPNPRC_IDX" => "open_price", Price, IndexPoints;
+    "HGPRC_IDX" => "high_price", Price, IndexPoints;
+    "LWPRC_IDX" => "low_price", Price, IndexPoints;
+    "SETL_PRC" => "settlement_price", Price, Krw;
+    "SPOT_PRC" => "spot_price", Price, Krw;
+    "NXTDD_BAS_PRC" => "next_day_base_price", Price, Krw;
+    "EXER_PRC" => "exercise_price", Price, Krw;
+    "ISU_PRC" => "issue_price", Price, Krw;
+    "PARVAL" => "par_value", Price, Krw;
+    "TARSTK_ISU_PRSNT_PRC" => "target_stock_price", Price, Krw;
+    "ULY_PRC" => "underlying_price", Price, Krw;
+    "NAV" => "nav", Price, Krw;
+    "PER1SECU_INDIC_VAL" => "per_security_indicative_value", Price, Krw;
+    "WT_AVG_PRC" => "weighted_average_price", Price, Krw;
+    "WT_DIS_AVG_PRC" => "weighted_discount_average_price", Price, Krw;
+    "OBJ_STKPRC_IDX" => "objective_stock_price_index", Price, IndexPoints;
+    "TOT_EARNG_IDX" => "total_earning_index", Price, IndexPoints;
+    "NETPRC_IDX" => "net_price_index", Price, IndexPoints;
+    "ZERO_REINVST_IDX" => "zero_reinvest_index", Price, IndexPoints;
+    "CALL_REINVST_IDX" => "call_reinvest_index", Price, IndexPoints;
+    "MKT_PRC_IDX" => "market_price_index", Price, IndexPoints;
+    "CMPPREVDD_PRC" => "price_change", Change, Krw;
+    "CMPPREVDD_IDX" => "index_change", Change, IndexPoints;
+    "CMPPREVDD_PRC_ULY" => "underlying_price_change", Change, Krw;
+    "TOT_EARNG_IDX_CMPPREVDD" => "total_earning_index_change", Change, IndexPoints;
+    "NETPRC_IDX_CMPPREVDD" => "net_price_index_change", Change, IndexPoints;
+    "ZERO_REINVST_IDX_CMPPREVDD" => "zero_reinvest_index_change", Change, IndexPoints;
+    "CALL_REINVST_IDX_CMPPREVDD" => "call_reinvest_index_change", Change, IndexPoints;
+    "MKT_PRC_IDX_CMPPREVDD" => "market_price_index_change", Change, IndexPoints;
+    "ACC_TRDVOL" => "trading_volume", Volume, Shares;
+    "ACC_TRDVAL" => "trading_value", Volume, Krw;
+    "MKTCAP" => "market_cap", Volume, Krw;
+    "LIST_SHRS" => "listed_shares", Volume, Shares;
+    "ACC_OPNINT_QTY" => "open_interest_quantity", Volume, Contracts;
+    "INVSTASST_NETASST_TOTAMT" => "investment_asset_net_total_amount", Volume, Krw;
+    "INDIC_VAL_AMT" => "indicative_value_amount", Volume, Krw;
+    "ISU_AMT" => "issue_amount", Volume, Krw;
+    "LIST_AMT" => "listing_amount", Volume, Krw;
+    "FLUC_RT" => "fluctuation_rate", Rate, Percent;
+    "FLUC_RT_IDX" => "index_fluctuation_rate", Rate, Percent;
+    "FLUC_RT_ULY" => "underlying_fluctuation_rate", Rate, Percent;
+    "CLSPRC_YD" => "close_price_yield", Rate, Percent;
+    "OPNPRC_YD" => "open_price_yield", Rate, Percent;
+    "HGPRC_YD" => "high_price_yield", Rate, Percent;
+    "LWPRC_YD" => "low_price_yield", Rate, Percent;
+    "BND_IDX_AVG_YD" => "bond_index_average_yield", Rate, Percent;
+    "ISU_RT" => "issue_rate", Rate, Percent;
+    "IMP_VOLT" => "implied_volatility", Rate, Percent;
+    "AVG_DURATION" => "average_duration", Other, Years;
+    "AVG_CONVEXITY_PRC" => "average_convexity_price", Other, None;
+    "LIST_DD" => "listing_date", Date, None;
+    "DELIST_DD" => "delisting_date", Date, None;
+    "ISU_DD" => "issue_date", Date, None;
+    "REDMPT_DD" => "redemption_date", Date, None;
+    "EXST_STRT_DD" => "existence_start_date", Date, None;
+    "EXST_END_DD" => "existence_end_date", Date, None;
+}
+
+fn lookup(id: &str) -> Option<&'static KnownField> {
+    KNOWN_FIELDS.iter().find(|field| field.id == id)
+}
+
+/// 엔드포인트에 따라 달라지는 단위를 보정합니다.
+fn unit_for(field: &KnownField, path: &str) -> Unit {
+    let derivative = path.starts_with("/drv/");
+    // 주식선물·옵션을 제외한 파생상품은 포인트로 호가합니다.
+    let equity_derivative = path.starts_with("/drv/eqs") || path.starts_with("/drv/eqk");
+    match (field.id, field.domain) {
+        ("ACC_TRDVOL", _) if derivative => Unit::Contracts,
+        // 채권 거래량은 액면금액 기준입니다.
+        ("ACC_TRDVOL", _) if path.starts_with("/bon/") => Unit::Krw,
+        ("ACC_TRDVOL", _) if path.starts_with("/gen/") => Unit::Quantity,
+        (_, FieldDomain::Price | FieldDomain::Change)
+            if derivative && !equity_derivative && field.unit == Unit::Krw =>
+        {
+            Unit::Points
+        }
+        _ => field.unit,
+    }
+}
+
+/// 표본 레코드 하나를 변환한 DataFrame과 필드별로 넣은 값
+///
+/// 필드마다 서로 다른 날짜 문자열을 넣어 출력 컬럼이 어느 필드에서 왔는지 알 수 있게 하고,
+/// `blank`로 지정한 필드에는 자리표시자 `"-"`를 넣습니다.
+pub(crate) fn sample_frame<E: Endpoint>(
+    blank: Option<usize>,
+) -> Result<(DataFrame, Vec<(String, &'static str)>)> {
+    let mut values = Vec::new();
+    let record = <E::Record as Deserialize>::deserialize(SampleRecord {
+        blank,
+        values: &mut values,
+    })
+    .map_err(|e| Error::Parsing {
+        details: format!("Failed to build sample record for {}", E::PATH),
+        source: e,
+        response_body: String::new(),
+    })?;
+    let df = E::parse(ApiResponse { data: vec![record] })?;
+    Ok((df, values))
+}
+
+/// 표본 레코드 하나를 변환하여 엔드포인트의 출력 스키마를 구합니다.
+pub(crate) fn sample_schema<E: Endpoint>() -> Result<Schema> {
+    let (df, _) = sample_frame::<E>(None)?;
+    Ok(df.schema().as_ref().clone())
+}
+
+/// 컬럼의 첫 값으로 원본 KRX 필드를 찾습니다.
+fn trace(column: &Column, values: &[(String, &'static str)]) -> Option<&'static str> {
+    let value = match column.get(0).ok()? {
+        AnyValue::String(s) => s.replace('-', ""),
+        AnyValue::StringOwned(s) => s.replace('-', ""),
+        AnyValue::Float64(v) if v.fract() == 0.0 => format!("{}", v as i64),
+        AnyValue::Float32(v) if v.fract() == 0.0 => format!("{}", v as i64),
+        AnyValue::UInt64(v) => v.to_string(),
+        AnyValue::Int64(v) => v.to_string(),
+        AnyValue::UInt32(v) => v.to_string(),
+        AnyValue::Int32(v) => v.to_string(),
+        AnyValue::Date(days) => {
+            let date = NaiveDate::from_num_days_from_ce_opt(days + 719_163)?;
+            date.format("%Y%m%d").to_string()
+        }
+        _ => return None,
+    };
+    values
+        .iter()
+        .find(|(sample, _)| *sample == value)
+        .map(|(_, field)| *field)
+}
+
+/// 필드마다 서로 다른 문자열 값을 갖는 레코드를 만드는 역직렬화기
+///
+/// KRX 응답은 모든 필드가 문자열이므로, 날짜와 숫자로 모두 해석되는 값(2024년 1월의
+/// 서로 다른 날짜)을 넣으면 어떤 레코드 타입이든 만들 수 있습니다.
+struct SampleRecord<'a> {
+    blank: Option<usize>,
+    values: &'a mut Vec<(String, &'static str)>,
+}
+
+impl SampleRecord<'_> {
+    const FIRST: NaiveDate = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+}
+
+impl<'de> Deserializer<'de> for SampleRecord<'_> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(
+        self,
+        _visitor: V,
+    ) -> std::result::Result<V::Value, Self::Error> {
+        Err(de::Error::custom("sample record must be a struct"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> std::result::Result<V::Value, Self::Error> {
+        let mut object = Map::new();
+        for (index, field) in fields.iter().enumerate() {
+            let value = Self::FIRST
+                .checked_add_days(Days::new(index as u64))
+                .unwrap_or(Self::FIRST)
+                .format("%Y%m%d")
+                .to_string();
+            self.values.push((value.clone(), field));
+            let value = if self.blank == Some(index) {
+                "-".to_string()
+            } else {
+                value
+            };
+            object.insert(field.to_string(), Value::String(value));
+        }
+        Value::Object(object).deserialize_struct(name, fields, visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::endpoint::{EtfDaily, FuturesDaily, KospiIndexDaily, StockDaily};
+
+    #[test]
+    fn test_every_column_maps_to_known_field() {
+        for info in ALL {
+            let schema = info.describe().unwrap();
+            assert_eq!(schema.schema(), info.schema().unwrap());
+            for field in schema.fields() {
+                let id = field
+                    .krx_field
+                    .unwrap_or_else(|| panic!("{}: `{}` not traced", info.name(), field.name));
+                assert!(lookup(id).is_some(), "{}: unknown field {id}", info.name());
+            }
+        }
+    }
+
+    #[test]
+    fn test_stock_daily_metadata() {
+        let schema = describe::<StockDaily>().unwrap();
+
+        let close = schema.field("종가").unwrap();
+        assert_eq!(close.krx_field, Some("TDD_CLSPRC"));
+        assert_eq!(close.english_name, "close_price");
+        assert_eq!(close.unit, Unit::Krw);
+        assert_eq!(close.domain, FieldDomain::Price);
+        assert!(close.nullable);
+
+        let code = schema.krx_field("ISU_CD").unwrap();
+        assert_eq!(code.name, "종목코드");
+        assert!(!code.nullable);
+        assert_eq!(schema.krx_field("LIST_SHRS").unwrap().unit, Unit::Shares);
+        assert_eq!(schema.krx_field("FLUC_RT").unwrap().unit, Unit::Percent);
+        assert!(!schema.krx_field("BAS_DD").unwrap().nullable);
+    }
+
+    #[test]
+    fn test_units_depend_on_endpoint() {
+        let futures = describe::<FuturesDaily>().unwrap();
+        assert_eq!(futures.krx_field("TDD_CLSPRC").unwrap().unit, Unit::Points);
+        assert_eq!(
+            futures.krx_field("ACC_TRDVOL").unwrap().unit,
+            Unit::Contracts
+        );
+        assert_eq!(futures.krx_field("ACC_TRDVAL").unwrap().unit, Unit::Krw);
+
+        let index = describe::<KospiIndexDaily>().unwrap();
+        assert_eq!(
+            index.krx_field("CLSPRC_IDX").unwrap().unit,
+            Unit::IndexPoints
+        );
+
+        let etf = describe::<EtfDaily>().unwrap();
+        assert_eq!(etf.krx_field("NAV").unwrap().unit, Unit::Krw);
+    }
+
+    #[test]
+    fn test_create_table_sql() {
+        let schema = describe::<StockDaily>().unwrap();
+
+        let sqlite = schema.create_table_sql("stk_bydd_trd", SqlDialect::Sqlite);
+        assert!(sqlite.starts_with("CREATE TABLE \"stk_bydd_trd\" (\n"));
+        assert!(sqlite.contains("\"종목코드\" TEXT NOT NULL, -- issue_code (ISU_CD)"));
+        assert!(sqlite.contains("\"종가\" REAL, -- close_price (TDD_CLSPRC) [KRW]"));
+        assert!(sqlite.ends_with(");"));
+
+        let postgres = schema.create_table_sql("stk_bydd_trd", SqlDialect::Postgres);
+        assert!(postgres.contains("\"거래량\" BIGINT"));
+        assert!(postgres.contains(
+            "COMMENT ON COLUMN \"stk_bydd_trd\".\"종가\" IS 'close_price (TDD_CLSPRC) [KRW]';"
+        ));
+    }
+
+    #[test]
+    fn test_arrow_schema_metadata() {
+        let schema = describe::<StockDaily>().unwrap();
+        let arrow = schema.arrow_schema();
+
+        assert_eq!(arrow.len(), schema.fields().len());
+        let close = arrow.get("종가").unwrap();
+        assert!(close.is_nullable);
+        let metadata = close.metadata.as_ref().unwrap();
+        assert_eq!(metadata.get("krx_field").unwrap().as_str(), "TDD_CLSPRC");
+        assert_eq!(metadata.get("unit").unwrap().as_str(), "KRW");
+        assert!(!arrow.get("종목코드").unwrap().is_nullable);
+    }
+
+    #[test]
+    fn test_data_dictionary() {
+        let dictionary = data_dictionary().unwrap();
+        let total: usize = ALL.iter().map(|info| info.schema().unwrap().len()).sum();
+        assert_eq!(dictionary.height(), total);
+        assert_eq!(dictionary.get_column_names()[0].as_str(), "엔드포인트");
+
+        let single = describe::<StockDaily>().unwrap().to_dataframe().unwrap();
+        assert_eq!(single.get_column_names()[0].as_str(), "컬럼명");
+    }
+}
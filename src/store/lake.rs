@@ -0,0 +1,333 @@
+//! 날짜별로 분할된 Parquet 데이터 레이크.
+//!
+//! 디렉터리 구조는 `{root}/{엔드포인트 이름}/date={YYYY-MM-DD}/part.parquet`(예:
+//! `{root}/sto/stk_bydd_trd/date=2024-01-05/part.parquet`)이며,
+//! 각 파티션은 `parse_*` 결과 DataFrame을 그대로 저장합니다. 따라서
+//! [`ParquetLake::scan`]으로 읽은 데이터는 실시간 조회 결과와 같은 스키마를 가집니다.
+//!
+//! [`ParquetLake::gaps`]는 [`TradingCalendar`] 기준 거래일 중 파티션이 없거나 비어 있는
+//! 날짜를 찾고, [`ParquetLake::sync`]는 그 날짜만 다시 조회하여 채웁니다.
+//!
+//! # 예시
+//! ```rust,no_run
+//! # use krx_rs::Client;
+//! use krx_rs::{api::endpoint::StockDaily, store::{Dataset, lake::ParquetLake}};
+//! use polars::prelude::*;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), krx_rs::error::Error> {
+//! # let client = Client::new("YOUR_AUTH_KEY");
+//! let lake = ParquetLake::open("./krx-lake")?;
+//! let stocks = Dataset::of::<StockDaily>();
+//! lake.sync(&client, &stocks, "20240102", "20240131").await?;
+//!
+//! let scan = lake
+//!     .scan(&stocks, "20240102", "20240131")?
+//!     .filter(col("종목코드").eq(lit("005930")));
+//! let df = tokio::task::spawn_blocking(move || scan.collect())
+//!     .await
+//!     .expect("scan task panicked")?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::Dataset;
+use crate::{
+    api::common::latest_workday_string,
+    calendar::TradingCalendar,
+    client::Client,
+    error::{Error, Result},
+};
+use chrono::NaiveDate;
+use polars::prelude::*;
+use std::{
+    fs::{self, File},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// 파티션 파일 이름
+const PARTITION_FILE: &str = "part.parquet";
+
+/// 파티션 누락 유형
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GapKind {
+    /// 파티션 파일이 없음
+    Missing,
+    /// 파티션 파일은 있지만 행이 없음
+    Empty,
+}
+
+/// 거래일 기준으로 채워지지 않은 파티션
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gap {
+    /// 기준일자 (YYYYMMDD)
+    pub date: String,
+    /// 누락 유형
+    pub kind: GapKind,
+}
+
+/// [`ParquetLake::sync`] 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LakeReport {
+    /// 새로 기록한 기준일자
+    pub written: Vec<String>,
+    /// 조회했지만 데이터가 없어 기록하지 않은 기준일자
+    pub empty: Vec<String>,
+    /// 기록한 행 수
+    pub rows: usize,
+}
+
+/// 날짜 분할 Parquet 데이터 레이크
+#[derive(Debug, Clone)]
+pub struct ParquetLake {
+    root: PathBuf,
+    calendar: TradingCalendar,
+}
+
+impl ParquetLake {
+    /// 루트 디렉터리를 열거나 새로 만듭니다.
+    pub fn open(root: impl Into<PathBuf>) -> Result<Self> {
+        let root = root.into();
+        fs::create_dir_all(&root)?;
+        Ok(Self {
+            root,
+            calendar: TradingCalendar::new(),
+        })
+    }
+
+    /// 누락 파티션 판정에 사용할 거래일 달력을 설정합니다.
+    pub fn calendar(mut self, calendar: TradingCalendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// 레이크 루트 디렉터리
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// 기준일자(YYYYMMDD)의 파티션 파일 경로
+    pub fn partition_path(&self, dataset: &Dataset, date: &str) -> Result<PathBuf> {
+        Ok(self
+            .root
+            .join(dataset.name())
+            .join(format!("date={}", partition_date(date)?))
+            .join(PARTITION_FILE))
+    }
+
+    /// 한 기준일자의 DataFrame을 파티션으로 기록합니다. 기존 파티션은 교체됩니다.
+    ///
+    /// 임시 파일에 먼저 쓴 뒤 이름을 바꾸므로, 중단되어도 불완전한 파티션이 남지 않습니다.
+    pub fn write(&self, dataset: &Dataset, date: &str, df: &DataFrame) -> Result<PathBuf> {
+        let path = self.partition_path(dataset, date)?;
+        let dir = path.parent().expect("partition path has a parent");
+        fs::create_dir_all(dir)?;
+
+        let tmp = dir.join(format!("{PARTITION_FILE}.tmp"));
+        let mut df = df.clone();
+        ParquetWriter::new(File::create(&tmp)?).finish(&mut df)?;
+        fs::rename(&tmp, &path)?;
+
+        Ok(path)
+    }
+
+    /// 기간 안의 거래일 중 파티션이 없거나 비어 있는 날짜를 찾습니다.
+    pub fn gaps(&self, dataset: &Dataset, from: &str, to: &str) -> Result<Vec<Gap>> {
+        let mut gaps = Vec::new();
+        for date in self.calendar.trading_days(from, to)? {
+            let path = self.partition_path(dataset, &date)?;
+            let kind = if !path.exists() {
+                GapKind::Missing
+            } else if ParquetReader::new(File::open(&path)?).num_rows()? == 0 {
+                GapKind::Empty
+            } else {
+                continue;
+            };
+            gaps.push(Gap { date, kind });
+        }
+        Ok(gaps)
+    }
+
+    /// 기간 안의 누락·빈 파티션을 다시 조회하여 기록합니다.
+    ///
+    /// 아직 데이터가 제공되지 않는 날짜(가장 최신 거래일 이후)는 건너뛰며,
+    /// 조회 결과가 비어 있는 날짜는 기록하지 않고 [`LakeReport::empty`]에 남깁니다.
+    pub async fn sync(
+        &self,
+        client: &Client,
+        dataset: &Dataset,
+        from: &str,
+        to: &str,
+    ) -> Result<LakeReport> {
+        let latest = latest_workday_string();
+        let mut report = LakeReport::default();
+
+        for gap in self.gaps(dataset, from, to)? {
+            if gap.date > latest {
+                continue;
+            }
+
+            let df = dataset.fetch(client, &gap.date).await?;
+            if df.is_empty() {
+                tracing::warn!(dataset = dataset.name(), date = %gap.date, "No data for trading day");
+                report.empty.push(gap.date);
+                continue;
+            }
+
+            self.write(dataset, &gap.date, &df)?;
+            tracing::info!(dataset = dataset.name(), date = %gap.date, rows = df.height(), "Wrote partition");
+            report.rows += df.height();
+            report.written.push(gap.date);
+        }
+
+        Ok(report)
+    }
+
+    /// 기간(양 끝 포함) 안의 파티션을 하나의 `LazyFrame`으로 읽습니다.
+    ///
+    /// 파티션 컬럼(`date`)은 추가되지 않습니다. 파티션이 하나도 없으면
+    /// 빈 `LazyFrame`을 반환합니다.
+    ///
+    /// polars의 Parquet 스캔은 내부 async 런타임을 사용하므로, tokio 태스크 안에서는
+    /// `tokio::task::spawn_blocking`으로 감싸서 `collect()`해야 합니다.
+    pub fn scan(&self, dataset: &Dataset, from: &str, to: &str) -> Result<LazyFrame> {
+        let from = partition_date(from)?;
+        let to = partition_date(to)?;
+        if from > to {
+            return Err(Error::InvalidInput(
+                "start date must not be after end date".to_string(),
+            ));
+        }
+
+        let dir = self.root.join(dataset.name());
+        let mut paths = Vec::new();
+        if dir.exists() {
+            for entry in fs::read_dir(&dir)? {
+                let entry = entry?;
+                let name = entry.file_name();
+                let Some(date) = name.to_str().and_then(|n| n.strip_prefix("date=")) else {
+                    continue;
+                };
+                let path = entry.path().join(PARTITION_FILE);
+                if date >= from.as_str() && date <= to.as_str() && path.exists() {
+                    paths.push(path);
+                }
+            }
+        }
+        paths.sort();
+
+        if paths.is_empty() {
+            return Ok(DataFrame::empty().lazy());
+        }
+
+        let args = ScanArgsParquet {
+            hive_options: polars::io::HiveOptions::new_disabled(),
+            ..Default::default()
+        };
+        Ok(LazyFrame::scan_parquet_files(Arc::from(paths), args)?)
+    }
+}
+
+/// YYYYMMDD를 파티션 디렉터리용 YYYY-MM-DD로 변환합니다.
+fn partition_date(date: &str) -> Result<String> {
+    NaiveDate::parse_from_str(date, "%Y%m%d")
+        .map(|date| date.format("%Y-%m-%d").to_string())
+        .map_err(|_| Error::InvalidInput("date must be in YYYYMMDD format".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::endpoint::StockDaily;
+
+    const STOCK_DAILY: Dataset = Dataset::of::<StockDaily>();
+
+    fn temp_lake(name: &str) -> ParquetLake {
+        let root = std::env::temp_dir().join(format!("krx-lake-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        ParquetLake::open(root).unwrap()
+    }
+
+    fn daily(date: &str) -> DataFrame {
+        df! {
+            "날짜" => [date, date],
+            "종목코드" => ["005930", "000660"],
+            "종가" => [Some(71_000.0), None],
+            "거래량" => [Some(100i64), Some(200)],
+        }
+        .unwrap()
+    }
+
+    #[test]
+    fn test_partition_path() {
+        let lake = temp_lake("path");
+        let path = lake.partition_path(&STOCK_DAILY, "20240105").unwrap();
+        assert!(path.ends_with("sto/stk_bydd_trd/date=2024-01-05/part.parquet"));
+        assert!(lake.partition_path(&STOCK_DAILY, "2024-01-05").is_err());
+        fs::remove_dir_all(lake.root()).unwrap();
+    }
+
+    #[test]
+    fn test_write_and_scan_round_trip() {
+        let lake = temp_lake("scan");
+        lake.write(&STOCK_DAILY, "20240104", &daily("2024-01-04"))
+            .unwrap();
+        lake.write(&STOCK_DAILY, "20240105", &daily("2024-01-05"))
+            .unwrap();
+
+        let all = lake
+            .scan(&STOCK_DAILY, "20240101", "20240131")
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert_eq!(all.height(), 4);
+        assert_eq!(all.schema(), daily("2024-01-04").schema());
+
+        let one = lake
+            .scan(&STOCK_DAILY, "20240105", "20240105")
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert!(one.equals_missing(&daily("2024-01-05")));
+
+        let none = lake
+            .scan(&STOCK_DAILY, "20240201", "20240229")
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert!(none.is_empty());
+        fs::remove_dir_all(lake.root()).unwrap();
+    }
+
+    #[test]
+    fn test_gaps_against_calendar() {
+        let lake =
+            temp_lake("gaps").calendar(TradingCalendar::new().with_holidays(["20240103"]).unwrap());
+        lake.write(&STOCK_DAILY, "20240102", &daily("2024-01-02"))
+            .unwrap();
+        lake.write(&STOCK_DAILY, "20240104", &daily("2024-01-04").head(Some(0)))
+            .unwrap();
+
+        // 01-01 신정, 01-03 휴장일, 01-06/07 주말 제외
+        let gaps = lake.gaps(&STOCK_DAILY, "20240101", "20240108").unwrap();
+        assert_eq!(
+            gaps,
+            vec![
+                Gap {
+                    date: "20240104".to_string(),
+                    kind: GapKind::Empty
+                },
+                Gap {
+                    date: "20240105".to_string(),
+                    kind: GapKind::Missing
+                },
+                Gap {
+                    date: "20240108".to_string(),
+                    kind: GapKind::Missing
+                },
+            ]
+        );
+        fs::remove_dir_all(lake.root()).unwrap();
+    }
+}
@@ -0,0 +1,33 @@
+//! KRX 조회 결과를 로컬에 보관하는 저장소 모듈입니다.
+//!
+//! 각 저장소는 [`Dataset`] 목록을 기준으로 엔드포인트별 데이터를 보관합니다.
+//! 저장 대상 DataFrame은 `data::parse_*` 함수의 결과와 동일한 스키마를 가지므로,
+//! 저장소에서 읽은 데이터는 실시간 조회 결과와 같은 형태로 사용할 수 있습니다.
+//!
+//! 실제 저장소 구현은 cargo feature로 활성화합니다.
+//! - `sqlite`: [`sqlite::SqliteStore`]
+//! - `parquet`: [`lake::ParquetLake`]
+
+#[cfg(feature = "parquet")]
+/// 날짜 분할 Parquet 데이터 레이크
+pub mod lake;
+#[cfg(feature = "sqlite")]
+/// SQLite 기반 증분 동기화 저장소
+pub mod sqlite;
+
+/// 저장소에 보관할 수 있는 엔드포인트
+///
+/// 엔드포인트 레지스트리의 [`EndpointInfo`](crate::api::endpoint::EndpointInfo)를 그대로
+/// 사용합니다. 테이블·파티션 이름은 [`EndpointInfo::name`](crate::api::endpoint::EndpointInfo::name)
+/// (`sto/stk_bydd_trd`), upsert 기준은 `key_columns`입니다. 전체 목록은
+/// [`api::endpoint::ALL`](crate::api::endpoint::ALL), 이름으로 찾을 때는
+/// [`api::endpoint::find`](crate::api::endpoint::find)를 사용합니다.
+///
+/// ```rust
+/// use krx_rs::{api::endpoint::StockDaily, store::Dataset};
+///
+/// const STOCK_DAILY: Dataset = Dataset::of::<StockDaily>();
+/// assert_eq!(STOCK_DAILY.name(), "sto/stk_bydd_trd");
+/// assert_eq!(STOCK_DAILY.key_columns, ["종목코드"]);
+/// ```
+pub type Dataset = crate::api::endpoint::EndpointInfo;
@@ -0,0 +1,521 @@
+//! SQLite 기반 로컬 미러 저장소.
+//!
+//! 엔드포인트([`Dataset`])마다 테이블을 하나씩 만들고, `(기준일자, 식별 컬럼)` 단위로
+//! upsert합니다. 테이블 컬럼은 `parse_*` 결과의 스키마에서 생성되며, 조회 기준일자는
+//! 내부 컬럼 `_base_date`에 함께 저장됩니다.
+//!
+//! 동기화가 끝난 기준일자는 `_krx_sync` 테이블에 엔드포인트별로 기록됩니다.
+//! 데이터와 동기화 기록은 기준일자마다 하나의 트랜잭션으로 저장되므로, 중간에
+//! 중단되더라도 다음 [`SqliteStore::sync`] 호출은 남은 날짜만 다시 조회합니다.
+//! 저장된 데이터는 [`SqliteStore::load`]로 네트워크 없이 읽을 수 있습니다.
+//!
+//! # 예시
+//! ```rust,no_run
+//! # use krx_rs::Client;
+//! use krx_rs::{
+//!     api::endpoint::{KosdaqDaily, StockDaily},
+//!     store::{Dataset, sqlite::SqliteStore},
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), krx_rs::error::Error> {
+//! # let client = Client::new("YOUR_AUTH_KEY");
+//! let mut db = SqliteStore::open("krx.db")?;
+//! let report = db
+//!     .sync(
+//!         &client,
+//!         &[Dataset::of::<StockDaily>(), Dataset::of::<KosdaqDaily>()],
+//!         "20240102",
+//!         "20240131",
+//!     )
+//!     .await?;
+//! println!("{} days fetched", report.fetched.len());
+//!
+//! // 오프라인 조회
+//! let df = db.load(&Dataset::of::<StockDaily>(), "20240102", "20240131")?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::Dataset;
+use crate::{
+    api::common::latest_workday_string,
+    calendar::{DEFAULT_PUBLISH_TIME, TradingCalendar, parse_date, publication_time},
+    client::Client,
+    error::{Error, Result},
+};
+use chrono::{DateTime, Local, Utc};
+use polars::prelude::*;
+use rusqlite::{Connection, OptionalExtension, params, params_from_iter, types::Value};
+use std::{collections::HashSet, path::Path};
+
+/// 조회 기준일자를 저장하는 내부 컬럼
+const BASE_DATE_COLUMN: &str = "_base_date";
+/// 동기화 기록 테이블
+const SYNC_TABLE: &str = "_krx_sync";
+
+/// [`SqliteStore::sync`] 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncReport {
+    /// 새로 조회한 `(엔드포인트, 기준일자)` 목록
+    pub fetched: Vec<(String, String)>,
+    /// 이미 동기화되어 건너뛴 날짜 수
+    pub skipped: usize,
+    /// 저장한 행 수
+    pub rows: usize,
+    /// 조회했지만 아직 데이터가 공개되지 않아 기록하지 않은 `(엔드포인트, 기준일자)` 목록
+    pub pending: Vec<(String, String)>,
+}
+
+/// SQLite 로컬 미러 저장소
+pub struct SqliteStore {
+    conn: Connection,
+    calendar: TradingCalendar,
+}
+
+impl SqliteStore {
+    /// 파일 경로의 데이터베이스를 열거나 새로 만듭니다.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// 메모리 데이터베이스를 엽니다. (테스트용)
+    pub fn open_in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch(&format!(
+            "CREATE TABLE IF NOT EXISTS {SYNC_TABLE} (
+                dataset TEXT NOT NULL,
+                base_date TEXT NOT NULL,
+                row_count INTEGER NOT NULL,
+                synced_at TEXT NOT NULL,
+                PRIMARY KEY (dataset, base_date)
+            )"
+        ))?;
+        Ok(Self {
+            conn,
+            calendar: TradingCalendar::new(),
+        })
+    }
+
+    /// 빈 응답을 휴장일로 판정할 때 사용할 거래일 달력을 설정합니다.
+    pub fn calendar(mut self, calendar: TradingCalendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// 엔드포인트별로 이미 동기화된 기준일자 목록을 반환합니다.
+    pub fn synced_dates(&self, dataset: &Dataset) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT base_date FROM {SYNC_TABLE} WHERE dataset = ?1 ORDER BY base_date"
+        ))?;
+        let dates = stmt
+            .query_map([dataset.name()], |row| row.get(0))?
+            .collect::<std::result::Result<Vec<String>, _>>()?;
+        Ok(dates)
+    }
+
+    /// 기간 안의 거래일 중 아직 동기화되지 않은 날짜 목록을 반환합니다.
+    ///
+    /// 거래일은 [`SqliteStore::calendar`]로 설정한 달력을 따릅니다.
+    pub fn missing_dates(&self, dataset: &Dataset, from: &str, to: &str) -> Result<Vec<String>> {
+        let trading_days = self.calendar.trading_days(from, to)?;
+        self.missing_among(dataset, trading_days)
+    }
+
+    fn missing_among(&self, dataset: &Dataset, dates: Vec<String>) -> Result<Vec<String>> {
+        let synced: HashSet<String> = self.synced_dates(dataset)?.into_iter().collect();
+        Ok(dates
+            .into_iter()
+            .filter(|date| !synced.contains(date))
+            .collect())
+    }
+
+    /// 기간 안의 거래일 중 빠진 기준일자만 조회하여 저장합니다.
+    ///
+    /// 달력상 휴장일은 요청하지 않습니다. 아직 데이터가 제공되지 않는 날짜(가장 최신 거래일 이후)는 건너뜁니다.
+    /// 조회 결과가 비어 있으면 달력상 휴장일이거나 공개 시각이 지난 경우에만 동기화된
+    /// 것으로 기록하고, 그 밖에는 [`SyncReport::pending`]에 남겨 다음 호출에서 다시
+    /// 조회합니다.
+    pub async fn sync(
+        &mut self,
+        client: &Client,
+        datasets: &[Dataset],
+        from: &str,
+        to: &str,
+    ) -> Result<SyncReport> {
+        let latest = latest_workday_string();
+        let mut report = SyncReport::default();
+
+        let trading_days = self.calendar.trading_days(from, to)?;
+        for dataset in datasets {
+            let missing = self.missing_among(dataset, trading_days.clone())?;
+            report.skipped += trading_days.len() - missing.len();
+
+            for date in missing.into_iter().filter(|date| *date <= latest) {
+                let df = dataset.fetch(client, &date).await?;
+                if df.is_empty() && !self.confirmed_empty(&date, Utc::now())? {
+                    tracing::warn!(dataset = dataset.name(), date = %date, "KRX data not published yet");
+                    report.pending.push((dataset.name().to_string(), date));
+                    continue;
+                }
+
+                let rows = self.write(dataset, &date, &df)?;
+                tracing::info!(dataset = dataset.name(), date = %date, rows, "Synced KRX data");
+
+                report.rows += rows;
+                report.fetched.push((dataset.name().to_string(), date));
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// 빈 조회 결과를 확정된 결과로 볼 수 있는지 판정합니다.
+    ///
+    /// 달력상 휴장일이거나 `now`가 해당 기준일자의 공개 시각 이후이면 확정으로 봅니다.
+    fn confirmed_empty(&self, date: &str, now: DateTime<Utc>) -> Result<bool> {
+        let date = parse_date(date)?;
+        Ok(!self.calendar.is_trading_day(date)
+            || now >= publication_time(date, DEFAULT_PUBLISH_TIME)?)
+    }
+
+    /// 한 기준일자의 DataFrame을 upsert하고 동기화 기록을 남깁니다.
+    ///
+    /// 저장한 행 수를 반환합니다.
+    pub fn write(&mut self, dataset: &Dataset, date: &str, df: &DataFrame) -> Result<usize> {
+        let tx = self.conn.transaction()?;
+
+        if !df.is_empty() {
+            ensure_table(&tx, dataset, df)?;
+
+            let names: Vec<String> = std::iter::once(BASE_DATE_COLUMN.to_string())
+                .chain(df.get_column_names().iter().map(|name| name.to_string()))
+                .collect();
+            let placeholders = vec!["?"; names.len()].join(", ");
+            let sql = format!(
+                "INSERT OR REPLACE INTO {} ({}) VALUES ({placeholders})",
+                quote(dataset.name()),
+                names
+                    .iter()
+                    .map(|name| quote(name))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+
+            let columns = df
+                .get_columns()
+                .iter()
+                .map(sql_values)
+                .collect::<Result<Vec<_>>>()?;
+            let mut stmt = tx.prepare(&sql)?;
+            for row in 0..df.height() {
+                let values = std::iter::once(Value::Text(date.to_string()))
+                    .chain(columns.iter().map(|column| column[row].clone()));
+                stmt.execute(params_from_iter(values))?;
+            }
+        }
+
+        tx.execute(
+            &format!("INSERT OR REPLACE INTO {SYNC_TABLE} VALUES (?1, ?2, ?3, ?4)"),
+            params![
+                dataset.name(),
+                date,
+                df.height() as i64,
+                Local::now().to_rfc3339()
+            ],
+        )?;
+        tx.commit()?;
+
+        Ok(df.height())
+    }
+
+    /// 저장된 데이터를 기간(양 끝 포함)으로 읽습니다.
+    ///
+    /// 결과는 `parse_*` 결과와 같은 컬럼 구성이며, 기준일자 순으로 정렬됩니다.
+    pub fn load(&self, dataset: &Dataset, from: &str, to: &str) -> Result<DataFrame> {
+        let schema = table_schema(&self.conn, dataset.name())?;
+        if schema.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+
+        let sql = format!(
+            "SELECT {} FROM {} WHERE {BASE_DATE_COLUMN} BETWEEN ?1 AND ?2 \
+             ORDER BY {BASE_DATE_COLUMN}, rowid",
+            schema
+                .iter()
+                .map(|(name, _)| quote(name))
+                .collect::<Vec<_>>()
+                .join(", "),
+            quote(dataset.name()),
+        );
+        let mut stmt = self.conn.prepare(&sql)?;
+        let mut rows = stmt.query(params![from, to])?;
+
+        let mut values: Vec<Vec<Value>> = vec![Vec::new(); schema.len()];
+        while let Some(row) = rows.next()? {
+            for (i, column) in values.iter_mut().enumerate() {
+                column.push(row.get(i)?);
+            }
+        }
+
+        if values[0].is_empty() {
+            return Ok(DataFrame::empty());
+        }
+
+        let columns = schema
+            .iter()
+            .zip(values)
+            .map(|((name, dtype), values)| polars_column(name, dtype, values))
+            .collect();
+        Ok(DataFrame::new(columns)?)
+    }
+}
+
+fn quote(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+fn sql_type(dtype: &DataType) -> &'static str {
+    match dtype {
+        DataType::Float32 | DataType::Float64 => "REAL",
+        dtype if dtype.is_integer() => "INTEGER",
+        _ => "TEXT",
+    }
+}
+
+/// 테이블이 없으면 만들고, 새 컬럼이 있으면 추가합니다.
+fn ensure_table(conn: &Connection, dataset: &Dataset, df: &DataFrame) -> Result<()> {
+    let existing = table_schema(conn, dataset.name())?;
+
+    if existing.is_empty() {
+        for key in dataset.key_columns {
+            if df.column(key).is_err() {
+                return Err(Error::InvalidInput(format!(
+                    "key column `{key}` not found in `{}` data",
+                    dataset.name()
+                )));
+            }
+        }
+
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(|c| format!("{} {}", quote(c.name()), sql_type(c.dtype())))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let keys = std::iter::once(BASE_DATE_COLUMN)
+            .chain(dataset.key_columns.iter().copied())
+            .map(quote)
+            .collect::<Vec<_>>()
+            .join(", ");
+        conn.execute_batch(&format!(
+            "CREATE TABLE {} ({BASE_DATE_COLUMN} TEXT NOT NULL, {columns}, PRIMARY KEY ({keys}))",
+            quote(dataset.name())
+        ))?;
+        return Ok(());
+    }
+
+    for column in df.get_columns() {
+        if !existing
+            .iter()
+            .any(|(name, _)| name == column.name().as_str())
+        {
+            conn.execute_batch(&format!(
+                "ALTER TABLE {} ADD COLUMN {} {}",
+                quote(dataset.name()),
+                quote(column.name()),
+                sql_type(column.dtype())
+            ))?;
+        }
+    }
+    Ok(())
+}
+
+/// `_base_date`를 제외한 테이블 컬럼과 선언 타입
+fn table_schema(conn: &Connection, table: &str) -> Result<Vec<(String, String)>> {
+    let exists = conn
+        .query_row(
+            "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1",
+            [table],
+            |_| Ok(()),
+        )
+        .optional()?
+        .is_some();
+    if !exists {
+        return Ok(Vec::new());
+    }
+
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", quote(table)))?;
+    let columns = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(1)?, row.get::<_, String>(2)?))
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(columns
+        .into_iter()
+        .filter(|(name, _)| name != BASE_DATE_COLUMN)
+        .collect())
+}
+
+fn sql_values(column: &Column) -> Result<Vec<Value>> {
+    let values = match column.dtype() {
+        DataType::Float32 | DataType::Float64 => column
+            .cast(&DataType::Float64)?
+            .f64()?
+            .into_iter()
+            .map(|v| v.map_or(Value::Null, Value::Real))
+            .collect(),
+        dtype if dtype.is_integer() => column
+            .cast(&DataType::Int64)?
+            .i64()?
+            .into_iter()
+            .map(|v| v.map_or(Value::Null, Value::Integer))
+            .collect(),
+        _ => column
+            .cast(&DataType::String)?
+            .str()?
+            .into_iter()
+            .map(|v| v.map_or(Value::Null, |s| Value::Text(s.to_string())))
+            .collect(),
+    };
+    Ok(values)
+}
+
+fn polars_column(name: &str, sql_type: &str, values: Vec<Value>) -> Column {
+    match sql_type {
+        "REAL" => Column::new(
+            name.into(),
+            values
+                .into_iter()
+                .map(|v| match v {
+                    Value::Real(v) => Some(v),
+                    Value::Integer(v) => Some(v as f64),
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+        ),
+        "INTEGER" => Column::new(
+            name.into(),
+            values
+                .into_iter()
+                .map(|v| match v {
+                    Value::Integer(v) => Some(v),
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+        ),
+        _ => Column::new(
+            name.into(),
+            values
+                .into_iter()
+                .map(|v| match v {
+                    Value::Text(v) => Some(v),
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::endpoint::{BondIndexDaily, StockDaily};
+
+    const STOCK_DAILY: Dataset = Dataset::of::<StockDaily>();
+    const BOND_INDEX_DAILY: Dataset = Dataset::of::<BondIndexDaily>();
+
+    fn daily(date: &str, close: f64) -> DataFrame {
+        df! {
+            "날짜" => [date, date],
+            "종목코드" => ["005930", "000660"],
+            "종목명" => ["삼성전자", "SK하이닉스"],
+            "종가" => [Some(close), None],
+            "거래량" => [Some(100i64), Some(200)],
+        }
+        .unwrap()
+    }
+
+    #[test]
+    fn test_write_and_load_round_trip() {
+        let mut db = SqliteStore::open_in_memory().unwrap();
+        let original = daily("2024-01-05", 71_000.0);
+
+        assert_eq!(db.write(&STOCK_DAILY, "20240105", &original).unwrap(), 2);
+        let loaded = db.load(&STOCK_DAILY, "20240101", "20240131").unwrap();
+
+        assert!(loaded.equals_missing(&original));
+        assert!(
+            db.load(&BOND_INDEX_DAILY, "20240101", "20240131")
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_write_upserts_by_date_and_key() {
+        let mut db = SqliteStore::open_in_memory().unwrap();
+        db.write(&STOCK_DAILY, "20240105", &daily("2024-01-05", 71_000.0))
+            .unwrap();
+        db.write(&STOCK_DAILY, "20240105", &daily("2024-01-05", 72_000.0))
+            .unwrap();
+        db.write(&STOCK_DAILY, "20240108", &daily("2024-01-08", 73_000.0))
+            .unwrap();
+
+        let loaded = db.load(&STOCK_DAILY, "20240101", "20240131").unwrap();
+        assert_eq!(loaded.height(), 4);
+        assert_eq!(
+            loaded.column("종가").unwrap().f64().unwrap().get(0),
+            Some(72_000.0)
+        );
+    }
+
+    #[test]
+    fn test_missing_dates() {
+        let mut db = SqliteStore::open_in_memory().unwrap();
+        db.write(&STOCK_DAILY, "20240105", &daily("2024-01-05", 71_000.0))
+            .unwrap();
+        // 휴장일처럼 빈 데이터도 동기화된 것으로 기록
+        db.write(&STOCK_DAILY, "20240103", &DataFrame::empty())
+            .unwrap();
+
+        assert_eq!(
+            db.missing_dates(&STOCK_DAILY, "20240102", "20240108")
+                .unwrap(),
+            vec!["20240102", "20240104", "20240108"]
+        );
+        assert_eq!(
+            db.missing_dates(&BOND_INDEX_DAILY, "20240105", "20240105")
+                .unwrap(),
+            vec!["20240105"]
+        );
+    }
+
+    #[test]
+    fn test_confirmed_empty() {
+        let db = SqliteStore::open_in_memory()
+            .unwrap()
+            .calendar(TradingCalendar::new().with_holidays(["20240209"]).unwrap());
+        let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().to_utc();
+
+        // 거래일은 다음 날 08:00(KST) 공개 이후에만 빈 결과를 확정
+        let before = at("2024-01-08T22:59:00Z");
+        let after = at("2024-01-08T23:00:00Z");
+        assert!(!db.confirmed_empty("20240108", before).unwrap());
+        assert!(db.confirmed_empty("20240108", after).unwrap());
+        // 휴장일은 공개 시각과 무관하게 확정
+        assert!(db.confirmed_empty("20240101", before).unwrap());
+        assert!(db.confirmed_empty("20240209", before).unwrap());
+    }
+
+    #[test]
+    fn test_write_rejects_missing_key_column() {
+        let mut db = SqliteStore::open_in_memory().unwrap();
+        let result = db.write(&BOND_INDEX_DAILY, "20240105", &daily("2024-01-05", 1.0));
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+        assert!(db.synced_dates(&BOND_INDEX_DAILY).unwrap().is_empty());
+    }
+}
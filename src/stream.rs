@@ -0,0 +1,162 @@
+//! 기간 조회를 날짜별 DataFrame 스트림으로 제공합니다.
+//!
+//! 수년 단위의 백필을 하나의 DataFrame으로 모으지 않고, 거래일마다 `(날짜, DataFrame)`을
+//! 날짜 순서대로 내보냅니다. [`TradingCalendar`] 기준 휴장일은 요청하지 않으며,
+//! 조회 결과가 비어 있는 날(달력에 없는 휴장일 등)은 건너뜁니다.
+//!
+//! - `concurrency`: 동시에 보내는 요청 수 (기본값: [`DEFAULT_CONCURRENCY`])
+//! - `prefetch`: 소비자가 가져가기 전에 미리 받아 둘 결과 수 (기본값: [`DEFAULT_PREFETCH`]).
+//!   0보다 크면 백그라운드 태스크가 요청을 계속 진행합니다.
+//!
+//! # 예시
+//! ```rust,no_run
+//! use futures::StreamExt;
+//! use krx_rs::{Client, api::endpoint::StockDaily};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), krx_rs::error::Error> {
+//! let client = Client::new("YOUR_AUTH_KEY");
+//!
+//! let mut days = client
+//!     .fetch_range::<StockDaily>("20100104", "20241231")
+//!     .concurrency(4)
+//!     .prefetch(8)
+//!     .stream()?;
+//! while let Some(day) = days.next().await {
+//!     let (date, df) = day?;
+//!     println!("{date}: {} rows", df.height());
+//! }
+//!
+//! // 빌더로 만든 조회도 스트림으로 만들 수 있습니다.
+//! let etfs = krx_rs::stream::RangeStreamBuilder::new("20240101", "20240131", move |date| {
+//!     client.etp().etf_daily().date(date).fetch()
+//! })
+//! .stream()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    calendar::TradingCalendar,
+    error::{Error, Result},
+};
+use chrono::NaiveDate;
+use futures::{
+    StreamExt,
+    future::{self, BoxFuture},
+    stream::{self, BoxStream},
+};
+use polars::prelude::DataFrame;
+use std::{future::Future, sync::Arc};
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// 기본 동시 요청 수
+pub const DEFAULT_CONCURRENCY: usize = 4;
+/// 기본 선행 조회 수
+pub const DEFAULT_PREFETCH: usize = 2;
+
+/// 기준일자 하나를 조회하는 함수
+type DateFetchFn = Arc<dyn Fn(String) -> BoxFuture<'static, Result<DataFrame>> + Send + Sync>;
+
+/// 기간 조회 스트림 빌더
+#[must_use = "Builder does nothing unless you call .stream()"]
+pub struct RangeStreamBuilder {
+    start_date: String,
+    end_date: String,
+    fetch: DateFetchFn,
+    calendar: TradingCalendar,
+    concurrency: usize,
+    prefetch: usize,
+}
+
+impl RangeStreamBuilder {
+    /// 기준일자(YYYYMMDD)마다 `fetch`를 호출하는 기간 스트림 빌더를 만듭니다.
+    ///
+    /// 카테고리별 빌더의 `fetch()`를 감싸면 어떤 엔드포인트든 스트림으로 조회할 수 있습니다.
+    pub fn new<F, Fut>(start_date: impl Into<String>, end_date: impl Into<String>, fetch: F) -> Self
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<DataFrame>> + Send + 'static,
+    {
+        Self {
+            start_date: start_date.into(),
+            end_date: end_date.into(),
+            fetch: Arc::new(move |date| Box::pin(fetch(date))),
+            calendar: TradingCalendar::new(),
+            concurrency: DEFAULT_CONCURRENCY,
+            prefetch: DEFAULT_PREFETCH,
+        }
+    }
+
+    /// 요청할 거래일을 정할 달력 (기본값: [`TradingCalendar::new`], 내장 KRX 휴장일표 포함)
+    pub fn calendar(mut self, calendar: TradingCalendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// 동시에 보낼 최대 요청 수 (최소 1)
+    pub fn concurrency(mut self, limit: usize) -> Self {
+        self.concurrency = limit.max(1);
+        self
+    }
+
+    /// 소비자보다 앞서 받아 둘 결과 수 (0이면 소비할 때만 요청을 진행)
+    pub fn prefetch(mut self, count: usize) -> Self {
+        self.prefetch = count;
+        self
+    }
+
+    /// 날짜 순서대로 `(기준일, DataFrame)`을 내보내는 스트림을 만듭니다.
+    ///
+    /// 조회에 실패한 날은 `Err` 항목으로 전달되며, 이후 날짜의 조회는 계속됩니다.
+    ///
+    /// # Errors
+    /// - 시작일·종료일 형식이 잘못되었거나 시작일이 종료일보다 늦으면 `Error::InvalidInput` 반환.
+    pub fn stream(self) -> Result<BoxStream<'static, Result<(NaiveDate, DataFrame)>>> {
+        let dates = self
+            .calendar
+            .trading_days(&self.start_date, &self.end_date)?
+            .into_iter()
+            .map(|date| {
+                NaiveDate::parse_from_str(&date, "%Y%m%d")
+                    .map(|parsed| (date, parsed))
+                    .map_err(|_| Error::InvalidInput("date must be in YYYYMMDD format".to_string()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        debug!(
+            start_date = %self.start_date,
+            end_date = %self.end_date,
+            trading_days = dates.len(),
+            "Starting range stream"
+        );
+
+        let fetch = self.fetch;
+        let days = stream::iter(dates)
+            .map(move |(date, parsed)| {
+                let request = fetch(date);
+                async move { request.await.map(|df| (parsed, df)) }
+            })
+            .buffered(self.concurrency)
+            .filter(|day| future::ready(!matches!(day, Ok((_, df)) if df.height() == 0)));
+
+        if self.prefetch == 0 {
+            return Ok(days.boxed());
+        }
+
+        let (sender, receiver) = mpsc::channel(self.prefetch);
+        tokio::spawn(async move {
+            let mut days = days;
+            while let Some(day) = days.next().await {
+                // 소비자가 스트림을 버리면 남은 요청을 중단합니다.
+                if sender.send(day).await.is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(stream::unfold(receiver, |mut receiver| async move {
+            receiver.recv().await.map(|day| (day, receiver))
+        })
+        .boxed())
+    }
+}
@@ -0,0 +1,893 @@
+//! 레코드 품질 검사와 이상치 보고서.
+//!
+//! KRX 응답에는 드물게 시가·고가·저가·종가 순서가 맞지 않거나, 거래량이 0인데 종가가
+//! 있거나, 같은 종목코드가 두 번 나오거나, 상장주식수가 갑자기 크게 바뀌는 행이 섞여
+//! 있습니다. [`ValidationRules`]는 `data::*`의 레코드를 검사하여 규칙 위반을
+//! [`ValidationReport`]로 모아 줍니다. 파이프라인은 [`ValidationReport::is_valid`]로
+//! 적재 여부를 결정할 수 있습니다.
+//!
+//! 검사할 수 있는 레코드는 [`Validate`]를 구현하며, 엔드포인트 종류별 기본 규칙은
+//! [`ValidationRules::for_endpoint`]로 가져옵니다.
+//!
+//! # 예시
+//! ```rust,no_run
+//! use krx_rs::{Client, api::endpoint::StockDaily, validate::{Rule, Severity, ValidationRules}};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), krx_rs::error::Error> {
+//! let client = Client::new("YOUR_AUTH_KEY");
+//! let previous = client.fetch_records::<StockDaily>("20240104").await?;
+//! let current = client.fetch_records::<StockDaily>("20240105").await?;
+//!
+//! let rules = ValidationRules::for_endpoint::<StockDaily>()
+//!     .severity(Rule::ListedSharesChange, Severity::Error)
+//!     .max_listed_shares_change(0.5);
+//! let report = rules.validate_against(&previous, &current);
+//! for issue in report.issues() {
+//!     println!("{issue}");
+//! }
+//! if !report.is_valid() {
+//!     eprintln!("{} errors, skipping load", report.errors().count());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    api::endpoint::Endpoint,
+    data::{bond, derivative, esg, etp, general, index, stock},
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+/// 상장주식수 변화율 기본 한도 (20%)
+pub const DEFAULT_MAX_LISTED_SHARES_CHANGE: f64 = 0.2;
+/// 종가 변화율 기본 한도 (가격제한폭 30%)
+pub const DEFAULT_MAX_CLOSE_CHANGE: f64 = 0.3;
+
+/// 검사 규칙
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rule {
+    /// 저가 ≤ 시가·종가 ≤ 고가 (거래가 있는 행만)
+    OhlcOrdering,
+    /// 가격·거래량·거래대금이 음수가 아님
+    NegativeValue,
+    /// 거래량이 0이면 종가가 없어야 함
+    ZeroVolumeWithClose,
+    /// 같은 키(종목코드 등)가 한 번만 나옴
+    DuplicateKey,
+    /// 전일 대비 상장주식수 변화율이 한도 이내
+    ListedSharesChange,
+    /// 전일 대비 종가 변화율이 한도 이내
+    CloseChange,
+}
+
+impl Rule {
+    /// 모든 규칙
+    pub const ALL: [Rule; 6] = [
+        Rule::OhlcOrdering,
+        Rule::NegativeValue,
+        Rule::ZeroVolumeWithClose,
+        Rule::DuplicateKey,
+        Rule::ListedSharesChange,
+        Rule::CloseChange,
+    ];
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Rule::OhlcOrdering => "ohlc_ordering",
+            Rule::NegativeValue => "negative_value",
+            Rule::ZeroVolumeWithClose => "zero_volume_with_close",
+            Rule::DuplicateKey => "duplicate_key",
+            Rule::ListedSharesChange => "listed_shares_change",
+            Rule::CloseChange => "close_change",
+        };
+        f.write_str(name)
+    }
+}
+
+/// 위반의 심각도
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// 기록만 하고 통과
+    Warning,
+    /// [`ValidationReport::is_valid`]를 실패시킴
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => f.write_str("warning"),
+            Severity::Error => f.write_str("error"),
+        }
+    }
+}
+
+/// 검사할 수 있는 레코드
+///
+/// 레코드에 없는 값은 기본 구현대로 `None`을 반환하며, `None`인 값은 검사하지 않습니다.
+pub trait Validate {
+    /// 행을 식별하는 키 (종목코드 등)
+    fn key(&self) -> String;
+
+    /// 시가
+    fn open(&self) -> Option<f64> {
+        None
+    }
+
+    /// 고가
+    fn high(&self) -> Option<f64> {
+        None
+    }
+
+    /// 저가
+    fn low(&self) -> Option<f64> {
+        None
+    }
+
+    /// 종가
+    fn close(&self) -> Option<f64> {
+        None
+    }
+
+    /// 거래량
+    fn volume(&self) -> Option<f64> {
+        None
+    }
+
+    /// 거래대금
+    fn trading_value(&self) -> Option<f64> {
+        None
+    }
+
+    /// 상장주식수
+    fn listed_shares(&self) -> Option<f64> {
+        None
+    }
+
+    /// 이 레코드 종류의 기본 규칙
+    fn default_rules() -> ValidationRules
+    where
+        Self: Sized,
+    {
+        ValidationRules::default()
+    }
+}
+
+/// 규칙 하나의 위반
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationIssue {
+    /// 위반한 규칙
+    pub rule: Rule,
+    /// 심각도
+    pub severity: Severity,
+    /// 검사한 레코드 목록에서의 행 번호 (0부터)
+    pub row: usize,
+    /// 행의 키
+    pub key: String,
+    /// 설명
+    pub message: String,
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}] {} row {} ({}): {}",
+            self.severity, self.rule, self.row, self.key, self.message
+        )
+    }
+}
+
+/// 검사 결과
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport {
+    records: usize,
+    issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// 검사한 레코드 수
+    pub fn records(&self) -> usize {
+        self.records
+    }
+
+    /// 모든 위반 (행 순)
+    pub fn issues(&self) -> &[ValidationIssue] {
+        &self.issues
+    }
+
+    /// `Error` 심각도의 위반
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Error)
+    }
+
+    /// `Warning` 심각도의 위반
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Warning)
+    }
+
+    /// 규칙별 위반
+    pub fn by_rule(&self, rule: Rule) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter().filter(move |issue| issue.rule == rule)
+    }
+
+    /// 규칙별 위반 수
+    pub fn counts(&self) -> BTreeMap<Rule, usize> {
+        let mut counts = BTreeMap::new();
+        for issue in &self.issues {
+            *counts.entry(issue.rule).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// 가장 높은 심각도 (위반이 없으면 `None`)
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.issues.iter().map(|issue| issue.severity).max()
+    }
+
+    /// `Error` 심각도의 위반이 없는지 여부
+    pub fn is_valid(&self) -> bool {
+        self.errors().next().is_none()
+    }
+
+    /// 위반이 하나도 없는지 여부
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// 규칙별 사용 여부·심각도와 변화율 한도
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationRules {
+    severities: BTreeMap<Rule, Severity>,
+    max_listed_shares_change: f64,
+    max_close_change: f64,
+}
+
+impl Default for ValidationRules {
+    /// 순서·음수·중복은 `Error`, 나머지는 `Warning`으로 모든 규칙을 사용합니다.
+    fn default() -> Self {
+        let severities = Rule::ALL
+            .into_iter()
+            .map(|rule| {
+                let severity = match rule {
+                    Rule::OhlcOrdering | Rule::NegativeValue | Rule::DuplicateKey => {
+                        Severity::Error
+                    }
+                    Rule::ZeroVolumeWithClose | Rule::ListedSharesChange | Rule::CloseChange => {
+                        Severity::Warning
+                    }
+                };
+                (rule, severity)
+            })
+            .collect();
+        Self {
+            severities,
+            max_listed_shares_change: DEFAULT_MAX_LISTED_SHARES_CHANGE,
+            max_close_change: DEFAULT_MAX_CLOSE_CHANGE,
+        }
+    }
+}
+
+impl ValidationRules {
+    /// 기본 규칙 ([`ValidationRules::default`])
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 레코드 종류의 기본 규칙
+    pub fn for_record<R: Validate>() -> Self {
+        R::default_rules()
+    }
+
+    /// 엔드포인트 종류의 기본 규칙
+    pub fn for_endpoint<E>() -> Self
+    where
+        E: Endpoint,
+        E::Record: Validate,
+    {
+        Self::for_record::<E::Record>()
+    }
+
+    /// 규칙을 사용하고 심각도를 지정합니다.
+    pub fn severity(mut self, rule: Rule, severity: Severity) -> Self {
+        self.severities.insert(rule, severity);
+        self
+    }
+
+    /// 규칙을 사용하지 않습니다.
+    pub fn disable(mut self, rule: Rule) -> Self {
+        self.severities.remove(&rule);
+        self
+    }
+
+    /// 전일 대비 상장주식수 변화율 한도 (기본값: [`DEFAULT_MAX_LISTED_SHARES_CHANGE`])
+    pub fn max_listed_shares_change(mut self, ratio: f64) -> Self {
+        self.max_listed_shares_change = ratio;
+        self
+    }
+
+    /// 전일 대비 종가 변화율 한도 (기본값: [`DEFAULT_MAX_CLOSE_CHANGE`])
+    pub fn max_close_change(mut self, ratio: f64) -> Self {
+        self.max_close_change = ratio;
+        self
+    }
+
+    /// 규칙의 심각도 (사용하지 않으면 `None`)
+    pub fn severity_of(&self, rule: Rule) -> Option<Severity> {
+        self.severities.get(&rule).copied()
+    }
+
+    /// 하루치 레코드를 검사합니다. 전일 비교 규칙은 적용되지 않습니다.
+    pub fn validate<R: Validate>(&self, records: &[R]) -> ValidationReport {
+        let mut report = ValidationReport {
+            records: records.len(),
+            issues: Vec::new(),
+        };
+        let mut seen: HashMap<String, usize> = HashMap::new();
+
+        for (row, record) in records.iter().enumerate() {
+            let key = record.key();
+            self.check_ohlc(&mut report, row, &key, record);
+            self.check_negative(&mut report, row, &key, record);
+
+            if record.volume() == Some(0.0) && record.close().is_some_and(|close| close != 0.0) {
+                self.push(
+                    &mut report,
+                    Rule::ZeroVolumeWithClose,
+                    row,
+                    &key,
+                    format!(
+                        "zero volume with close {}",
+                        record.close().unwrap_or_default()
+                    ),
+                );
+            }
+
+            if let Some(first) = seen.get(&key) {
+                self.push(
+                    &mut report,
+                    Rule::DuplicateKey,
+                    row,
+                    &key,
+                    format!("duplicate key, first seen at row {first}"),
+                );
+            } else {
+                seen.insert(key, row);
+            }
+        }
+        report
+    }
+
+    /// 당일 레코드를 검사하고, 키가 같은 전일 레코드와 비교하는 규칙도 적용합니다.
+    pub fn validate_against<R: Validate>(&self, previous: &[R], current: &[R]) -> ValidationReport {
+        let mut report = self.validate(current);
+        let previous: HashMap<String, &R> = previous
+            .iter()
+            .map(|record| (record.key(), record))
+            .collect();
+
+        for (row, record) in current.iter().enumerate() {
+            let key = record.key();
+            let Some(prev) = previous.get(&key) else {
+                continue;
+            };
+            if let Some(change) = change_ratio(prev.listed_shares(), record.listed_shares())
+                .filter(|change| *change > self.max_listed_shares_change)
+            {
+                self.push(
+                    &mut report,
+                    Rule::ListedSharesChange,
+                    row,
+                    &key,
+                    format!(
+                        "listed shares changed {:.1}% ({} -> {})",
+                        change * 100.0,
+                        prev.listed_shares().unwrap_or_default(),
+                        record.listed_shares().unwrap_or_default()
+                    ),
+                );
+            }
+            if let Some(change) = change_ratio(prev.close(), record.close())
+                .filter(|change| *change > self.max_close_change)
+            {
+                self.push(
+                    &mut report,
+                    Rule::CloseChange,
+                    row,
+                    &key,
+                    format!(
+                        "close changed {:.1}% ({} -> {})",
+                        change * 100.0,
+                        prev.close().unwrap_or_default(),
+                        record.close().unwrap_or_default()
+                    ),
+                );
+            }
+        }
+        report.issues.sort_by_key(|issue| issue.row);
+        report
+    }
+
+    fn check_ohlc<R: Validate>(
+        &self,
+        report: &mut ValidationReport,
+        row: usize,
+        key: &str,
+        record: &R,
+    ) {
+        // 거래가 없는 행은 시가·고가·저가가 0으로 오므로 순서를 보지 않습니다.
+        if record.volume() == Some(0.0) {
+            return;
+        }
+        let (Some(open), Some(high), Some(low), Some(close)) =
+            (record.open(), record.high(), record.low(), record.close())
+        else {
+            return;
+        };
+        if low > high || open < low || open > high || close < low || close > high {
+            self.push(
+                report,
+                Rule::OhlcOrdering,
+                row,
+                key,
+                format!("open {open}, high {high}, low {low}, close {close} out of order"),
+            );
+        }
+    }
+
+    fn check_negative<R: Validate>(
+        &self,
+        report: &mut ValidationReport,
+        row: usize,
+        key: &str,
+        record: &R,
+    ) {
+        let values = [
+            ("open", record.open()),
+            ("high", record.high()),
+            ("low", record.low()),
+            ("close", record.close()),
+            ("volume", record.volume()),
+            ("trading value", record.trading_value()),
+            ("listed shares", record.listed_shares()),
+        ];
+        for (name, value) in values {
+            if let Some(value) = value.filter(|value| *value < 0.0) {
+                self.push(
+                    report,
+                    Rule::NegativeValue,
+                    row,
+                    key,
+                    format!("negative {name} {value}"),
+                );
+            }
+        }
+    }
+
+    fn push(
+        &self,
+        report: &mut ValidationReport,
+        rule: Rule,
+        row: usize,
+        key: &str,
+        message: String,
+    ) {
+        if let Some(severity) = self.severity_of(rule) {
+            report.issues.push(ValidationIssue {
+                rule,
+                severity,
+                row,
+                key: key.to_string(),
+                message,
+            });
+        }
+    }
+}
+
+/// 전일 대비 변화율의 절댓값 (전일 값이 없거나 0이면 `None`)
+fn change_ratio(previous: Option<f64>, current: Option<f64>) -> Option<f64> {
+    match (previous, current) {
+        (Some(previous), Some(current)) if previous != 0.0 => {
+            Some(((current - previous) / previous).abs())
+        }
+        _ => None,
+    }
+}
+
+/// 문자열 가격(옵션 등)을 숫자로 변환합니다.
+fn parse_price(value: &str) -> Option<f64> {
+    value.replace(',', "").trim().parse().ok()
+}
+
+macro_rules! impl_validate {
+    (
+        $record:ty {
+            key: |$r:ident| $key:expr
+            $(, ohlc: ($open:ident, $high:ident, $low:ident, $close:ident))?
+            $(, close: $close_only:ident)?
+            $(, volume: $volume:ident)?
+            $(, trading_value: $value:ident)?
+            $(, listed_shares: $shares:ident)?
+            $(, rules: $rules:expr)?
+            $(,)?
+        }
+    ) => {
+        impl Validate for $record {
+            fn key(&self) -> String {
+                let $r = self;
+                $key
+            }
+
+            $(
+                fn open(&self) -> Option<f64> {
+                    self.$open
+                }
+
+                fn high(&self) -> Option<f64> {
+                    self.$high
+                }
+
+                fn low(&self) -> Option<f64> {
+                    self.$low
+                }
+
+                fn close(&self) -> Option<f64> {
+                    self.$close
+                }
+            )?
+
+            $(
+                fn close(&self) -> Option<f64> {
+                    self.$close_only
+                }
+            )?
+
+            $(
+                fn volume(&self) -> Option<f64> {
+                    self.$volume.map(|v| v as f64)
+                }
+            )?
+
+            $(
+                fn trading_value(&self) -> Option<f64> {
+                    self.$value.map(|v| v as f64)
+                }
+            )?
+
+            $(
+                fn listed_shares(&self) -> Option<f64> {
+                    self.$shares.map(|v| v as f64)
+                }
+            )?
+
+            $(
+                fn default_rules() -> ValidationRules {
+                    $rules
+                }
+            )?
+        }
+    };
+}
+
+impl_validate!(stock::StockDailyRecord {
+    key: |r| r.issue_code.clone(),
+    ohlc: (open_price, high_price, low_price, close_price),
+    volume: trading_volume,
+    trading_value: trading_value,
+    listed_shares: listed_shares,
+});
+
+// 신주인수권증권·증서는 가격제한폭이 없어 종가 변화율을 보지 않습니다.
+impl_validate!(stock::StockWarrantDailyRecord {
+    key: |r| r.issue_code.clone(),
+    ohlc: (open_price, high_price, low_price, close_price),
+    volume: trading_volume,
+    trading_value: trading_value,
+    listed_shares: listed_shares,
+    rules: ValidationRules::default().disable(Rule::CloseChange),
+});
+
+impl_validate!(stock::StockRightDailyRecord {
+    key: |r| r.issue_code.clone(),
+    ohlc: (open_price, high_price, low_price, close_price),
+    volume: trading_volume,
+    trading_value: trading_value,
+    listed_shares: listed_shares,
+    rules: ValidationRules::default().disable(Rule::CloseChange),
+});
+
+impl_validate!(stock::StockBaseInfoRecord {
+    key: |r| r.issue_code.clone(),
+    listed_shares: listed_shares,
+});
+
+impl_validate!(index::KrxIndexDailyRecord {
+    key: |r| format!("{}/{}", r.index_class, r.index_name),
+    ohlc: (open_price, high_price, low_price, close_price),
+    volume: trading_volume,
+    trading_value: trading_value,
+});
+
+impl_validate!(index::DerivativeIndexDailyRecord {
+    key: |r| format!("{}/{}", r.index_class, r.index_name),
+    ohlc: (open_price, high_price, low_price, close_price),
+});
+
+impl_validate!(index::BondIndexDailyRecord {
+    key: |r| r.bond_index_group_name.clone(),
+    close: total_earning_index,
+});
+
+impl_validate!(bond::KtsDailyRecord {
+    key: |r| r.issue_code.clone(),
+    ohlc: (open_price, high_price, low_price, close_price),
+    volume: trading_volume,
+    trading_value: trading_value,
+});
+
+impl_validate!(bond::BondDailyRecord {
+    key: |r| r.issue_code.clone(),
+    ohlc: (open_price, high_price, low_price, close_price),
+    volume: trading_volume,
+    trading_value: trading_value,
+});
+
+impl_validate!(etp::EtfDailyRecord {
+    key: |r| r.issue_code.clone(),
+    ohlc: (open_price, high_price, low_price, close_price),
+    volume: trading_volume,
+    trading_value: trading_value,
+    listed_shares: listed_shares,
+});
+
+impl_validate!(etp::EtnDailyRecord {
+    key: |r| r.issue_code.clone(),
+    ohlc: (open_price, high_price, low_price, close_price),
+    volume: trading_volume,
+    trading_value: trading_value,
+    listed_shares: listed_shares,
+});
+
+// ELW는 기초자산에 따라 하루에 수 배씩 움직이므로 종가 변화율을 보지 않습니다.
+impl_validate!(etp::ElwDailyRecord {
+    key: |r| r.issue_code.clone(),
+    ohlc: (open_price, high_price, low_price, close_price),
+    volume: trading_volume,
+    trading_value: trading_value,
+    listed_shares: listed_shares,
+    rules: ValidationRules::default().disable(Rule::CloseChange),
+});
+
+// 선물은 스프레드 종목의 가격이 음수일 수 있고, 만기 근처에는 변화율이 큽니다.
+impl_validate!(derivative::FuturesDailyRecord {
+    key: |r| r.issue_code.clone(),
+    ohlc: (open_price, high_price, low_price, close_price),
+    volume: trading_volume,
+    trading_value: trading_value,
+    rules: ValidationRules::default()
+        .disable(Rule::NegativeValue)
+        .disable(Rule::CloseChange),
+});
+
+impl_validate!(derivative::EquityKosdaqFuturesDailyRecord {
+    key: |r| r.issue_code.clone(),
+    close: close_price,
+    volume: trading_volume,
+    trading_value: trading_value,
+    rules: ValidationRules::default()
+        .disable(Rule::NegativeValue)
+        .disable(Rule::CloseChange),
+});
+
+impl Validate for derivative::OptionsDailyRecord {
+    fn key(&self) -> String {
+        self.issue_code.clone()
+    }
+
+    fn open(&self) -> Option<f64> {
+        parse_price(&self.open_price)
+    }
+
+    fn high(&self) -> Option<f64> {
+        parse_price(&self.high_price)
+    }
+
+    fn low(&self) -> Option<f64> {
+        parse_price(&self.low_price)
+    }
+
+    fn close(&self) -> Option<f64> {
+        parse_price(&self.close_price)
+    }
+
+    fn volume(&self) -> Option<f64> {
+        self.trading_volume.map(|v| v as f64)
+    }
+
+    fn trading_value(&self) -> Option<f64> {
+        self.trading_value.map(|v| v as f64)
+    }
+
+    /// 옵션 프리미엄은 하루에 수 배씩 움직이므로 종가 변화율을 보지 않습니다.
+    fn default_rules() -> ValidationRules {
+        ValidationRules::default().disable(Rule::CloseChange)
+    }
+}
+
+impl_validate!(general::OilDailyRecord {
+    key: |r| r.oil_name.clone(),
+    close: weighted_average_price,
+    volume: trading_volume,
+    trading_value: trading_value,
+});
+
+impl_validate!(general::GoldDailyRecord {
+    key: |r| r.issue_code.clone(),
+    ohlc: (open_price, high_price, low_price, close_price),
+    volume: trading_volume,
+    trading_value: trading_value,
+});
+
+impl_validate!(general::EmissionsDailyRecord {
+    key: |r| r.issue_code.clone(),
+    ohlc: (open_price, high_price, low_price, close_price),
+    volume: trading_volume,
+    trading_value: trading_value,
+});
+
+impl_validate!(esg::SriBondInfoRecord {
+    key: |r| r.issue_code.clone(),
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Row {
+        code: &'static str,
+        ohlc: Option<(f64, f64, f64, f64)>,
+        volume: Option<f64>,
+        shares: Option<f64>,
+    }
+
+    impl Validate for Row {
+        fn key(&self) -> String {
+            self.code.to_string()
+        }
+
+        fn open(&self) -> Option<f64> {
+            self.ohlc.map(|p| p.0)
+        }
+
+        fn high(&self) -> Option<f64> {
+            self.ohlc.map(|p| p.1)
+        }
+
+        fn low(&self) -> Option<f64> {
+            self.ohlc.map(|p| p.2)
+        }
+
+        fn close(&self) -> Option<f64> {
+            self.ohlc.map(|p| p.3)
+        }
+
+        fn volume(&self) -> Option<f64> {
+            self.volume
+        }
+
+        fn listed_shares(&self) -> Option<f64> {
+            self.shares
+        }
+    }
+
+    fn row(code: &'static str, ohlc: (f64, f64, f64, f64), volume: f64) -> Row {
+        Row {
+            code,
+            ohlc: Some(ohlc),
+            volume: Some(volume),
+            ..Row::default()
+        }
+    }
+
+    #[test]
+    fn test_single_day_rules() {
+        let rows = [
+            row("A", (100.0, 110.0, 95.0, 105.0), 10.0),
+            row("B", (100.0, 99.0, 95.0, 105.0), 10.0),
+            row("C", (0.0, 0.0, 0.0, 105.0), 0.0),
+            row("A", (100.0, 110.0, 95.0, 105.0), 10.0),
+            row("D", (-1.0, 110.0, -1.0, 105.0), 10.0),
+        ];
+        let report = ValidationRules::default().validate(&rows);
+
+        assert_eq!(report.records(), 5);
+        let rules: Vec<(Rule, usize)> = report.issues().iter().map(|i| (i.rule, i.row)).collect();
+        assert_eq!(
+            rules,
+            vec![
+                (Rule::OhlcOrdering, 1),
+                (Rule::ZeroVolumeWithClose, 2),
+                (Rule::DuplicateKey, 3),
+                (Rule::NegativeValue, 4),
+                (Rule::NegativeValue, 4),
+            ]
+        );
+        assert_eq!(report.warnings().count(), 1);
+        assert_eq!(report.max_severity(), Some(Severity::Error));
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn test_rule_configuration() {
+        let rows = [
+            row("A", (100.0, 99.0, 95.0, 105.0), 10.0),
+            row("B", (0.0, 0.0, 0.0, 105.0), 0.0),
+        ];
+        let rules = ValidationRules::default()
+            .severity(Rule::OhlcOrdering, Severity::Warning)
+            .disable(Rule::ZeroVolumeWithClose);
+        let report = rules.validate(&rows);
+
+        assert_eq!(report.issues().len(), 1);
+        assert!(report.is_valid());
+        assert!(!report.is_clean());
+        assert_eq!(rules.severity_of(Rule::ZeroVolumeWithClose), None);
+    }
+
+    #[test]
+    fn test_change_rules_against_previous_day() {
+        let shares = |code, shares| Row {
+            code,
+            shares: Some(shares),
+            ..Row::default()
+        };
+        let previous = [shares("A", 1000.0), shares("B", 1000.0), shares("C", 0.0)];
+        let current = [
+            shares("A", 1100.0),
+            shares("B", 1500.0),
+            shares("C", 100.0),
+            shares("D", 1.0),
+        ];
+        let report = ValidationRules::default().validate_against(&previous, &current);
+
+        assert_eq!(report.counts().get(&Rule::ListedSharesChange), Some(&1));
+        assert_eq!(report.issues()[0].key, "B");
+        assert!(report.is_valid());
+
+        let strict = ValidationRules::default().max_listed_shares_change(0.05);
+        assert_eq!(
+            strict.validate_against(&previous, &current).issues().len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_endpoint_default_rules() {
+        use crate::api::endpoint::{ElwDaily, FuturesDaily, StockDaily};
+
+        assert_eq!(
+            ValidationRules::for_endpoint::<StockDaily>(),
+            ValidationRules::default()
+        );
+        assert_eq!(
+            ValidationRules::for_endpoint::<ElwDaily>().severity_of(Rule::CloseChange),
+            None
+        );
+        assert_eq!(
+            ValidationRules::for_endpoint::<FuturesDaily>().severity_of(Rule::NegativeValue),
+            None
+        );
+    }
+}
@@ -394,3 +394,76 @@ async fn test_esg_bond_fluctuation_types() {
         Some("지배구조 채권")
     );
 }
+
+#[tokio::test]
+async fn test_sri_bond_info_with_prices() {
+    let info_body = r#"{
+        "OutBlock_1": [{
+            "BAS_DD": "20240105",
+            "ISUR_NM": "한국전력공사",
+            "ISU_CD": "KR350101GC12",
+            "SRI_BND_TP_NM": "녹색채권",
+            "ISU_NM": "한국전력1234",
+            "LIST_DD": "20220105",
+            "ISU_DD": "20220105",
+            "REDMPT_DD": "20260105",
+            "ISU_RT": "3.5",
+            "ISU_AMT": "100000000000",
+            "LIST_AMT": "100000000000",
+            "BND_TP_NM": "특수채"
+        }]
+    }"#;
+    let prices_body = r#"{
+        "OutBlock_1": [{
+            "BAS_DD": "20240105",
+            "ISU_CD": "KR350101GC12",
+            "ISU_NM": "한국전력1234",
+            "MKT_NM": "일반채권",
+            "CLSPRC": "9950.5",
+            "CLSPRC_YD": "3.612",
+            "OPNPRC": "9950.5",
+            "OPNPRC_YD": "3.612",
+            "HGPRC": "9950.5",
+            "HGPRC_YD": "3.612",
+            "LWPRC": "9950.5",
+            "LWPRC_YD": "3.612",
+            "CMPPREVDD_PRC": "10.0",
+            "ACC_TRDVOL": "1000000",
+            "ACC_TRDVAL": "995050000"
+        }]
+    }"#;
+
+    let (client, server) = setup_esg_test("/esg/sri_bond_info", "20240105", info_body, 200).await;
+    Mock::given(method("GET"))
+        .and(path("/bon/bnd_bydd_trd"))
+        .and(query_param("basDd", "20240105"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(prices_body))
+        .mount(&server)
+        .await;
+
+    let df = client
+        .esg()
+        .sri_bond_info()
+        .date("20240105")
+        .fetch_with_prices()
+        .await
+        .unwrap();
+
+    assert_eq!(df.height(), 1);
+    assert_eq!(
+        df.column("분류").unwrap().str().unwrap().get(0),
+        Some("녹색채권")
+    );
+    assert_eq!(
+        df.column("잔존만기구간").unwrap().str().unwrap().get(0),
+        Some("1~3년")
+    );
+    assert_eq!(
+        df.column("종가").unwrap().f64().unwrap().get(0),
+        Some(9950.5)
+    );
+    assert_eq!(
+        df.column("종가수익률").unwrap().f64().unwrap().get(0),
+        Some(3.612)
+    );
+}
@@ -560,3 +560,168 @@ async fn test_index_rate_limit() {
         _ => panic!("Expected RateLimit error"),
     }
 }
+
+fn bond_index_body(date: &str, total_return: &str) -> String {
+    format!(
+        r#"{{
+        "OutBlock_1": [{{
+            "BAS_DD": "{date}",
+            "BND_IDX_GRP_NM": "국고채프라임지수",
+            "TOT_EARNG_IDX": "{total_return}",
+            "TOT_EARNG_IDX_CMPPREVDD": "0.5",
+            "NETPRC_IDX": "110.1",
+            "NETPRC_IDX_CMPPREVDD": "0.1",
+            "ZERO_REINVST_IDX": "180.2",
+            "ZERO_REINVST_IDX_CMPPREVDD": "0.4",
+            "CALL_REINVST_IDX": "181.3",
+            "CALL_REINVST_IDX_CMPPREVDD": "0.4",
+            "MKT_PRC_IDX": "112.4",
+            "MKT_PRC_IDX_CMPPREVDD": "0.2",
+            "AVG_DURATION": "6.5",
+            "AVG_CONVEXITY_PRC": "70.1",
+            "BND_IDX_AVG_YD": "3.2"
+        }}, {{
+            "BAS_DD": "{date}",
+            "BND_IDX_GRP_NM": "KTB 지수",
+            "TOT_EARNG_IDX": "150.0",
+            "TOT_EARNG_IDX_CMPPREVDD": "0.1",
+            "NETPRC_IDX": "100.0",
+            "NETPRC_IDX_CMPPREVDD": "0.1",
+            "ZERO_REINVST_IDX": "140.0",
+            "ZERO_REINVST_IDX_CMPPREVDD": "0.1",
+            "CALL_REINVST_IDX": "141.0",
+            "CALL_REINVST_IDX_CMPPREVDD": "0.1",
+            "MKT_PRC_IDX": "101.0",
+            "MKT_PRC_IDX_CMPPREVDD": "0.1",
+            "AVG_DURATION": "2.5",
+            "AVG_CONVEXITY_PRC": "8.0",
+            "BND_IDX_AVG_YD": "3.4"
+        }}]
+    }}"#
+    )
+}
+
+#[tokio::test]
+async fn test_bond_series_over_date_range() {
+    use krx_rs::analysis::bond_index::{BondIndexGroup, BondIndexVariant};
+
+    let (client, server) = setup_index_test(
+        "/idx/bon_dd_trd",
+        "20240104",
+        &bond_index_body("20240104", "200.5"),
+        200,
+    )
+    .await;
+    Mock::given(method("GET"))
+        .and(path("/idx/bon_dd_trd"))
+        .and(query_param("basDd", "20240105"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(bond_index_body("20240105", "201.0")),
+        )
+        .mount(&server)
+        .await;
+    // 20240108은 휴장일처럼 빈 응답
+    Mock::given(method("GET"))
+        .and(path("/idx/bon_dd_trd"))
+        .and(query_param("basDd", "20240108"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"OutBlock_1": []}"#))
+        .mount(&server)
+        .await;
+
+    let df = client
+        .index()
+        .bond_series(BondIndexGroup::KtbPrime, BondIndexVariant::TotalReturn)
+        .start_date("20240104")
+        .end_date("20240108")
+        .fetch()
+        .await
+        .unwrap();
+
+    assert_eq!(df.height(), 2);
+    let values = df.column("지수").unwrap().f64().unwrap();
+    assert_eq!(values.get(0), Some(200.5));
+    assert_eq!(values.get(1), Some(201.0));
+}
+
+#[tokio::test]
+async fn test_bond_series_skips_calendar_holidays() {
+    use krx_rs::{
+        analysis::bond_index::{BondIndexGroup, BondIndexVariant},
+        calendar::TradingCalendar,
+    };
+
+    let (client, server) = setup_index_test(
+        "/idx/bon_dd_trd",
+        "20240104",
+        &bond_index_body("20240104", "200.5"),
+        200,
+    )
+    .await;
+    // 먼저 요청한 날짜의 응답이 늦게 도착해도 날짜 순서를 유지합니다.
+    Mock::given(method("GET"))
+        .and(path("/idx/bon_dd_trd"))
+        .and(query_param("basDd", "20240105"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(bond_index_body("20240105", "201.0"))
+                .set_delay(std::time::Duration::from_millis(100)),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/idx/bon_dd_trd"))
+        .and(query_param("basDd", "20240109"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(bond_index_body("20240109", "201.5")),
+        )
+        .mount(&server)
+        .await;
+
+    let df = client
+        .index()
+        .bond_series(BondIndexGroup::KtbPrime, BondIndexVariant::TotalReturn)
+        .start_date("20240105")
+        .end_date("20240109")
+        .calendar(TradingCalendar::new().with_holidays(["20240108"]).unwrap())
+        .concurrency(2)
+        .fetch()
+        .await
+        .unwrap();
+
+    let values = df.column("지수").unwrap().f64().unwrap();
+    assert_eq!(
+        values.into_iter().collect::<Vec<_>>(),
+        [Some(201.0), Some(201.5)]
+    );
+    // 주말과 달력의 휴장일(20240108)은 요청하지 않습니다.
+    assert_eq!(client.stats().total_requests(), 2);
+}
+
+#[tokio::test]
+async fn test_bond_group_comparison() {
+    let (client, _server) = setup_index_test(
+        "/idx/bon_dd_trd",
+        "20240105",
+        &bond_index_body("20240105", "201.0"),
+        200,
+    )
+    .await;
+
+    let df = client
+        .index()
+        .bond_daily()
+        .date("20240105")
+        .fetch_group_comparison()
+        .await
+        .unwrap();
+
+    assert_eq!(df.shape(), (3, 3));
+    assert_eq!(
+        df.column("KTB 지수").unwrap().f64().unwrap().get(0),
+        Some(2.5)
+    );
+    assert_eq!(
+        df.column("국고채프라임지수").unwrap().f64().unwrap().get(2),
+        Some(3.2)
+    );
+}
@@ -1,4 +1,4 @@
-use krx_rs::{Client, error::Error};
+use krx_rs::{Client, analysis::valuation::ValuationParams, error::Error};
 use wiremock::{
     Mock, MockServer, ResponseTemplate,
     matchers::{header, method, path, query_param},
@@ -568,3 +568,109 @@ async fn test_stock_fluctuation_types() {
         0.0
     );
 }
+
+// Warrant / Right Valuation Tests
+#[tokio::test]
+async fn test_stock_warrant_valuation() {
+    let response_body = r#"{
+        "OutBlock_1": [{
+            "ACC_TRDVAL": "59712974",
+            "ACC_TRDVOL": "45938",
+            "BAS_DD": "20240105",
+            "CMPPREVDD_PRC": "1",
+            "EXER_PRC": "8411",
+            "EXST_END_DD": "20240125",
+            "EXST_STRT_DD": "20230105",
+            "FLUC_RT": "0.08",
+            "ISU_CD": "0036221D",
+            "ISU_NM": "KG모빌리티 122WR",
+            "LIST_SHRS": "17893060",
+            "MKTCAP": "23243084940",
+            "MKT_NM": "KOSPI",
+            "TARSTK_ISU_NM": "KG모빌리티",
+            "TARSTK_ISU_PRSNT_PRC": "9150",
+            "TARSTK_ISU_SRT_CD": "003620",
+            "TDD_CLSPRC": "1299",
+            "TDD_HGPRC": "1330",
+            "TDD_LWPRC": "1271",
+            "TDD_OPNPRC": "1330"
+        }]
+    }"#;
+
+    let (client, _server) =
+        setup_stock_test("/sto/sw_bydd_trd", "20240105", response_body, 200).await;
+
+    let df = client
+        .stock()
+        .stock_warrant_daily()
+        .date("20240105")
+        .fetch_valuation(&ValuationParams::default())
+        .await
+        .unwrap();
+
+    assert_eq!(df.shape(), (1, 15));
+    assert_eq!(
+        df.column("내재가치").unwrap().f64().unwrap().get(0),
+        Some(739.0)
+    );
+    assert_eq!(
+        df.column("잔존일수").unwrap().i64().unwrap().get(0),
+        Some(20)
+    );
+    assert_eq!(
+        df.column("만기임박").unwrap().bool().unwrap().get(0),
+        Some(true)
+    );
+}
+
+#[tokio::test]
+async fn test_stock_right_valuation() {
+    let response_body = r#"{
+        "OutBlock_1": [{
+            "ACC_TRDVAL": "1000000",
+            "ACC_TRDVOL": "1000",
+            "BAS_DD": "20240105",
+            "CMPPREVDD_PRC": "-5",
+            "DELIST_DD": "20240112",
+            "FLUC_RT": "-0.50",
+            "ISU_CD": "0123452R",
+            "ISU_NM": "테스트 1R",
+            "ISU_PRC": "5000",
+            "LIST_SHRS": "1000000",
+            "MKTCAP": "995000000",
+            "MKT_NM": "KOSDAQ",
+            "TARSTK_ISU_NM": "테스트",
+            "TARSTK_ISU_PRSNT_PRC": "4800",
+            "TARSTK_ISU_SRT_CD": "012345",
+            "TDD_CLSPRC": "995",
+            "TDD_HGPRC": "1000",
+            "TDD_LWPRC": "990",
+            "TDD_OPNPRC": "1000"
+        }]
+    }"#;
+
+    let (client, _server) =
+        setup_stock_test("/sto/sr_bydd_trd", "20240105", response_body, 200).await;
+
+    let df = client
+        .stock()
+        .stock_right_daily()
+        .date("20240105")
+        .fetch_valuation(&ValuationParams::default())
+        .await
+        .unwrap();
+
+    assert_eq!(df.shape(), (1, 15));
+    assert_eq!(
+        df.column("신주발행가").unwrap().f64().unwrap().get(0),
+        Some(5000.0)
+    );
+    assert_eq!(
+        df.column("내재가치").unwrap().f64().unwrap().get(0),
+        Some(0.0)
+    );
+    assert_eq!(
+        df.column("잔존일수").unwrap().i64().unwrap().get(0),
+        Some(7)
+    );
+}
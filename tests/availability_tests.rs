@@ -0,0 +1,123 @@
+mod common;
+
+use chrono::{Days, Utc};
+use common::{EMPTY, mount, mount_times, ok, sample, setup};
+use krx_rs::{
+    api::endpoint::StockDaily,
+    availability::FetchOutcome,
+    calendar::{TradingCalendar, seoul},
+};
+use std::time::Duration;
+
+/// 아직 공개 시각이 지나지 않은 다음 거래일
+fn upcoming_trading_day() -> String {
+    let calendar = TradingCalendar::new();
+    let mut date = Utc::now().with_timezone(&seoul()).date_naive();
+    loop {
+        date = date.checked_add_days(Days::new(1)).unwrap();
+        if calendar.is_trading_day(date) {
+            return date.format("%Y%m%d").to_string();
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_fetch_outcome_distinguishes_empty_responses() {
+    let (client, server) = setup().await;
+    let upcoming = upcoming_trading_day();
+    mount(
+        &server,
+        "sto/stk_bydd_trd",
+        "20240105",
+        ok(sample("stk_bydd_trd", "20240105")),
+    )
+    .await;
+    mount(&server, "sto/stk_bydd_trd", "20240108", ok(EMPTY)).await;
+    mount(&server, "sto/stk_bydd_trd", "20240106", ok(EMPTY)).await;
+    mount(&server, "sto/stk_bydd_trd", &upcoming, ok(EMPTY)).await;
+
+    assert!(matches!(
+        client.fetch_outcome::<StockDaily>("20240105").await.unwrap(),
+        FetchOutcome::Data(df) if df.height() > 0
+    ));
+    assert!(client.is_available::<StockDaily>("20240105").await.unwrap());
+
+    assert_eq!(
+        client
+            .fetch_outcome::<StockDaily>("20240108")
+            .await
+            .unwrap(),
+        FetchOutcome::Empty
+    );
+    assert_eq!(
+        client
+            .fetch_outcome::<StockDaily>("20240106")
+            .await
+            .unwrap(),
+        FetchOutcome::NonTradingDay
+    );
+    assert_eq!(
+        client.fetch_outcome::<StockDaily>(&upcoming).await.unwrap(),
+        FetchOutcome::NotPublished
+    );
+    assert!(!client.is_available::<StockDaily>(&upcoming).await.unwrap());
+
+    // 달력에 등록한 휴장일도 휴장일로 분류합니다.
+    let checker = client
+        .availability()
+        .calendar(TradingCalendar::new().with_holidays(["20240108"]).unwrap());
+    assert_eq!(
+        checker
+            .fetch_outcome::<StockDaily>("20240108")
+            .await
+            .unwrap(),
+        FetchOutcome::NonTradingDay
+    );
+}
+
+#[tokio::test]
+async fn test_wait_until_available_polls_with_backoff() {
+    let (client, server) = setup().await;
+    mount_times(&server, "sto/stk_bydd_trd", "20240105", ok(EMPTY), 2).await;
+    mount(
+        &server,
+        "sto/stk_bydd_trd",
+        "20240105",
+        ok(sample("stk_bydd_trd", "20240105")),
+    )
+    .await;
+
+    let outcome = client
+        .availability()
+        .poll_interval(Duration::from_millis(10), Duration::from_millis(20))
+        .wait_until_available::<StockDaily>("20240105", Duration::from_secs(10))
+        .await
+        .unwrap();
+    assert!(outcome.is_available());
+    assert_eq!(client.stats().total_requests(), 3);
+}
+
+#[tokio::test]
+async fn test_wait_until_available_timeout_and_holiday() {
+    let (client, server) = setup().await;
+    mount(&server, "sto/stk_bydd_trd", "20240108", ok(EMPTY)).await;
+    mount(&server, "sto/stk_bydd_trd", "20240106", ok(EMPTY)).await;
+
+    let checker = client
+        .availability()
+        .poll_interval(Duration::from_millis(10), Duration::from_millis(10));
+    let outcome = checker
+        .wait_until_available::<StockDaily>("20240108", Duration::from_millis(50))
+        .await
+        .unwrap();
+    assert_eq!(outcome, FetchOutcome::Empty);
+    assert!(client.stats().total_requests() > 1);
+
+    let before = client.stats().total_requests();
+    let outcome = checker
+        .wait_until_available::<StockDaily>("20240106", Duration::from_secs(10))
+        .await
+        .unwrap();
+    assert_eq!(outcome, FetchOutcome::NonTradingDay);
+    assert_eq!(client.stats().total_requests(), before + 1);
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use common::{EMPTY, client_for, mount, ok, sample, setup};
+use krx_rs::{Client, batch::Batch, error::Error};
+use wiremock::{Mock, MockServer, ResponseTemplate, matchers::method};
+
+#[tokio::test]
+async fn test_batch_mixed_builders_and_endpoint_names() {
+    let (client, server) = setup().await;
+    for date in ["20240104", "20240105"] {
+        mount(
+            &server,
+            "sto/stk_bydd_trd",
+            date,
+            ok(sample("stk_bydd_trd", date)),
+        )
+        .await;
+    }
+    mount(
+        &server,
+        "etp/etf_bydd_trd",
+        "20240105",
+        ResponseTemplate::new(500).set_body_string("Internal Server Error"),
+    )
+    .await;
+
+    let batch = client
+        .batch()
+        .concurrency(2)
+        .endpoints(["sto/stk_bydd_trd"], ["20240104", "20240105"])
+        .endpoint("etp/etf_bydd_trd", "20240105")
+        .endpoint("sto/unknown", "20240105")
+        .add(
+            "kospi",
+            client.stock().stock_daily().date("20240105").fetch(),
+        );
+    assert_eq!(batch.len(), 5);
+
+    let results = batch.run().await;
+    assert_eq!(results.len(), 5);
+    assert!(!results.all_succeeded());
+
+    let key = Batch::endpoint_key("sto/stk_bydd_trd", "20240105");
+    let by_name = results.get(&key).unwrap().as_ref().unwrap();
+    let by_builder = results.get("kospi").unwrap().as_ref().unwrap();
+    assert_eq!(by_name, by_builder);
+    assert_eq!(results.successes().count(), 3);
+
+    assert_eq!(
+        results.failed_keys(),
+        vec!["etp/etf_bydd_trd@20240105", "sto/unknown@20240105"]
+    );
+    assert!(matches!(
+        results.get("etp/etf_bydd_trd@20240105"),
+        Some(Err(Error::ApiError {
+            status_code: 500,
+            ..
+        }))
+    ));
+    assert!(matches!(
+        results.get("sto/unknown@20240105"),
+        Some(Err(Error::InvalidInput(_)))
+    ));
+    // 알 수 없는 엔드포인트는 요청을 보내지 않습니다.
+    assert_eq!(client.stats().total_requests(), 4);
+}
+
+#[tokio::test]
+async fn test_batch_all_endpoints() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ok(EMPTY))
+        .mount(&server)
+        .await;
+    let client = client_for(&server);
+
+    let results = client.batch().all_endpoints("20240105").run().await;
+    assert_eq!(results.len(), krx_rs::api::endpoint::ALL.len());
+    assert!(results.all_succeeded());
+    assert_eq!(
+        client.stats().total_requests(),
+        krx_rs::api::endpoint::ALL.len() as u64
+    );
+}
+
+#[tokio::test]
+async fn test_empty_batch() {
+    let client = Client::new("test_key");
+    let batch = client.batch();
+    assert!(batch.is_empty());
+    assert!(batch.run().await.is_empty());
+}
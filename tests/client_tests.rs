@@ -241,6 +241,7 @@ async fn test_client_with_logging() {
         json_format: false,
         filter_sensitive: true,
         file_path: None,
+        ..Default::default()
     };
 
     let result = Client::with_logging("test_key", logging_config);
@@ -258,6 +259,7 @@ async fn test_client_with_logging_invalid_config() {
         json_format: false,
         filter_sensitive: true,
         file_path: None,
+        ..Default::default()
     };
 
     // The logging initialization should still work even with invalid level
@@ -274,6 +276,7 @@ async fn test_client_builder_with_logging() {
         json_format: true,
         filter_sensitive: false,
         file_path: Some("/tmp/test.log".to_string()),
+        ..Default::default()
     };
 
     let result = Client::builder()
@@ -421,3 +424,323 @@ async fn test_client_request_large_response() {
         _ => panic!("Expected Parsing error"),
     }
 }
+
+#[tokio::test]
+async fn test_client_stats() {
+    let (client, server) = setup_test_client(
+        "/idx/krx_dd_trd?basDd=20240105",
+        "krx_dd_trd_20240105.json",
+        200,
+    )
+    .await;
+    Mock::given(method("GET"))
+        .and(path("/sto/stk_bydd_trd"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "1"))
+        .mount(&server)
+        .await;
+
+    let df = client
+        .index()
+        .krx_daily()
+        .date("20240105")
+        .fetch()
+        .await
+        .unwrap();
+    let result = client.stock().stock_daily().date("20240105").fetch().await;
+    assert!(matches!(result, Err(Error::RateLimit { .. })));
+
+    let stats = client.stats();
+    assert_eq!(stats.total_requests(), 2);
+    assert_eq!(stats.failed_requests(), 1);
+    assert_eq!(stats.rate_limited(), 1);
+    assert_eq!(stats.rows(), df.height() as u64);
+
+    let index = &stats.endpoints["/idx/krx_dd_trd"];
+    assert_eq!(index.requests["200"], 1);
+    assert_eq!(index.latency.count, 1);
+    assert!(index.response_bytes > 0);
+
+    let text = stats.to_prometheus();
+    assert!(text.contains("krx_requests_total{endpoint=\"/sto/stk_bydd_trd\",status=\"429\"} 1"));
+    assert!(text.contains(&format!(
+        "krx_rows_total{{endpoint=\"/idx/krx_dd_trd\"}} {}",
+        df.height()
+    )));
+
+    client.reset_stats();
+    assert_eq!(client.stats().total_requests(), 0);
+}
+
+#[tokio::test]
+async fn test_key_pool_rotates_on_quota() {
+    let mock_server = MockServer::start().await;
+    let body = std::fs::read_to_string(
+        "docs/krx-api-reference/KRX_API_Spec/samples/krx_dd_trd_20240105.json",
+    )
+    .unwrap();
+    Mock::given(method("GET"))
+        .and(path("/idx/krx_dd_trd"))
+        .and(header("AUTH_KEY", "quota-key-0001"))
+        .respond_with(ResponseTemplate::new(429))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/idx/krx_dd_trd"))
+        .and(header("AUTH_KEY", "spare-key-0002"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(body))
+        .expect(3)
+        .mount(&mock_server)
+        .await;
+
+    let client = Client::builder()
+        .auth_keys(["quota-key-0001", "spare-key-0002"])
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    assert_eq!(client.credential_count(), 2);
+
+    for _ in 0..3 {
+        client
+            .index()
+            .krx_daily()
+            .date("20240105")
+            .fetch()
+            .await
+            .unwrap();
+    }
+
+    let stats = client.stats();
+    assert_eq!(stats.rate_limited(), 1);
+    assert_eq!(stats.retries(), 1);
+}
+
+#[tokio::test]
+async fn test_reload_credentials_from_file() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/idx/krx_dd_trd"))
+        .and(header("AUTH_KEY", "rotated-key-0002"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"OutBlock_1": []}"#))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/idx/krx_dd_trd"))
+        .respond_with(ResponseTemplate::new(401).set_body_string("Unauthorized"))
+        .mount(&mock_server)
+        .await;
+
+    let key_file = std::env::temp_dir().join(format!("krx-key-{}", std::process::id()));
+    std::fs::write(&key_file, "expired-key-0001\n").unwrap();
+    let client = Client::builder()
+        .credentials(krx_rs::credentials::FileCredentials::new(&key_file))
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let result = client.index().krx_daily().date("20240105").fetch().await;
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status_code: 401,
+            ..
+        })
+    ));
+
+    std::fs::write(&key_file, "rotated-key-0002\n").unwrap();
+    client.reload_credentials().unwrap();
+    let result = client.index().krx_daily().date("20240105").fetch().await;
+    assert!(result.is_ok(), "{:?}", result.err());
+    std::fs::remove_file(key_file).unwrap();
+}
+
+#[tokio::test]
+async fn test_client_through_authenticated_proxy() {
+    let proxy = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/svc/apis/idx/krx_dd_trd"))
+        .and(header("proxy-authorization", "Basic dXNlcjpwYXNz"))
+        .and(header("AUTH_KEY", "test_key"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"OutBlock_1": []}"#))
+        .expect(1)
+        .mount(&proxy)
+        .await;
+
+    let client = Client::builder()
+        .auth_key("test_key")
+        .base_url("http://data-dbg.krx.co.kr/svc/apis")
+        .proxy(proxy.uri())
+        .proxy_auth("user", "pass")
+        .build()
+        .unwrap();
+
+    let result = client.index().krx_daily().date("20240105").fetch().await;
+    assert!(result.is_ok(), "{:?}", result.err());
+}
+
+#[tokio::test]
+async fn test_client_with_injected_http_client() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/idx/krx_dd_trd"))
+        .and(header("x-corp-trace", "on"))
+        .and(header("AUTH_KEY", "test_key"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"OutBlock_1": []}"#))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("x-corp-trace", "on".parse().unwrap());
+    let http_client = reqwest::Client::builder()
+        .default_headers(headers)
+        .build()
+        .unwrap();
+
+    let client = Client::builder()
+        .auth_key("test_key")
+        .base_url(mock_server.uri())
+        .http_client(http_client)
+        .build()
+        .unwrap();
+
+    let result = client.index().krx_daily().date("20240105").fetch().await;
+    assert!(result.is_ok(), "{:?}", result.err());
+}
+
+#[tokio::test]
+async fn test_client_builder_network_settings() {
+    let client = Client::builder()
+        .auth_key("test_key")
+        .https_only(true)
+        .connect_timeout(std::time::Duration::from_secs(5))
+        .pool_max_idle_per_host(4)
+        .pool_idle_timeout(std::time::Duration::from_secs(30))
+        .gzip(true)
+        .no_proxy()
+        .build()
+        .unwrap();
+    assert_eq!(client.get_base_url(), "https://data-dbg.krx.co.kr/svc/apis");
+
+    let result = Client::builder()
+        .auth_key("test_key")
+        .https_only(true)
+        .base_url("http://data-dbg.krx.co.kr/svc/apis")
+        .build();
+    assert!(matches!(result, Err(Error::InvalidInput(_))));
+
+    let result = Client::builder()
+        .auth_key("test_key")
+        .proxy("not a url")
+        .build();
+    assert!(matches!(result, Err(Error::InvalidInput(_))));
+
+    let result = Client::builder()
+        .auth_key("test_key")
+        .add_root_certificate_pem(
+            "-----BEGIN CERTIFICATE-----\ngarbage\n-----END CERTIFICATE-----\n",
+        )
+        .build();
+    assert!(matches!(result, Err(Error::InvalidInput(_))));
+}
+
+#[tokio::test]
+async fn test_cloned_client_builders_can_be_spawned() {
+    fn assert_send_static<T: Send + Sync + 'static>(_: &T) {}
+
+    let (client, _mock_server) = setup_test_client(
+        "/sto/stk_bydd_trd?basDd=20240105",
+        "stk_bydd_trd_20240105.json",
+        200,
+    )
+    .await;
+
+    let builder = client.stock().stock_daily().date("20240105");
+    assert_send_static(&client);
+    assert_send_static(&builder);
+
+    let handle = tokio::spawn(builder.fetch());
+    let other = tokio::spawn({
+        let client = client.clone();
+        async move { client.stock().stock_daily().date("20240105").fetch().await }
+    });
+
+    assert!(handle.await.unwrap().is_ok());
+    assert!(other.await.unwrap().is_ok());
+    // 복제한 클라이언트는 같은 지표를 공유합니다.
+    assert_eq!(client.stats().total_requests(), 2);
+}
+
+#[tokio::test]
+async fn test_generic_fetch_by_endpoint_type() {
+    use krx_rs::{
+        Endpoint,
+        api::endpoint::{Category, EtfDaily, StockDaily},
+    };
+
+    async fn fetch_height<E: Endpoint>(client: &Client) -> usize {
+        client.fetch::<E>("20240105").await.unwrap().height()
+    }
+
+    let (client, mock_server) = setup_test_client(
+        "/sto/stk_bydd_trd?basDd=20240105",
+        "stk_bydd_trd_20240105.json",
+        200,
+    )
+    .await;
+    Mock::given(method("GET"))
+        .and(path(EtfDaily::PATH))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(
+                std::fs::read_to_string(
+                    "docs/krx-api-reference/KRX_API_Spec/samples/etf_bydd_trd_20240105.json",
+                )
+                .unwrap(),
+            ),
+        )
+        .mount(&mock_server)
+        .await;
+
+    assert_eq!(StockDaily::CATEGORY, Category::Stock);
+    assert_eq!(EtfDaily::CATEGORY.prefix(), "etp");
+
+    let generic = client.fetch::<StockDaily>("20240105").await.unwrap();
+    let builder = client
+        .stock()
+        .stock_daily()
+        .date("20240105")
+        .fetch()
+        .await
+        .unwrap();
+    assert_eq!(generic, builder);
+    assert!(fetch_height::<EtfDaily>(&client).await > 0);
+
+    let result = client.fetch::<StockDaily>("2024-01-05").await;
+    assert!(matches!(result, Err(Error::InvalidInput(_))));
+}
+
+#[tokio::test]
+async fn test_fetch_by_name() {
+    let (client, _mock_server) = setup_test_client(
+        "/etp/etf_bydd_trd?basDd=20240105",
+        "etf_bydd_trd_20240105.json",
+        200,
+    )
+    .await;
+
+    let by_name = client
+        .fetch_by_name("etp/etf_bydd_trd", "20240105")
+        .await
+        .unwrap();
+    let by_builder = client
+        .etp()
+        .etf_daily()
+        .date("20240105")
+        .fetch()
+        .await
+        .unwrap();
+    assert_eq!(by_name, by_builder);
+
+    let result = client.fetch_by_name("etp/etf_bydd", "20240105").await;
+    assert!(matches!(result, Err(Error::InvalidInput(msg)) if msg.contains("etp/etf_bydd_trd")));
+}
@@ -0,0 +1,106 @@
+//! 통합 테스트가 함께 쓰는 wiremock 도우미.
+//!
+//! 테스트 파일마다 쓰는 도우미가 달라 사용하지 않는 항목이 생깁니다.
+#![allow(dead_code)]
+
+use krx_rs::Client;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path, query_param},
+};
+
+/// 데이터가 없는 응답 본문
+pub const EMPTY: &str = r#"{"OutBlock_1": []}"#;
+
+/// `{name}_{date}.json` 샘플 응답 본문을 읽습니다. (예: `sample("stk_bydd_trd", "20240105")`)
+pub fn sample(name: &str, date: &str) -> String {
+    std::fs::read_to_string(format!(
+        "docs/krx-api-reference/KRX_API_Spec/samples/{name}_{date}.json"
+    ))
+    .unwrap()
+}
+
+/// 삼성전자 한 종목만 담은 KOSPI 일별매매정보 응답 본문
+pub fn stock_daily_body(date: &str, close: &str) -> String {
+    format!(
+        r#"{{
+        "OutBlock_1": [{{
+            "BAS_DD": "{date}",
+            "ISU_CD": "005930",
+            "ISU_NM": "삼성전자",
+            "MKT_NM": "KOSPI",
+            "SECT_TP_NM": "",
+            "TDD_CLSPRC": "{close}",
+            "CMPPREVDD_PRC": "-500",
+            "FLUC_RT": "-0.70",
+            "TDD_OPNPRC": "71500",
+            "TDD_HGPRC": "72000",
+            "TDD_LWPRC": "70500",
+            "ACC_TRDVOL": "10000000",
+            "ACC_TRDVAL": "710000000000",
+            "MKTCAP": "423000000000000",
+            "LIST_SHRS": "5969782550"
+        }}]
+    }}"#
+    )
+}
+
+/// 모의 서버를 가리키는 클라이언트
+pub fn client_for(server: &MockServer) -> Client {
+    Client::builder()
+        .auth_key("test_key")
+        .base_url(server.uri())
+        .build()
+        .unwrap()
+}
+
+/// 모의 서버와 그 서버를 가리키는 클라이언트를 만듭니다.
+pub async fn setup() -> (Client, MockServer) {
+    let server = MockServer::start().await;
+    (client_for(&server), server)
+}
+
+/// 상태 코드 200과 본문으로 응답합니다.
+pub fn ok(body: impl Into<String>) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_string(body)
+}
+
+fn mock(endpoint: &str, date: &str, response: ResponseTemplate) -> Mock {
+    Mock::given(method("GET"))
+        .and(path(format!("/{}", endpoint.trim_start_matches('/'))))
+        .and(query_param("basDd", date))
+        .respond_with(response)
+}
+
+/// 엔드포인트(예: `sto/stk_bydd_trd`)의 기준일자 요청에 응답을 등록합니다.
+pub async fn mount(server: &MockServer, endpoint: &str, date: &str, response: ResponseTemplate) {
+    mock(endpoint, date, response).mount(server).await;
+}
+
+/// [`mount`]와 같지만 처음 `times`번만 응답합니다.
+pub async fn mount_times(
+    server: &MockServer,
+    endpoint: &str,
+    date: &str,
+    response: ResponseTemplate,
+    times: u64,
+) {
+    mock(endpoint, date, response)
+        .up_to_n_times(times)
+        .mount(server)
+        .await;
+}
+
+/// [`mount`]와 같지만 정확히 `times`번 요청되었는지 서버가 종료될 때 검증합니다.
+pub async fn mount_expect(
+    server: &MockServer,
+    endpoint: &str,
+    date: &str,
+    response: ResponseTemplate,
+    times: u64,
+) {
+    mock(endpoint, date, response)
+        .expect(times)
+        .mount(server)
+        .await;
+}
@@ -0,0 +1,228 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::{EMPTY, mount, ok, setup};
+use krx_rs::{analysis::listing::ListingChangeKind, calendar::TradingCalendar, error::Error};
+use serde_json::Value;
+
+const MARKETS: [(&str, &str); 3] = [
+    ("stk_isu_base_info", "sto/stk_isu_base_info"),
+    ("ksq_isu_base_info", "sto/ksq_isu_base_info"),
+    ("knx_isu_base_info", "sto/knx_isu_base_info"),
+];
+
+fn sample(name: &str, date: &str) -> Value {
+    serde_json::from_str(&common::sample(name, date)).unwrap()
+}
+
+async fn mount_samples(server: &wiremock::MockServer, date: &str) {
+    for (name, endpoint) in MARKETS {
+        mount(server, endpoint, date, ok(common::sample(name, date))).await;
+    }
+}
+
+#[tokio::test]
+async fn test_listing_changes_from_samples() {
+    let (client, server) = setup().await;
+    mount_samples(&server, "20240104").await;
+    mount_samples(&server, "20240105").await;
+
+    let changes = client
+        .stock()
+        .listing_changes("20240104", "20240105")
+        .await
+        .unwrap();
+
+    assert_eq!(changes.len(), 11);
+    assert_eq!(changes.share_changes().count(), 11);
+    let change = changes.for_issue("011810").next().unwrap();
+    assert_eq!(change.market, "KOSPI");
+    assert_eq!(
+        change.kind,
+        ListingChangeKind::SharesChanged {
+            from: Some(23657927),
+            to: Some(31017927)
+        }
+    );
+    assert_eq!(changes.new_listings().count(), 0);
+    assert_eq!(changes.delistings().count(), 0);
+}
+
+#[tokio::test]
+async fn test_listing_changes_detects_transfer_listing_and_delisting() {
+    let (client, server) = setup().await;
+    let mut kospi = sample("stk_isu_base_info", "20240105");
+    let mut kosdaq = sample("ksq_isu_base_info", "20240105");
+    let konex = sample("knx_isu_base_info", "20240105");
+
+    // 코스닥 첫 종목을 유가증권으로 옮기고, 유가증권 첫 종목을 상장폐지, 새 종목을 상장
+    let mut moved = kosdaq["OutBlock_1"].as_array_mut().unwrap().remove(0);
+    moved["MKT_TP_NM"] = "KOSPI".into();
+    let kospi_rows = kospi["OutBlock_1"].as_array_mut().unwrap();
+    let delisted = kospi_rows.remove(0);
+    let mut listed = kospi_rows[0].clone();
+    listed["ISU_CD"] = "KR7999990001".into();
+    listed["ISU_SRT_CD"] = "999990".into();
+    listed["ISU_ABBRV"] = "신규종목".into();
+    kospi_rows.push(moved.clone());
+    kospi_rows.push(listed);
+
+    mount_samples(&server, "20240104").await;
+    mount(
+        &server,
+        "sto/stk_isu_base_info",
+        "20240105",
+        ok(kospi.to_string()),
+    )
+    .await;
+    mount(
+        &server,
+        "sto/ksq_isu_base_info",
+        "20240105",
+        ok(kosdaq.to_string()),
+    )
+    .await;
+    mount(
+        &server,
+        "sto/knx_isu_base_info",
+        "20240105",
+        ok(konex.to_string()),
+    )
+    .await;
+
+    let changes = client
+        .stock()
+        .listing_changes("20240104", "20240105")
+        .await
+        .unwrap();
+
+    let transfer = changes.market_transfers().next().unwrap();
+    assert_eq!(transfer.issue_code, moved["ISU_CD"].as_str().unwrap());
+    assert_eq!(
+        transfer.kind,
+        ListingChangeKind::MarketTransferred {
+            from: "KOSDAQ".to_string(),
+            to: "KOSPI".to_string()
+        }
+    );
+    let new: Vec<_> = changes.new_listings().collect();
+    assert_eq!(new.len(), 1);
+    assert_eq!(new[0].name, "신규종목");
+    let delistings: Vec<_> = changes.delistings().collect();
+    assert_eq!(delistings.len(), 1);
+    assert_eq!(
+        delistings[0].issue_code,
+        delisted["ISU_CD"].as_str().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_listing_changes_rejects_incomplete_snapshots() {
+    let (client, server) = setup().await;
+    mount_samples(&server, "20240104").await;
+    // 20240105에는 코넥스 스냅샷만 비어 있습니다.
+    for (name, endpoint) in &MARKETS[..2] {
+        mount(
+            &server,
+            endpoint,
+            "20240105",
+            ok(common::sample(name, "20240105")),
+        )
+        .await;
+    }
+    mount(&server, "sto/knx_isu_base_info", "20240105", ok(EMPTY)).await;
+
+    // 비교 대상(to)과 기준(from) 어느 쪽이 비어 있어도 전 종목 상장폐지·신규상장으로
+    // 보고하지 않고 오류를 반환합니다.
+    for (from, to) in [("20240104", "20240105"), ("20240105", "20240104")] {
+        let result = client.stock().listing_changes(from, to).await;
+        assert!(
+            matches!(&result, Err(Error::InvalidInput(message)) if message.contains("KONEX")),
+            "{result:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_listing_history_over_range() {
+    let (client, server) = setup().await;
+    mount_samples(&server, "20240104").await;
+    mount_samples(&server, "20240105").await;
+    // 달력에 없는 휴장일은 빈 스냅샷으로 건너뜁니다.
+    for (_, endpoint) in MARKETS {
+        mount(&server, endpoint, "20240103", ok(EMPTY)).await;
+    }
+
+    let history = client
+        .stock()
+        .listing_history("20240103", "20240105")
+        .calendar(TradingCalendar::new())
+        .fetch()
+        .await
+        .unwrap();
+
+    assert_eq!(history.days().len(), 1);
+    assert_eq!(
+        history.days()[0].0,
+        NaiveDate::from_ymd_opt(2024, 1, 5).unwrap()
+    );
+    assert_eq!(history.for_issue("KR7011810009").count(), 1);
+
+    let df = history.to_dataframe().unwrap();
+    assert_eq!(df.height(), 11);
+    assert_eq!(df.get_column_names()[0].as_str(), "날짜");
+}
+
+#[tokio::test]
+async fn test_listing_history_skips_partial_snapshots() {
+    let (client, server) = setup().await;
+    mount_samples(&server, "20240104").await;
+    // 20240105에는 코넥스 스냅샷만 비어 있습니다.
+    for (name, endpoint) in &MARKETS[..2] {
+        mount(
+            &server,
+            endpoint,
+            "20240105",
+            ok(common::sample(name, "20240105")),
+        )
+        .await;
+    }
+    mount(&server, "sto/knx_isu_base_info", "20240105", ok(EMPTY)).await;
+    // 20240108에는 20240105 샘플을 그대로 돌려줍니다.
+    for (name, endpoint) in MARKETS {
+        mount(
+            &server,
+            endpoint,
+            "20240108",
+            ok(common::sample(name, "20240105")),
+        )
+        .await;
+    }
+
+    let history = client
+        .stock()
+        .listing_history("20240104", "20240108")
+        .fetch()
+        .await
+        .unwrap();
+
+    // 20240105는 건너뛰고 20240108을 20240104와 비교하므로 코넥스 상장폐지가 없습니다.
+    assert_eq!(history.days().len(), 1);
+    assert_eq!(
+        history.days()[0].0,
+        NaiveDate::from_ymd_opt(2024, 1, 8).unwrap()
+    );
+    assert_eq!(history.days()[0].1.delistings().count(), 0);
+    assert_eq!(history.days()[0].1.share_changes().count(), 11);
+}
+
+#[tokio::test]
+async fn test_listing_history_rejects_invalid_range() {
+    let (client, _server) = setup().await;
+    let result = client
+        .stock()
+        .listing_history("20240105", "20240101")
+        .fetch()
+        .await;
+    assert!(result.is_err());
+}
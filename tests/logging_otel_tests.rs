@@ -0,0 +1,106 @@
+#![cfg(feature = "otel")]
+
+use krx_rs::{Client, logging::otel};
+use opentelemetry::Value;
+use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider, SpanData};
+use tracing_subscriber::layer::SubscriberExt;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+fn attribute<'a>(span: &'a SpanData, key: &str) -> Option<&'a Value> {
+    span.attributes
+        .iter()
+        .find(|kv| kv.key.as_str() == key)
+        .map(|kv| &kv.value)
+}
+
+#[tokio::test]
+async fn test_request_span_attributes() {
+    let mock_server = MockServer::start().await;
+    let body = std::fs::read_to_string(
+        "docs/krx-api-reference/KRX_API_Spec/samples/krx_dd_trd_20240105.json",
+    )
+    .unwrap();
+    Mock::given(method("GET"))
+        .and(path("/idx/krx_dd_trd"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(body))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/sto/stk_bydd_trd"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("Internal Server Error"))
+        .mount(&mock_server)
+        .await;
+
+    let exporter = InMemorySpanExporter::default();
+    let provider = SdkTracerProvider::builder()
+        .with_simple_exporter(exporter.clone())
+        .build();
+    let subscriber = tracing_subscriber::registry().with(otel::layer_with_provider(&provider));
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let client = Client::builder()
+        .auth_key("test_key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let df = client
+        .index()
+        .krx_daily()
+        .date("20240105")
+        .fetch()
+        .await
+        .unwrap();
+    let result = client.stock().stock_daily().date("20240104").fetch().await;
+    assert!(result.is_err());
+
+    provider.force_flush().unwrap();
+    let spans = exporter.get_finished_spans().unwrap();
+    let find = |endpoint: &str| {
+        spans
+            .iter()
+            .find(|span| attribute(span, "endpoint") == Some(&Value::from(endpoint.to_string())))
+            .unwrap_or_else(|| panic!("span for {endpoint} not exported"))
+    };
+
+    let ok = find("/idx/krx_dd_trd");
+    assert_eq!(ok.name, "get");
+    assert_eq!(attribute(ok, "bas_dd"), Some(&Value::from("20240105")));
+    assert_eq!(attribute(ok, "status"), Some(&Value::I64(200)));
+    assert_eq!(attribute(ok, "rows"), Some(&Value::I64(df.height() as i64)));
+    assert_eq!(attribute(ok, "retry_attempt"), Some(&Value::I64(0)));
+
+    let failed = find("/sto/stk_bydd_trd");
+    assert_eq!(attribute(failed, "bas_dd"), Some(&Value::from("20240104")));
+    assert_eq!(attribute(failed, "status"), Some(&Value::I64(500)));
+    assert_eq!(attribute(failed, "rows"), None);
+}
+
+#[tokio::test]
+async fn test_network_error_span_status() {
+    let exporter = InMemorySpanExporter::default();
+    let provider = SdkTracerProvider::builder()
+        .with_simple_exporter(exporter.clone())
+        .build();
+    let subscriber = tracing_subscriber::registry().with(otel::layer_with_provider(&provider));
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let client = Client::builder()
+        .auth_key("test_key")
+        .base_url("http://localhost:12345") // 존재하지 않는 서버
+        .build()
+        .unwrap();
+    let result = client.stock().stock_daily().date("20240105").fetch().await;
+    assert!(result.is_err());
+
+    provider.force_flush().unwrap();
+    let spans = exporter.get_finished_spans().unwrap();
+    assert!(!spans.is_empty());
+    assert!(
+        spans
+            .iter()
+            .all(|span| attribute(span, "status") == Some(&Value::from("network_error")))
+    );
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use common::{mount, ok, sample, setup};
+use krx_rs::analysis::panel::{self, FillPolicy, PanelOptions};
+use wiremock::MockServer;
+
+async fn mount_sample(server: &MockServer, date: &str) {
+    mount(
+        server,
+        "sto/stk_bydd_trd",
+        date,
+        ok(sample("stk_bydd_trd", date)),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_stock_daily_close_panel() {
+    let (client, server) = setup().await;
+    mount_sample(&server, "20240104").await;
+    mount_sample(&server, "20240105").await;
+
+    let mut df = client
+        .stock()
+        .stock_daily()
+        .date("20240104")
+        .fetch()
+        .await
+        .unwrap();
+    df.vstack_mut(
+        &client
+            .stock()
+            .stock_daily()
+            .date("20240105")
+            .fetch()
+            .await
+            .unwrap(),
+    )
+    .unwrap();
+
+    let options = PanelOptions::detect(&df).fill(FillPolicy::ForwardFill);
+    let close = panel::to_wide(&df, "종가", &options).unwrap();
+    assert_eq!(close.height(), 2);
+    assert_eq!(close.width(), 954);
+    assert_eq!(close.get_column_names()[0].as_str(), "날짜");
+
+    let long = panel::to_long(&close, "종가", &options).unwrap();
+    assert_eq!(long.height(), df.height());
+
+    let volume = panel::to_wide(&df, "거래량", &options).unwrap();
+    assert_eq!(volume.shape(), close.shape());
+}
@@ -0,0 +1,97 @@
+use krx_rs::{
+    Client,
+    logging::{LoggingConfig, build_subscriber, redact::MASK},
+};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+const AUTH_KEY: &str = "test_secret_key_1234";
+
+async fn setup(endpoint: &str, status: u16, body: String) -> (Client, MockServer) {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(endpoint))
+        .respond_with(ResponseTemplate::new(status).set_body_string(body))
+        .mount(&mock_server)
+        .await;
+    let client = Client::builder()
+        .auth_key(AUTH_KEY)
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    (client, mock_server)
+}
+
+#[tokio::test]
+async fn test_errors_do_not_expose_auth_key() {
+    let (client, _server) = setup(
+        "/idx/krx_dd_trd",
+        200,
+        format!(r#"{{"message": "unknown key {AUTH_KEY}"}}"#),
+    )
+    .await;
+    let err = client
+        .index()
+        .krx_daily()
+        .date("20240105")
+        .fetch()
+        .await
+        .unwrap_err();
+    assert!(!format!("{err:?}").contains(AUTH_KEY));
+    assert!(format!("{err:?}").contains(MASK));
+
+    let (client, _server) = setup(
+        "/sto/stk_bydd_trd",
+        401,
+        format!("AUTH_KEY {AUTH_KEY} is not registered"),
+    )
+    .await;
+    let err = client
+        .stock()
+        .stock_daily()
+        .date("20240105")
+        .fetch()
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        format!("API error (status 401): AUTH_KEY {MASK} is not registered")
+    );
+}
+
+#[tokio::test]
+async fn test_log_output_masks_auth_key() {
+    let dir = std::env::temp_dir().join(format!("krx-redact-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let log_path = dir.join("krx.log");
+    let config = LoggingConfig {
+        level: "debug".to_string(),
+        file_path: Some(log_path.to_string_lossy().into_owned()),
+        stdout: false,
+        redact_patterns: vec![r"\d{6}-\d{7}".to_string()],
+        ..Default::default()
+    };
+
+    let (client, _server) = setup(
+        "/idx/krx_dd_trd",
+        200,
+        format!(r#"{{"key": "{AUTH_KEY}", "id": "900101-1234567"}}"#),
+    )
+    .await;
+
+    {
+        let _guard = tracing::subscriber::set_default(build_subscriber(&config).unwrap());
+        let result = client.index().krx_daily().date("20240105").fetch().await;
+        assert!(result.is_err());
+        tracing::info!(auth_key = AUTH_KEY, "logged directly");
+    }
+
+    let contents = std::fs::read_to_string(&log_path).unwrap();
+    assert!(contents.contains("Failed to parse response"));
+    assert!(contents.contains(MASK));
+    assert!(!contents.contains(AUTH_KEY));
+    assert!(!contents.contains("900101-1234567"));
+    std::fs::remove_dir_all(dir).unwrap();
+}
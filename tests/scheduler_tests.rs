@@ -0,0 +1,164 @@
+mod common;
+
+use chrono::DateTime;
+use common::{EMPTY, mount, mount_times, ok, sample, setup};
+use krx_rs::{
+    DataFrame, Result,
+    api::endpoint::{EtfDaily, StockDaily},
+    scheduler::{JobStatus, Scheduler},
+    store::Dataset,
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use wiremock::{Mock, ResponseTemplate, matchers::method};
+
+#[tokio::test]
+async fn test_scheduler_catches_up_polls_and_retries() {
+    let (client, server) = setup().await;
+    mount(
+        &server,
+        "sto/stk_bydd_trd",
+        "20240104",
+        ok(sample("stk_bydd_trd", "20240104")),
+    )
+    .await;
+    // 처음에는 아직 공개되지 않아 비어 있다가 다음 조회에서 채워집니다.
+    mount_times(&server, "sto/stk_bydd_trd", "20240105", ok(EMPTY), 1).await;
+    mount(
+        &server,
+        "sto/stk_bydd_trd",
+        "20240105",
+        ok(sample("stk_bydd_trd", "20240105")),
+    )
+    .await;
+    mount(
+        &server,
+        "sto/stk_bydd_trd",
+        "20240108",
+        ResponseTemplate::new(500).set_body_string("error"),
+    )
+    .await;
+
+    let written = Arc::new(Mutex::new(Vec::new()));
+    let sink = {
+        let written = written.clone();
+        move |dataset: &Dataset, date: &str, df: &DataFrame| -> Result<()> {
+            written
+                .lock()
+                .unwrap()
+                .push((dataset.name(), date.to_string(), df.height()));
+            Ok(())
+        }
+    };
+
+    let mut scheduler = Scheduler::new(client.clone(), sink)
+        .datasets([Dataset::of::<StockDaily>()])
+        .catch_up_days(3)
+        .poll_interval(Duration::from_millis(10))
+        .poll_timeout(Duration::from_secs(100 * 365 * 24 * 60 * 60))
+        .retries(1, Duration::from_millis(10));
+
+    // 2024-01-09(화) 09:00 KST: 2024-01-08(월)까지 공개됨
+    let now = DateTime::parse_from_rfc3339("2024-01-09T09:00:00+09:00").unwrap();
+    let report = scheduler.run_once_at(now).await.unwrap();
+
+    let statuses: Vec<(&str, &JobStatus)> = report
+        .jobs
+        .iter()
+        .map(|job| (job.date.as_str(), &job.status))
+        .collect();
+    assert_eq!(statuses.len(), 3);
+    assert_eq!(statuses[0].0, "20240104");
+    assert!(matches!(statuses[0].1, JobStatus::Written { rows } if *rows > 0));
+    assert_eq!(statuses[1].0, "20240105");
+    assert!(matches!(statuses[1].1, JobStatus::Written { rows } if *rows > 0));
+    assert_eq!(statuses[2].0, "20240108");
+    assert!(matches!(statuses[2].1, JobStatus::Failed(_)));
+    assert!(report.has_failures());
+    assert_eq!(written.lock().unwrap().len(), 2);
+
+    // 다음 실행에서는 실패한 날짜만 다시 처리합니다.
+    let report = scheduler.run_once_at(now).await.unwrap();
+    assert_eq!(report.jobs.len(), 1);
+    assert_eq!(report.jobs[0].date, "20240108");
+
+    // 0104 1회, 0105 2회, 0108 (1회 + 재시도 1회) × 2
+    assert_eq!(client.stats().total_requests(), 7);
+}
+
+#[tokio::test]
+async fn test_scheduler_skips_empty_days_after_timeout() {
+    let (client, server) = setup().await;
+    Mock::given(method("GET"))
+        .respond_with(ok(EMPTY))
+        .mount(&server)
+        .await;
+    let sink = |_: &Dataset, _: &str, _: &DataFrame| -> Result<()> { panic!("nothing to write") };
+
+    let mut scheduler = Scheduler::new(client, sink)
+        .datasets([Dataset::of::<StockDaily>(), Dataset::of::<EtfDaily>()])
+        .catch_up_days(1)
+        .poll_timeout(Duration::ZERO);
+    let now = DateTime::parse_from_rfc3339("2024-01-09T09:00:00+09:00").unwrap();
+    let report = scheduler.run_once_at(now).await.unwrap();
+
+    assert_eq!(report.jobs.len(), 2);
+    assert!(report.jobs.iter().all(|job| job.status == JobStatus::Empty));
+    assert_eq!(report.rows(), 0);
+}
+
+#[tokio::test]
+async fn test_scheduler_polls_datasets_concurrently_at_injected_time() {
+    let (client, server) = setup().await;
+    // 주식은 세 번 비어 있다가 채워지고, ETF는 처음부터 데이터가 있습니다.
+    mount_times(&server, "sto/stk_bydd_trd", "20240105", ok(EMPTY), 3).await;
+    mount(
+        &server,
+        "sto/stk_bydd_trd",
+        "20240105",
+        ok(sample("stk_bydd_trd", "20240105")),
+    )
+    .await;
+    mount(
+        &server,
+        "etp/etf_bydd_trd",
+        "20240105",
+        ok(sample("etf_bydd_trd", "20240105")),
+    )
+    .await;
+
+    let written = Arc::new(Mutex::new(Vec::new()));
+    let sink = {
+        let written = written.clone();
+        move |dataset: &Dataset, _: &str, _: &DataFrame| -> Result<()> {
+            written.lock().unwrap().push(dataset.name());
+            Ok(())
+        }
+    };
+
+    // 공개 시각(2024-01-06 08:00)부터 3일을 기다리므로, 주입한 시각 기준으로는 아직
+    // 대기 중입니다. 실제 현재 시각을 쓰면 바로 빈 날짜로 처리됩니다.
+    let mut scheduler = Scheduler::new(client, sink)
+        .datasets([Dataset::of::<StockDaily>(), Dataset::of::<EtfDaily>()])
+        .catch_up_days(1)
+        .poll_interval(Duration::from_millis(20))
+        .poll_timeout(Duration::from_secs(3 * 24 * 60 * 60));
+    let now = DateTime::parse_from_rfc3339("2024-01-08T09:00:00+09:00").unwrap();
+    let report = scheduler.run_once_at(now).await.unwrap();
+
+    // 비어 있는 주식이 ETF 저장을 막지 않습니다. 보고서는 엔드포인트 순서를 유지합니다.
+    assert_eq!(
+        *written.lock().unwrap(),
+        ["etp/etf_bydd_trd", "sto/stk_bydd_trd"]
+    );
+    let names: Vec<&str> = report.jobs.iter().map(|job| job.dataset).collect();
+    assert_eq!(names, ["sto/stk_bydd_trd", "etp/etf_bydd_trd"]);
+    assert!(
+        report
+            .jobs
+            .iter()
+            .all(|job| matches!(job.status, JobStatus::Written { rows } if rows > 0))
+    );
+}
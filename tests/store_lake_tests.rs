@@ -0,0 +1,68 @@
+#![cfg(feature = "parquet")]
+
+mod common;
+
+use common::{EMPTY, mount_expect, ok, setup, stock_daily_body};
+use krx_rs::{
+    api::endpoint::StockDaily,
+    calendar::TradingCalendar,
+    data::{ApiResponse, stock::parse_stock_daily},
+    store::{
+        Dataset,
+        lake::{GapKind, ParquetLake},
+    },
+};
+
+const STOCK_DAILY: Dataset = Dataset::of::<StockDaily>();
+
+#[tokio::test]
+async fn test_lake_sync_fills_gaps_and_scans_like_live() {
+    let (client, server) = setup().await;
+    for (date, close) in [("20240104", "71000"), ("20240105", "70500")] {
+        mount_expect(
+            &server,
+            "sto/stk_bydd_trd",
+            date,
+            ok(stock_daily_body(date, close)),
+            1,
+        )
+        .await;
+    }
+    // 달력에 없는 휴장일은 빈 응답 → 기록하지 않음
+    mount_expect(&server, "sto/stk_bydd_trd", "20240108", ok(EMPTY), 1).await;
+    let root = std::env::temp_dir().join(format!("krx-lake-it-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    let lake = ParquetLake::open(&root).unwrap();
+
+    let report = lake
+        .sync(&client, &STOCK_DAILY, "20240104", "20240108")
+        .await
+        .unwrap();
+    assert_eq!(report.written, vec!["20240104", "20240105"]);
+    assert_eq!(report.empty, vec!["20240108"]);
+
+    let gaps = lake.gaps(&STOCK_DAILY, "20240104", "20240108").unwrap();
+    assert_eq!(gaps.len(), 1);
+    assert_eq!(gaps[0].kind, GapKind::Missing);
+
+    // 휴장일을 달력에 등록하면 더 이상 누락으로 보지 않음 (mock expect(1))
+    let lake = lake.calendar(TradingCalendar::new().with_holidays(["20240108"]).unwrap());
+    let report = lake
+        .sync(&client, &STOCK_DAILY, "20240104", "20240108")
+        .await
+        .unwrap();
+    assert!(report.written.is_empty() && report.empty.is_empty());
+
+    let response: ApiResponse<_> =
+        serde_json::from_str(&stock_daily_body("20240105", "70500")).unwrap();
+    let live = parse_stock_daily(response).unwrap();
+    // polars의 Parquet 스캔은 자체 런타임을 사용하므로 blocking 스레드에서 collect
+    let scan = lake.scan(&STOCK_DAILY, "20240105", "20240105").unwrap();
+    let stored = tokio::task::spawn_blocking(move || scan.collect())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(stored.equals_missing(&live));
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
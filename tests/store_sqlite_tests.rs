@@ -0,0 +1,65 @@
+#![cfg(feature = "sqlite")]
+
+mod common;
+
+use common::{EMPTY, mount_expect, ok, setup, stock_daily_body};
+use krx_rs::{
+    api::endpoint::StockDaily,
+    data::{ApiResponse, stock::parse_stock_daily},
+    store::{Dataset, sqlite::SqliteStore},
+};
+
+const STOCK_DAILY: Dataset = Dataset::of::<StockDaily>();
+
+#[tokio::test]
+async fn test_sync_fetches_only_missing_days_and_loads_offline() {
+    let (client, server) = setup().await;
+    for (date, close) in [
+        ("20240102", "71500"),
+        ("20240103", "71000"),
+        ("20240104", "71000"),
+        ("20240105", "70500"),
+    ] {
+        mount_expect(
+            &server,
+            "sto/stk_bydd_trd",
+            date,
+            ok(stock_daily_body(date, close)),
+            1,
+        )
+        .await;
+    }
+    // 신정(20240101)은 달력상 휴장일이므로 요청하지 않음
+    mount_expect(&server, "sto/stk_bydd_trd", "20240101", ok(EMPTY), 0).await;
+    let mut db = SqliteStore::open_in_memory().unwrap();
+
+    let report = db
+        .sync(&client, &[STOCK_DAILY], "20240102", "20240105")
+        .await
+        .unwrap();
+    assert_eq!(report.fetched.len(), 4);
+    assert_eq!(report.rows, 4);
+
+    // 중단 후 재개: 이미 받은 날짜는 다시 조회하지 않음 (mock expect(1))
+    let report = db
+        .sync(&client, &[STOCK_DAILY], "20240101", "20240105")
+        .await
+        .unwrap();
+    assert_eq!(report.skipped, 4);
+    assert!(report.fetched.is_empty());
+    assert!(report.pending.is_empty());
+    assert!(
+        db.missing_dates(&STOCK_DAILY, "20240101", "20240105")
+            .unwrap()
+            .is_empty()
+    );
+
+    // 오프라인 조회 결과는 실시간 조회와 같은 스키마
+    let response: ApiResponse<_> =
+        serde_json::from_str(&stock_daily_body("20240102", "71500")).unwrap();
+    let live = parse_stock_daily(response).unwrap();
+    let stored = db.load(&STOCK_DAILY, "20240101", "20240105").unwrap();
+    assert_eq!(stored.schema(), live.schema());
+    assert_eq!(stored.height(), 4);
+    assert!(stored.head(Some(1)).equals_missing(&live));
+}
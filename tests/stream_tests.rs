@@ -0,0 +1,137 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::{EMPTY, mount, ok, sample, setup};
+use futures::StreamExt;
+use krx_rs::{Client, api::endpoint::StockDaily, calendar::TradingCalendar, error::Error};
+use std::time::Duration;
+use wiremock::ResponseTemplate;
+
+fn day(date: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(date, "%Y%m%d").unwrap()
+}
+
+#[tokio::test]
+async fn test_range_stream_in_date_order_skipping_empty_days() {
+    let (client, server) = setup().await;
+    // 앞선 날짜의 응답이 더 늦게 도착해도 날짜 순서를 유지해야 합니다.
+    mount(
+        &server,
+        "sto/stk_bydd_trd",
+        "20240104",
+        ok(sample("stk_bydd_trd", "20240104")).set_delay(Duration::from_millis(200)),
+    )
+    .await;
+    mount(
+        &server,
+        "sto/stk_bydd_trd",
+        "20240105",
+        ok(sample("stk_bydd_trd", "20240105")),
+    )
+    .await;
+    mount(&server, "sto/stk_bydd_trd", "20240108", ok(EMPTY)).await;
+    mount(
+        &server,
+        "sto/stk_bydd_trd",
+        "20240109",
+        ok(sample("stk_bydd_trd", "20240105")),
+    )
+    .await;
+
+    let calendar = TradingCalendar::new().with_holidays(["20240110"]).unwrap();
+    let days: Vec<_> = client
+        .fetch_range::<StockDaily>("20240104", "20240110")
+        .calendar(calendar)
+        .concurrency(3)
+        .prefetch(2)
+        .stream()
+        .unwrap()
+        .collect()
+        .await;
+
+    let dates: Vec<NaiveDate> = days.iter().map(|d| d.as_ref().unwrap().0).collect();
+    assert_eq!(
+        dates,
+        vec![day("20240104"), day("20240105"), day("20240109")]
+    );
+    assert!(days.iter().all(|d| d.as_ref().unwrap().1.height() > 0));
+    // 달력의 휴장일(20240110)은 요청하지 않습니다.
+    assert_eq!(client.stats().total_requests(), 4);
+}
+
+#[tokio::test]
+async fn test_range_stream_by_name_reports_errors_per_day() {
+    let (client, server) = setup().await;
+    mount(
+        &server,
+        "sto/stk_bydd_trd",
+        "20240104",
+        ResponseTemplate::new(500).set_body_string("error"),
+    )
+    .await;
+    mount(
+        &server,
+        "sto/stk_bydd_trd",
+        "20240105",
+        ok(sample("stk_bydd_trd", "20240105")),
+    )
+    .await;
+
+    let days: Vec<_> = client
+        .fetch_range_by_name("sto/stk_bydd_trd", "20240104", "20240105")
+        .unwrap()
+        .prefetch(0)
+        .stream()
+        .unwrap()
+        .collect()
+        .await;
+
+    assert_eq!(days.len(), 2);
+    assert!(matches!(
+        days[0],
+        Err(Error::ApiError {
+            status_code: 500,
+            ..
+        })
+    ));
+    assert_eq!(days[1].as_ref().unwrap().0, day("20240105"));
+}
+
+#[tokio::test]
+async fn test_range_stream_from_builder() {
+    let (client, server) = setup().await;
+    mount(
+        &server,
+        "sto/stk_bydd_trd",
+        "20240105",
+        ok(sample("stk_bydd_trd", "20240105")),
+    )
+    .await;
+
+    let builder_client = client.clone();
+    let mut days = krx_rs::stream::RangeStreamBuilder::new("20240105", "20240105", move |date| {
+        builder_client.stock().stock_daily().date(date).fetch()
+    })
+    .stream()
+    .unwrap();
+
+    let (date, df) = days.next().await.unwrap().unwrap();
+    assert_eq!(date, day("20240105"));
+    assert!(df.height() > 0);
+    assert!(days.next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_range_stream_invalid_input() {
+    let client = Client::new("test_key");
+    assert!(matches!(
+        client
+            .fetch_range::<StockDaily>("20240105", "20240101")
+            .stream(),
+        Err(Error::InvalidInput(_))
+    ));
+    assert!(matches!(
+        client.fetch_range_by_name("sto/nope", "20240101", "20240105"),
+        Err(Error::InvalidInput(_))
+    ));
+}
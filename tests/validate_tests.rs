@@ -0,0 +1,79 @@
+mod common;
+
+use common::{mount, ok, sample, setup};
+use krx_rs::{
+    api::endpoint::{EtfDaily, StockDaily},
+    validate::{Rule, Severity, ValidationRules},
+};
+
+#[tokio::test]
+async fn test_validate_stock_daily_sample() {
+    let (client, server) = setup().await;
+    mount(
+        &server,
+        "sto/stk_bydd_trd",
+        "20240104",
+        ok(sample("stk_bydd_trd", "20240104")),
+    )
+    .await;
+    mount(
+        &server,
+        "sto/stk_bydd_trd",
+        "20240105",
+        ok(sample("stk_bydd_trd", "20240105")),
+    )
+    .await;
+
+    let previous = client
+        .fetch_records::<StockDaily>("20240104")
+        .await
+        .unwrap();
+    let current = client
+        .fetch_records::<StockDaily>("20240105")
+        .await
+        .unwrap();
+    assert_eq!(current.len(), 953);
+
+    let rules = ValidationRules::for_endpoint::<StockDaily>();
+    let report = rules.validate_against(&previous, &current);
+
+    assert_eq!(report.records(), 953);
+    assert!(report.is_valid());
+    let counts = report.counts();
+    assert_eq!(counts.get(&Rule::OhlcOrdering), None);
+    assert_eq!(counts.get(&Rule::DuplicateKey), None);
+    // 거래정지 종목은 거래량 0에 기준가를 종가로 보고합니다.
+    assert_eq!(counts.get(&Rule::ZeroVolumeWithClose), Some(&14));
+    let jump: Vec<&str> = report
+        .by_rule(Rule::ListedSharesChange)
+        .map(|issue| issue.key.as_str())
+        .collect();
+    assert_eq!(jump, vec!["011810"]);
+
+    let strict = rules
+        .max_listed_shares_change(0.1)
+        .severity(Rule::ListedSharesChange, Severity::Error);
+    let report = strict.validate_against(&previous, &current);
+    assert_eq!(report.by_rule(Rule::ListedSharesChange).count(), 2);
+    assert!(!report.is_valid());
+}
+
+#[tokio::test]
+async fn test_validate_detects_duplicates() {
+    let (client, server) = setup().await;
+    let body: serde_json::Value =
+        serde_json::from_str(&sample("etf_bydd_trd", "20240105")).unwrap();
+    let mut rows = body["OutBlock_1"].as_array().unwrap().clone();
+    rows.push(rows[0].clone());
+    let body = serde_json::json!({ "OutBlock_1": rows }).to_string();
+    mount(&server, "etp/etf_bydd_trd", "20240105", ok(body)).await;
+
+    let records = client.fetch_records::<EtfDaily>("20240105").await.unwrap();
+    let report = ValidationRules::for_endpoint::<EtfDaily>().validate(&records);
+
+    let duplicates: Vec<_> = report.by_rule(Rule::DuplicateKey).collect();
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates[0].row, records.len() - 1);
+    assert_eq!(duplicates[0].severity, Severity::Error);
+    assert!(!report.is_valid());
+}